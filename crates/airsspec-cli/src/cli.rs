@@ -8,11 +8,13 @@
 //!
 //! ## Commands
 //!
-//! | Command    | Description                              |
-//! |------------|------------------------------------------|
-//! | `init`     | Initialize a new `AirsSpec` workspace      |
-//! | `mcp`      | Start the MCP server (stdio transport)   |
-//! | `validate` | Run spec validation with TUI reporter    |
+//! | Command         | Description                              |
+//! |-----------------|------------------------------------------|
+//! | `init`          | Initialize a new `AirsSpec` workspace      |
+//! | `mcp`           | Start the MCP server (stdio transport)   |
+//! | `validate`      | Run spec validation with TUI reporter    |
+//! | `validate-file` | Validate a single spec or plan file      |
+//! | `doctor`        | Check workspace structural integrity     |
 //!
 //! ## Architecture
 //!
@@ -20,6 +22,8 @@
 //! orchestration layer. All types here are pure data -- command routing and
 //! business logic live elsewhere.
 
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand};
 
 /// Top-level CLI entry point for `AirsSpec`.
@@ -40,6 +44,11 @@ use clap::{Parser, Subcommand};
     propagate_version = true
 )]
 pub struct Cli {
+    /// Workspace root to use instead of searching upwards from the current
+    /// directory.
+    #[arg(long, global = true, value_name = "PATH", env = "AIRSSPEC_WORKSPACE")]
+    pub workspace: Option<PathBuf>,
+
     /// The subcommand to execute.
     #[command(subcommand)]
     pub command: Commands,
@@ -56,7 +65,19 @@ pub enum Commands {
     /// Creates the workspace directory structure, configuration files, and
     /// initial spec templates. Guides the user through project setup with
     /// an interactive terminal UI.
-    Init,
+    Init {
+        /// Preview the workspace that would be created without writing anything.
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+
+        /// Project name. When set, the interactive wizard is skipped.
+        #[arg(long, value_name = "NAME")]
+        name: Option<String>,
+
+        /// Project description (used together with `--name`).
+        #[arg(long, value_name = "TEXT", requires = "name")]
+        description: Option<String>,
+    },
 
     /// Start the MCP server using stdio transport.
     ///
@@ -76,7 +97,37 @@ pub enum Commands {
     ///
     /// Validates all specs in the current workspace against the configured
     /// rules and displays a summary report in the terminal.
-    Validate,
+    Validate {
+        /// Stop validation after this many seconds, report partial results,
+        /// and fail.
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
+
+        /// Print nothing on success and only error lines on failure.
+        #[arg(short, long, default_value_t = false)]
+        quiet: bool,
+
+        /// Finish with a colorless `AIRSSPEC_RESULT ...` line for CI log
+        /// scrapers. Printed even with `--quiet`, on success too.
+        #[arg(long, default_value_t = false)]
+        summary_line: bool,
+    },
+
+    /// Validate a single spec or plan file without scanning the workspace.
+    ///
+    /// The file kind is inferred from its name: `{spec-id}.yaml` is a spec
+    /// and `{spec-id}.plan.yaml` is a plan.
+    ValidateFile {
+        /// Path to the spec or plan file.
+        path: PathBuf,
+    },
+
+    /// Check the structural integrity of the workspace.
+    ///
+    /// Verifies that the configuration parses, required directories exist,
+    /// every plan references an existing spec, no dependency points at a
+    /// missing spec, and no spec ID is declared twice on disk.
+    Doctor,
 }
 
 #[cfg(test)]
@@ -86,10 +137,50 @@ mod tests {
     #[test]
     fn test_parse_init() {
         let cli = Cli::try_parse_from(["airsspec", "init"]).expect("should parse init command");
-        assert!(
-            matches!(cli.command, Commands::Init),
-            "expected Commands::Init"
-        );
+        match cli.command {
+            Commands::Init {
+                dry_run,
+                name,
+                description,
+            } => {
+                assert!(!dry_run, "dry_run should default to false");
+                assert!(name.is_none());
+                assert!(description.is_none());
+            }
+            _ => panic!("expected Commands::Init"),
+        }
+    }
+
+    #[test]
+    fn test_parse_init_dry_run_with_flags() {
+        let cli = Cli::try_parse_from([
+            "airsspec",
+            "init",
+            "--dry-run",
+            "--name",
+            "demo",
+            "--description",
+            "A demo",
+        ])
+        .expect("should parse init flags");
+        match cli.command {
+            Commands::Init {
+                dry_run,
+                name,
+                description,
+            } => {
+                assert!(dry_run);
+                assert_eq!(name.as_deref(), Some("demo"));
+                assert_eq!(description.as_deref(), Some("A demo"));
+            }
+            _ => panic!("expected Commands::Init"),
+        }
+    }
+
+    #[test]
+    fn test_parse_init_description_requires_name() {
+        let result = Cli::try_parse_from(["airsspec", "init", "--description", "A demo"]);
+        assert!(result.is_err(), "--description without --name should fail");
     }
 
     #[test]
@@ -119,12 +210,94 @@ mod tests {
     fn test_parse_validate() {
         let cli =
             Cli::try_parse_from(["airsspec", "validate"]).expect("should parse validate command");
+        match cli.command {
+            Commands::Validate {
+                timeout,
+                quiet,
+                summary_line,
+            } => {
+                assert!(timeout.is_none(), "timeout should default to None");
+                assert!(!quiet, "quiet should default to false");
+                assert!(!summary_line, "summary_line should default to false");
+            }
+            _ => panic!("expected Commands::Validate"),
+        }
+    }
+
+    #[test]
+    fn test_parse_validate_timeout() {
+        let cli = Cli::try_parse_from(["airsspec", "validate", "--timeout", "30"])
+            .expect("should parse validate --timeout");
+        match cli.command {
+            Commands::Validate { timeout, .. } => {
+                assert_eq!(timeout, Some(30), "timeout should be parsed in seconds");
+            }
+            _ => panic!("expected Commands::Validate"),
+        }
+    }
+
+    #[test]
+    fn test_parse_validate_quiet() {
+        for flag in ["--quiet", "-q"] {
+            let cli = Cli::try_parse_from(["airsspec", "validate", flag])
+                .expect("should parse validate --quiet");
+            match cli.command {
+                Commands::Validate { quiet, .. } => assert!(quiet, "{flag} should set quiet"),
+                _ => panic!("expected Commands::Validate"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_validate_summary_line() {
+        let cli = Cli::try_parse_from(["airsspec", "validate", "--summary-line"])
+            .expect("should parse validate --summary-line");
+        match cli.command {
+            Commands::Validate { summary_line, .. } => {
+                assert!(summary_line, "--summary-line should set summary_line");
+            }
+            _ => panic!("expected Commands::Validate"),
+        }
+    }
+
+    #[test]
+    fn test_parse_doctor() {
+        let cli = Cli::try_parse_from(["airsspec", "doctor"]).expect("should parse doctor command");
         assert!(
-            matches!(cli.command, Commands::Validate),
-            "expected Commands::Validate"
+            matches!(cli.command, Commands::Doctor),
+            "expected Commands::Doctor"
         );
     }
 
+    #[test]
+    fn test_parse_validate_file() {
+        let cli = Cli::try_parse_from(["airsspec", "validate-file", "specs/1-a.yaml"])
+            .expect("should parse validate-file command");
+        match cli.command {
+            Commands::ValidateFile { path } => {
+                assert_eq!(path, PathBuf::from("specs/1-a.yaml"));
+            }
+            _ => panic!("expected Commands::ValidateFile"),
+        }
+    }
+
+    #[test]
+    fn test_parse_validate_file_requires_path() {
+        let result = Cli::try_parse_from(["airsspec", "validate-file"]);
+        assert!(result.is_err(), "validate-file without a path should fail");
+    }
+
+    #[test]
+    fn test_parse_workspace_flag_before_and_after_command() {
+        let before = Cli::try_parse_from(["airsspec", "--workspace", "/ws", "doctor"])
+            .expect("should parse --workspace before the command");
+        let after = Cli::try_parse_from(["airsspec", "validate", "--workspace", "/ws"])
+            .expect("should parse --workspace after the command");
+
+        assert_eq!(before.workspace, Some(PathBuf::from("/ws")));
+        assert_eq!(after.workspace, Some(PathBuf::from("/ws")));
+    }
+
     #[test]
     fn test_parse_no_command_fails() {
         let result = Cli::try_parse_from(["airsspec"]);
@@ -0,0 +1,71 @@
+//! # Doctor Command Handler
+//!
+//! Handles the `airsspec doctor` subcommand by checking the structural
+//! integrity of the workspace and displaying results with the TUI reporter.
+//!
+//! ## Flow
+//!
+//! 1. Find the root of the workspace containing the current directory via
+//!    [`require_workspace_root`](super::workspace::require_workspace_root),
+//!    which does not load `config.toml`
+//! 2. Run integrity checks via [`airsspec_mcp::check_workspace_integrity`],
+//!    which reports a missing or unparsable config as a finding
+//! 3. Render the report to stdout via [`airsspec_tui::render_validation_report`]
+//! 4. Return `Ok(())` if no errors were found (exit code 0) or `Err` otherwise (exit code 1)
+//!
+//! ## Exit Codes
+//!
+//! | Code | Meaning |
+//! |------|---------|
+//! | 0    | Workspace is healthy (no errors; warnings are OK) |
+//! | 1    | Integrity errors found or internal error |
+//! | 3    | No workspace found |
+
+// Layer 1: Standard library
+use std::io;
+use std::path::Path;
+
+// Layer 2: External crates
+use anyhow::Context;
+
+// Layer 3: Internal crates
+use airsspec_mcp::check_workspace_integrity;
+use airsspec_tui::render_validation_report;
+
+use super::workspace::require_workspace_root;
+#[cfg(doc)]
+use crate::error::CliError;
+
+/// Run the workspace integrity check command.
+///
+/// Checks that the configuration parses, required directories exist, every
+/// plan references an existing spec, no dependency dangles, and no spec ID
+/// is declared twice on disk.
+///
+/// `workspace` overrides discovery of the workspace root (see
+/// [`require_workspace_root`]). Only a `.airsspec/` directory is needed to
+/// run; configuration problems appear in the report.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - No workspace is found, or the override is not one (see [`CliError`])
+/// - Writing the report to stdout fails
+/// - Any integrity check reported an error (to trigger non-zero exit code)
+pub async fn run(workspace: Option<&Path>) -> anyhow::Result<()> {
+    let root = require_workspace_root(workspace)?;
+
+    let report = check_workspace_integrity(&root).await;
+
+    let mut stdout = io::stdout();
+    render_validation_report(&report, &mut stdout).context("failed to write doctor report")?;
+
+    if !report.is_valid() {
+        anyhow::bail!(
+            "workspace integrity check failed with {} error(s)",
+            report.error_count()
+        );
+    }
+
+    Ok(())
+}
@@ -7,17 +7,24 @@
 //!
 //! 1. Get current working directory
 //! 2. Check if workspace already exists (error if so)
-//! 3. Launch TUI wizard for project configuration
-//! 4. Create workspace using `FileSystemWorkspaceProvider`
+//! 3. Launch TUI wizard for project configuration (skipped when `--name` is given)
+//! 4. Create workspace using `FileSystemWorkspaceProvider`, holding the
+//!    workspace lock from the moment `.airsspec/` exists
 //! 5. Print success message with next steps
 //!
+//! ## Dry Run
+//!
+//! With `--dry-run`, step 4 is replaced by a preview: the directories that
+//! would be created and the `config.toml` contents are printed to stdout,
+//! and nothing is written to disk.
+//!
 //! ## Cancellation
 //!
 //! If the user cancels the wizard (Esc or Ctrl+C), the command prints
 //! "Setup cancelled." and exits normally (no error).
 
 // Layer 1: Standard library
-use std::env;
+use std::path::Path;
 
 // Layer 2: External crates
 use anyhow::Context;
@@ -27,52 +34,79 @@ use airsspec_core::workspace::{ProjectConfig, WorkspaceProvider};
 use airsspec_mcp::FileSystemWorkspaceProvider;
 use airsspec_tui::run_init_wizard;
 
+use crate::error::CliError;
+
+use super::workspace::target_dir;
+
 /// Run the workspace initialization command.
 ///
 /// Launches the interactive TUI wizard that guides the user through creating
 /// a new `AirsSpec` workspace, including directory structure and configuration
 /// files.
 ///
+/// When `name` is given, the wizard is skipped and the project is configured
+/// from `name` and `description` instead. When `dry_run` is set, the planned
+/// workspace is printed and nothing is created.
+///
+/// The workspace is created in `workspace` when given (the `--workspace`
+/// override), otherwise in the current directory.
+///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The current working directory cannot be determined
-/// - A workspace already exists in the current directory
+/// - The current working directory cannot be determined, or the override is
+///   not an existing directory
+/// - A workspace already exists in the target directory
+/// - Another process holds the lock of a partly created workspace there
 /// - The TUI wizard encounters a terminal I/O failure
 /// - The workspace directory or config file cannot be created
 #[expect(
     clippy::unused_async,
     reason = "async signature established in Phase 3.1 for consistency with other command handlers"
 )]
-pub async fn run() -> anyhow::Result<()> {
-    let cwd = env::current_dir().context("failed to determine current directory")?;
+pub async fn run(
+    workspace: Option<&Path>,
+    dry_run: bool,
+    name: Option<String>,
+    description: Option<String>,
+) -> anyhow::Result<()> {
+    let root = target_dir(workspace)?;
     let provider = FileSystemWorkspaceProvider::new();
 
     // Check if workspace already exists before launching the wizard
-    if provider.exists(&cwd) {
+    if provider.exists(&root) {
         anyhow::bail!(
             "workspace already exists at {}",
-            cwd.join(".airsspec").display()
+            root.join(".airsspec").display()
         );
     }
 
-    // Run the TUI wizard to collect project configuration
-    let result = run_init_wizard().context("TUI wizard failed")?;
+    let config = if let Some(name) = name {
+        ProjectConfig::new(name, description.unwrap_or_default())
+    } else {
+        // Run the TUI wizard to collect project configuration
+        let result = run_init_wizard().context("TUI wizard failed")?;
+
+        // Handle wizard cancellation (not an error)
+        let Some(wizard_result) = result else {
+            println!("Setup cancelled.");
+            return Ok(());
+        };
 
-    // Handle wizard cancellation (not an error)
-    let Some(wizard_result) = result else {
-        println!("Setup cancelled.");
-        return Ok(());
+        ProjectConfig::new(
+            &wizard_result.project_name,
+            &wizard_result.project_description,
+        )
     };
 
-    // Create workspace from wizard result
-    let config = ProjectConfig::new(
-        &wizard_result.project_name,
-        &wizard_result.project_description,
-    );
+    if dry_run {
+        return print_preview(&root, &config);
+    }
 
-    let info = provider
-        .initialize(&cwd, &config)
+    // Create workspace from collected configuration
+    let (info, _lock) = provider
+        .initialize_locked(&root, &config)
+        .map_err(|err| CliError::from_workspace(&root, err))
         .context("failed to create workspace")?;
 
     // Print success message
@@ -89,3 +123,25 @@ pub async fn run() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Print the workspace that `init` would create, without writing anything.
+fn print_preview(cwd: &Path, config: &ProjectConfig) -> anyhow::Result<()> {
+    let config_content = FileSystemWorkspaceProvider::render_config(config)
+        .context("failed to render workspace configuration")?;
+
+    println!("Dry run: no files were created.");
+    println!();
+    println!("Would create directories:");
+    for dir in FileSystemWorkspaceProvider::planned_directories(cwd) {
+        println!("  {}", dir.display());
+    }
+    println!();
+    println!(
+        "Would write {}:",
+        FileSystemWorkspaceProvider::config_path(cwd).display()
+    );
+    println!();
+    print!("{config_content}");
+
+    Ok(())
+}
@@ -7,6 +7,18 @@
 //!
 //! This is a placeholder implementation. The full MCP server will be
 //! implemented in Phase 5 (MCP Server).
+//!
+//! The command resolves the workspace found by
+//! [`require_workspace`]: the `--workspace` override, or the one containing
+//! the current directory, and holds that workspace's lock (`.airsspec/.lock`)
+//! for as long as it runs, so a second server cannot modify the same
+//! workspace concurrently. It does not start a server yet.
+
+// Layer 1: Standard library
+use std::path::Path;
+
+// Layer 3: Internal crates
+use super::workspace::{lock_workspace, require_workspace};
 
 /// Run the MCP server command.
 ///
@@ -15,12 +27,15 @@
 ///
 /// # Arguments
 ///
+/// * `workspace` - Workspace root overriding discovery (see [`require_workspace`]).
 /// * `debug` - When `true`, enables verbose debug logging and diagnostics
 ///   including request/response payloads and internal state transitions.
 ///
 /// # Errors
 ///
 /// Returns an error if:
+/// - No workspace is found, or the override is not one (see [`require_workspace`])
+/// - Another process holds the workspace lock
 /// - The MCP server fails to bind to stdio transport
 /// - The server encounters a fatal runtime error
 // Intentionally async: MCP server will use async I/O in Phase 5.
@@ -28,8 +43,12 @@
     clippy::unused_async,
     reason = "intentionally async for Phase 5 MCP server integration"
 )]
-pub async fn run(debug: bool) -> anyhow::Result<()> {
+pub async fn run(workspace: Option<&Path>, debug: bool) -> anyhow::Result<()> {
+    let workspace = require_workspace(workspace)?;
+    let _lock = lock_workspace(workspace.root())?;
+
     println!("AirsSpec MCP Server");
+    println!("Workspace: {}", workspace.root().display());
     println!("Debug mode: {debug}");
     println!("(MCP server will be implemented in Phase 5)");
     Ok(())
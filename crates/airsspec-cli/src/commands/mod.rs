@@ -9,6 +9,9 @@
 //! - [`init`] -- Phase 3.3 (Init Command Integration with TUI Wizard)
 //! - [`mcp`] -- Phase 5 (MCP Server)
 //! - [`validate`] -- Phase 4 (Validation Engine)
+//! - [`validate_file`] -- Single spec or plan file validation
+//! - [`doctor`] -- Workspace integrity checks
+//! - [`workspace`] -- Shared workspace lookup for the commands above
 //!
 //! ## Architecture
 //!
@@ -16,6 +19,9 @@
 //! to library crates (`airsspec-core`, `airsspec-mcp`, `airsspec-tui`).
 //! No business logic belongs in these handlers.
 
+pub mod doctor;
 pub mod init;
 pub mod mcp;
 pub mod validate;
+pub mod validate_file;
+pub mod workspace;
@@ -5,9 +5,13 @@
 //!
 //! ## Flow
 //!
-//! 1. Determine the current working directory
-//! 2. Run all workspace validators via [`airsspec_mcp::validate_workspace`]
-//! 3. Render the validation report to stdout via [`airsspec_tui::render_validation_report`]
+//! 1. Find the workspace containing the current directory via
+//!    [`require_workspace`](super::workspace::require_workspace)
+//! 2. Run all workspace validators via
+//!    [`airsspec_mcp::validation::validate_workspace_with_limits`], bounded by
+//!    `--timeout` when given
+//! 3. Render the validation report to stdout via [`airsspec_tui::render_validation_report`],
+//!    followed by the machine summary line when `--summary-line` is set
 //! 4. Return `Ok(())` if validation passed (exit code 0) or `Err` if failed (exit code 1)
 //!
 //! ## Exit Codes
@@ -15,43 +19,93 @@
 //! | Code | Meaning |
 //! |------|---------|
 //! | 0    | Validation passed (no errors; warnings are OK) |
-//! | 1    | Validation failed (errors present) or internal error |
+//! | 1    | Validation failed (errors present), timed out, or internal error |
+//! | 3    | No workspace found |
 
 // Layer 1: Standard library
 use std::io;
+use std::path::Path;
+use std::time::Duration;
 
 // Layer 2: External crates
 use anyhow::Context;
 
 // Layer 3: Internal crates
-use airsspec_mcp::validate_workspace;
-use airsspec_tui::render_validation_report;
+use airsspec_core::spec::SpecStorage as _;
+use airsspec_mcp::FileSystemSpecStorage;
+use airsspec_mcp::validation::{CancellationToken, validate_workspace_with_limits};
+use airsspec_tui::{
+    ReportOptions, render_summary_line, render_validation_report_with_options, terminal_wrap_width,
+};
+
+use super::workspace::require_workspace;
+#[cfg(doc)]
+use crate::error::CliError;
 
 /// Run the workspace validation command.
 ///
 /// Validates all specs in the current workspace against the configured
 /// validation rules and displays a summary report using the TUI reporter.
 ///
+/// # Arguments
+///
+/// * `workspace` - Workspace root overriding discovery (see [`require_workspace`]).
+/// * `timeout` - Optional limit in seconds. When it elapses, validation stops
+///   and the partial report is rendered with an error saying so, so the
+///   command fails.
+/// * `quiet` - Print nothing when validation passes and only the error lines
+///   when it fails. The exit code is unaffected.
+/// * `summary_line` - Finish with an `AIRSSPEC_RESULT ...` line (see
+///   [`render_summary_line`]) for CI log scrapers. It overrides `quiet`: the
+///   line is printed whether validation passes or fails, so scrapers always
+///   find it.
+///
 /// # Flow
 ///
-/// 1. Gets the current working directory
-/// 2. Calls [`validate_workspace`] to run all validators
-/// 3. Renders the report to stdout via [`render_validation_report`]
+/// 1. Finds the workspace (the override, or the one containing the current
+///    directory)
+/// 2. Calls [`validate_workspace_with_limits`] to run all validators
+/// 3. Renders the report to stdout via [`render_validation_report_with_options`],
+///    wrapping long messages at the terminal width, then the summary line
+///    if requested
 /// 4. Returns `Err` if validation found errors (maps to exit code 1 in `main()`)
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The current working directory cannot be determined
+/// - No workspace is found, or the override is not one (see [`CliError`])
+/// - The workspace configuration cannot be loaded
 /// - Writing the validation report to stdout fails
+/// - The specs cannot be listed to count them for the summary line
 /// - Validation found errors (to trigger non-zero exit code)
-pub async fn run() -> anyhow::Result<()> {
-    let cwd = std::env::current_dir().context("failed to determine current directory")?;
+pub async fn run(
+    workspace: Option<&Path>,
+    timeout: Option<u64>,
+    quiet: bool,
+    summary_line: bool,
+) -> anyhow::Result<()> {
+    let workspace = require_workspace(workspace)?;
 
-    let report = validate_workspace(&cwd).await;
+    let timeout = timeout.map(Duration::from_secs);
+    let report =
+        validate_workspace_with_limits(workspace.root(), timeout, &CancellationToken::new()).await;
 
+    let options = ReportOptions {
+        quiet,
+        wrap_width: terminal_wrap_width(),
+    };
     let mut stdout = io::stdout();
-    render_validation_report(&report, &mut stdout).context("failed to write validation report")?;
+    render_validation_report_with_options(&report, options, &mut stdout)
+        .context("failed to write validation report")?;
+
+    if summary_line {
+        let specs = FileSystemSpecStorage::new(workspace.specs_dir())
+            .list_specs()
+            .await
+            .context("failed to count specs for the summary line")?
+            .len();
+        render_summary_line(&report, specs, &mut stdout).context("failed to write summary line")?;
+    }
 
     if !report.is_valid() {
         anyhow::bail!("validation failed with {} error(s)", report.error_count());
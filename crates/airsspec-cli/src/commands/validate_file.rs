@@ -0,0 +1,49 @@
+//! # Validate-File Command Handler
+//!
+//! Handles the `airsspec validate-file <PATH>` subcommand by validating a
+//! single spec or plan file and displaying results with the TUI reporter.
+//!
+//! ## Flow
+//!
+//! 1. Infer the file kind and validate via [`airsspec_mcp::validation::validate_file`]
+//! 2. Fail with a clear error if the file is neither a spec nor a plan
+//! 3. Render the report to stdout via [`airsspec_tui::render_validation_report`]
+//! 4. Return `Ok(())` if no errors were found (exit code 0) or `Err` otherwise (exit code 1)
+
+// Layer 1: Standard library
+use std::io;
+use std::path::Path;
+
+// Layer 2: External crates
+use anyhow::Context;
+
+// Layer 3: Internal crates
+use airsspec_mcp::validation::validate_file;
+use airsspec_tui::render_validation_report;
+
+/// Run the single-file validation command.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `path` is not named like a spec (`{spec-id}.yaml`) or plan
+///   (`{spec-id}.plan.yaml`) file
+/// - Writing the report to stdout fails
+/// - The file has validation errors (to trigger non-zero exit code)
+pub async fn run(path: &Path) -> anyhow::Result<()> {
+    let Some(report) = validate_file(path).await else {
+        anyhow::bail!(
+            "unrecognized file type: {} (expected a spec `{{spec-id}}.yaml` or plan `{{spec-id}}.plan.yaml` file)",
+            path.display()
+        );
+    };
+
+    let mut stdout = io::stdout();
+    render_validation_report(&report, &mut stdout).context("failed to write validation report")?;
+
+    if !report.is_valid() {
+        anyhow::bail!("validation failed with {} error(s)", report.error_count());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,235 @@
+//! # Workspace Lookup
+//!
+//! Shared entry point for commands that need an existing workspace, so they
+//! all fail the same way -- with a [`CliError`] and exit code 3 -- when run
+//! outside one.
+//!
+//! The workspace is discovered upwards from the current directory unless an
+//! override is given with the global `--workspace <PATH>` flag or the
+//! `AIRSSPEC_WORKSPACE` environment variable. An override names the
+//! workspace root itself; no upward search happens from it.
+
+// Layer 1: Standard library
+use std::path::{Path, PathBuf};
+
+// Layer 3: Internal crates
+use airsspec_core::workspace::{WorkspaceError, WorkspaceInfo, WorkspaceProvider as _};
+use airsspec_mcp::FileSystemWorkspaceProvider;
+use airsspec_mcp::storage::WorkspaceLock;
+
+use crate::error::CliError;
+
+/// Finds the workspace to operate on.
+///
+/// Uses `override_root` when given, otherwise searches the current directory
+/// and its parents for `.airsspec/config.toml`.
+///
+/// # Errors
+///
+/// - [`CliError::NotFound`] if no workspace contains the current directory
+/// - [`CliError::InvalidPath`] if `override_root` is not an existing directory
+/// - [`CliError::NotAWorkspace`] if `override_root` has no workspace config
+/// - [`CliError::Workspace`] if the current directory cannot be determined,
+///   or the configuration cannot be read or parsed
+pub fn require_workspace(override_root: Option<&Path>) -> Result<WorkspaceInfo, CliError> {
+    match override_root {
+        Some(root) => require_workspace_at(root),
+        None => require_workspace_from(&current_dir()?),
+    }
+}
+
+/// Finds the root of the workspace to operate on without loading its
+/// configuration.
+///
+/// Like [`require_workspace`], but only a `.airsspec/` directory is required,
+/// so a command that diagnoses the configuration itself can still run when
+/// `config.toml` is missing or does not parse.
+///
+/// # Errors
+///
+/// - [`CliError::NotFound`] if no workspace contains the current directory
+/// - [`CliError::InvalidPath`] if `override_root` is not an existing directory
+/// - [`CliError::NotAWorkspace`] if `override_root` has no `.airsspec/` directory
+/// - [`CliError::Workspace`] if the current directory cannot be determined
+pub fn require_workspace_root(override_root: Option<&Path>) -> Result<PathBuf, CliError> {
+    match override_root {
+        Some(root) => {
+            let root = target_dir(Some(root))?;
+            if has_workspace_dir(&root) {
+                Ok(root.canonicalize().unwrap_or(root))
+            } else {
+                Err(CliError::NotAWorkspace(root))
+            }
+        }
+        None => {
+            let start = current_dir()?;
+            let canonical = start.canonicalize().unwrap_or_else(|_| start.clone());
+            canonical
+                .ancestors()
+                .find(|dir| has_workspace_dir(dir))
+                .map(Path::to_path_buf)
+                .ok_or(CliError::NotFound(start))
+        }
+    }
+}
+
+/// Returns the directory a command that creates a workspace should use:
+/// `override_root` if given, otherwise the current directory.
+///
+/// # Errors
+///
+/// - [`CliError::InvalidPath`] if `override_root` is not an existing directory
+/// - [`CliError::Workspace`] if the current directory cannot be determined
+pub fn target_dir(override_root: Option<&Path>) -> Result<PathBuf, CliError> {
+    match override_root {
+        Some(root) if root.is_dir() => Ok(root.to_path_buf()),
+        Some(root) => Err(CliError::InvalidPath(root.to_path_buf())),
+        None => current_dir(),
+    }
+}
+
+/// Locks the workspace rooted at `root` until the returned guard is dropped.
+///
+/// Commands that modify a workspace hold the lock while they run, so they
+/// cannot interleave with each other or with an MCP server.
+///
+/// # Errors
+///
+/// - [`CliError::Locked`] if another process holds the lock
+/// - [`CliError::Workspace`] if the lock file cannot be created
+pub fn lock_workspace(root: &Path) -> Result<WorkspaceLock, CliError> {
+    WorkspaceLock::acquire(root).map_err(|err| CliError::from_workspace(root, err))
+}
+
+/// Finds the workspace containing `start` by searching upwards.
+///
+/// # Errors
+///
+/// - [`CliError::NotFound`] if there is no workspace
+/// - [`CliError::Workspace`] if the configuration cannot be read or parsed
+pub fn require_workspace_from(start: &Path) -> Result<WorkspaceInfo, CliError> {
+    FileSystemWorkspaceProvider::new()
+        .discover(start)
+        .map_err(|err| match err {
+            WorkspaceError::NotFound(_) => CliError::NotFound(start.to_path_buf()),
+            err => CliError::Workspace(err),
+        })
+}
+
+/// Loads the workspace rooted exactly at `root`.
+fn require_workspace_at(root: &Path) -> Result<WorkspaceInfo, CliError> {
+    let root = target_dir(Some(root))?;
+    if !FileSystemWorkspaceProvider::config_path(&root).is_file() {
+        return Err(CliError::NotAWorkspace(root));
+    }
+    FileSystemWorkspaceProvider::new()
+        .discover(&root)
+        .map_err(CliError::Workspace)
+}
+
+/// Returns `true` if `dir` contains a `.airsspec/` directory.
+fn has_workspace_dir(dir: &Path) -> bool {
+    FileSystemWorkspaceProvider::config_path(dir)
+        .parent()
+        .is_some_and(Path::is_dir)
+}
+
+fn current_dir() -> Result<PathBuf, CliError> {
+    std::env::current_dir().map_err(|err| CliError::Workspace(WorkspaceError::Io(err.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_outside_workspace_is_not_found() {
+        let temp = tempfile::tempdir().unwrap();
+
+        let err = require_workspace_from(temp.path()).unwrap_err();
+
+        assert!(matches!(err, CliError::NotFound(_)));
+        assert!(err.to_string().contains("run `airsspec init`"));
+        assert_eq!(err.exit_code(), 3);
+    }
+
+    fn create_workspace(root: &Path) {
+        fs::create_dir_all(root.join(".airsspec")).unwrap();
+        fs::write(
+            root.join(".airsspec/config.toml"),
+            "[project]\nname = \"test\"\ndescription = \"test project\"\n",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_discovers_workspace_from_subdirectory() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        let nested = temp.path().join("src/deep");
+        fs::create_dir_all(&nested).unwrap();
+
+        let info = require_workspace_from(&nested).unwrap();
+
+        assert_eq!(info.root(), temp.path().canonicalize().unwrap());
+    }
+
+    #[test]
+    fn test_override_selects_workspace() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+
+        let info = require_workspace(Some(temp.path())).unwrap();
+
+        assert_eq!(info.root(), temp.path().canonicalize().unwrap());
+    }
+
+    #[test]
+    fn test_override_does_not_search_upwards() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        let nested = temp.path().join("nested");
+        fs::create_dir(&nested).unwrap();
+
+        let err = require_workspace(Some(&nested)).unwrap_err();
+
+        assert!(matches!(err, CliError::NotAWorkspace(_)));
+    }
+
+    #[test]
+    fn test_root_lookup_ignores_broken_config() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::create_dir_all(temp.path().join(".airsspec")).unwrap();
+        fs::write(temp.path().join(".airsspec/config.toml"), "not = [valid").unwrap();
+
+        let root = require_workspace_root(Some(temp.path())).unwrap();
+
+        assert_eq!(root, temp.path().canonicalize().unwrap());
+        assert!(matches!(
+            require_workspace(Some(temp.path())),
+            Err(CliError::Workspace(_))
+        ));
+    }
+
+    #[test]
+    fn test_root_lookup_requires_workspace_dir() {
+        let temp = tempfile::tempdir().unwrap();
+
+        let err = require_workspace_root(Some(temp.path())).unwrap_err();
+
+        assert!(matches!(err, CliError::NotAWorkspace(_)));
+    }
+
+    #[test]
+    fn test_override_must_exist() {
+        let temp = tempfile::tempdir().unwrap();
+        let missing = temp.path().join("missing");
+
+        let err = require_workspace(Some(&missing)).unwrap_err();
+
+        assert!(matches!(err, CliError::InvalidPath(_)));
+        assert_eq!(err.exit_code(), 3);
+    }
+}
@@ -0,0 +1,123 @@
+//! # CLI Errors
+//!
+//! Errors that commands raise deliberately and that map to a specific exit
+//! code. Everything else is reported through `anyhow` with exit code 1.
+//!
+//! ## Exit Codes
+//!
+//! | Code | Meaning |
+//! |------|---------|
+//! | 1    | Command failed (validation errors, I/O, invalid configuration, workspace locked) |
+//! | 3    | No workspace found, or the `--workspace` / `AIRSSPEC_WORKSPACE` override is not one |
+
+// Layer 1: Standard library
+use std::path::{Path, PathBuf};
+
+// Layer 3: Internal crates
+use airsspec_core::workspace::WorkspaceError;
+
+/// Errors with a dedicated exit code.
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    /// No `.airsspec/` workspace in the start directory or any parent.
+    #[error(
+        "No AirsSpec workspace found in {} or any parent directory; run `airsspec init` to create one",
+        .0.display()
+    )]
+    NotFound(PathBuf),
+
+    /// The workspace override does not exist or is not a directory.
+    #[error(
+        "Workspace path {} (from --workspace or AIRSSPEC_WORKSPACE) does not exist or is not a directory",
+        .0.display()
+    )]
+    InvalidPath(PathBuf),
+
+    /// The workspace override is a directory without `.airsspec/config.toml`.
+    #[error(
+        "{} is not an AirsSpec workspace (no .airsspec/config.toml); run `airsspec init` there or point --workspace elsewhere",
+        .0.display()
+    )]
+    NotAWorkspace(PathBuf),
+
+    /// Another process holds the workspace lock.
+    #[error(
+        "Workspace {} is locked by another process; retry once it has finished ({1})",
+        .0.display()
+    )]
+    Locked(PathBuf, WorkspaceError),
+
+    /// A workspace was found but could not be loaded.
+    #[error(transparent)]
+    Workspace(WorkspaceError),
+}
+
+impl CliError {
+    /// Wraps a [`WorkspaceError`] raised for the workspace at `root`,
+    /// reporting a held lock as [`CliError::Locked`].
+    #[must_use]
+    pub fn from_workspace(root: &Path, err: WorkspaceError) -> Self {
+        match err {
+            WorkspaceError::Locked(_) | WorkspaceError::LockedByUnknown(_) => {
+                Self::Locked(root.to_path_buf(), err)
+            }
+            err => Self::Workspace(err),
+        }
+    }
+
+    /// Exit code used when a command fails with this error.
+    #[must_use]
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::NotFound(_) | Self::InvalidPath(_) | Self::NotAWorkspace(_) => 3,
+            Self::Locked(..) | Self::Workspace(_) => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_found_message_and_exit_code() {
+        let err = CliError::NotFound(PathBuf::from("/tmp/project"));
+
+        assert_eq!(
+            err.to_string(),
+            "No AirsSpec workspace found in /tmp/project or any parent directory; \
+             run `airsspec init` to create one"
+        );
+        assert_eq!(err.exit_code(), 3);
+    }
+
+    #[test]
+    fn test_override_errors_exit_code() {
+        let invalid = CliError::InvalidPath(PathBuf::from("/missing"));
+        let not_workspace = CliError::NotAWorkspace(PathBuf::from("/tmp"));
+
+        assert!(invalid.to_string().contains("/missing"));
+        assert_eq!(invalid.exit_code(), 3);
+        assert_eq!(not_workspace.exit_code(), 3);
+    }
+
+    #[test]
+    fn test_held_lock_is_locked_error() {
+        let err = CliError::from_workspace(Path::new("/tmp/project"), WorkspaceError::Locked(42));
+
+        assert!(matches!(err, CliError::Locked(..)));
+        assert!(
+            err.to_string()
+                .contains("/tmp/project is locked by another process"),
+            "{err}"
+        );
+        assert_eq!(err.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_workspace_error_exit_code() {
+        let err = CliError::Workspace(WorkspaceError::InvalidConfig("bad toml".to_string()));
+
+        assert_eq!(err.exit_code(), 1);
+    }
+}
@@ -12,41 +12,68 @@
 //!
 //! ## Commands
 //!
-//! | Command              | Description                              |
-//! |----------------------|------------------------------------------|
-//! | `airsspec init`      | Initialize workspace (TUI wizard)        |
-//! | `airsspec mcp`       | Start MCP server (stdio transport)       |
-//! | `airsspec validate`  | Run validation with TUI reporter         |
+//! | Command                         | Description                              |
+//! |---------------------------------|------------------------------------------|
+//! | `airsspec init`                 | Initialize workspace (TUI wizard)        |
+//! | `airsspec mcp`                  | Start MCP server (stdio transport)       |
+//! | `airsspec validate`             | Run validation with TUI reporter         |
+//! | `airsspec validate-file <PATH>` | Validate one spec or plan file           |
+//! | `airsspec doctor`               | Check workspace structural integrity     |
+//!
+//! ## Workspace Selection
+//!
+//! Commands operate on the workspace containing the current directory. The
+//! global `--workspace <PATH>` flag, or the `AIRSSPEC_WORKSPACE` environment
+//! variable, names the workspace root explicitly instead.
 //!
 //! ## Error Handling
 //!
 //! Command handlers return `anyhow::Result<()>`. Errors are caught in `main()`,
-//! printed to stderr, and mapped to a non-zero exit code per Unix conventions.
+//! printed to stderr, and mapped to a non-zero exit code per Unix conventions:
+//! a [`CliError`](error::CliError) carries its own code (3 when no workspace
+//! is found); any other error exits with 1.
 
 mod cli;
 mod commands;
+mod error;
 
 use std::process::ExitCode;
 
 use clap::Parser;
 
 use cli::{Cli, Commands};
+use error::CliError;
 
 #[tokio::main]
 async fn main() -> ExitCode {
     let cli = Cli::parse();
 
+    let workspace = cli.workspace.as_deref();
+
     let result = match cli.command {
-        Commands::Init => commands::init::run().await,
-        Commands::Mcp { debug } => commands::mcp::run(debug).await,
-        Commands::Validate => commands::validate::run().await,
+        Commands::Init {
+            dry_run,
+            name,
+            description,
+        } => commands::init::run(workspace, dry_run, name, description).await,
+        Commands::Mcp { debug } => commands::mcp::run(workspace, debug).await,
+        Commands::Validate {
+            timeout,
+            quiet,
+            summary_line,
+        } => commands::validate::run(workspace, timeout, quiet, summary_line).await,
+        Commands::ValidateFile { path } => commands::validate_file::run(&path).await,
+        Commands::Doctor => commands::doctor::run(workspace).await,
     };
 
     match result {
         Ok(()) => ExitCode::SUCCESS,
         Err(err) => {
             eprintln!("Error: {err:?}");
-            ExitCode::FAILURE
+            ExitCode::from(
+                err.downcast_ref::<CliError>()
+                    .map_or(1, CliError::exit_code),
+            )
         }
     }
 }
@@ -52,6 +52,10 @@ fn test_help_output() {
         stdout.contains("validate"),
         "help output should list 'validate' command"
     );
+    assert!(
+        stdout.contains("doctor"),
+        "help output should list 'doctor' command"
+    );
 }
 
 #[test]
@@ -95,10 +99,61 @@ fn test_init_command_no_tty() {
     );
 }
 
+#[test]
+fn test_init_dry_run_creates_nothing() {
+    let temp = tempfile::tempdir().unwrap();
+
+    let output = airsspec_cmd()
+        .args([
+            "init",
+            "--dry-run",
+            "--name",
+            "preview-project",
+            "--description",
+            "A previewed project",
+        ])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec init --dry-run");
+
+    assert!(
+        output.status.success(),
+        "airsspec init --dry-run should exit with code 0, stderr: {}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+    assert!(
+        fs::read_dir(temp.path()).unwrap().next().is_none(),
+        "dry run should not create any files"
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("no files were created"),
+        "preview should say nothing was created, got: {stdout}"
+    );
+    assert!(
+        stdout.contains("name = \"preview-project\""),
+        "preview should include the project name, got: {stdout}"
+    );
+    assert!(
+        stdout.contains("description = \"A previewed project\""),
+        "preview should include the project description, got: {stdout}"
+    );
+    assert!(
+        stdout.contains("specs"),
+        "preview should list the specs directory, got: {stdout}"
+    );
+}
+
 #[test]
 fn test_mcp_command() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+
     let output = airsspec_cmd()
         .arg("mcp")
+        .current_dir(temp.path())
+        .env_remove("AIRSSPEC_WORKSPACE")
         .output()
         .expect("failed to execute airsspec mcp");
 
@@ -116,8 +171,13 @@ fn test_mcp_command() {
 
 #[test]
 fn test_mcp_debug_flag() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+
     let output = airsspec_cmd()
         .args(["mcp", "--debug"])
+        .current_dir(temp.path())
+        .env_remove("AIRSSPEC_WORKSPACE")
         .output()
         .expect("failed to execute airsspec mcp --debug");
 
@@ -133,6 +193,80 @@ fn test_mcp_debug_flag() {
     );
 }
 
+#[test]
+fn test_mcp_no_workspace() {
+    let temp = tempfile::tempdir().unwrap();
+
+    let output = airsspec_cmd()
+        .arg("mcp")
+        .current_dir(temp.path())
+        .env_remove("AIRSSPEC_WORKSPACE")
+        .output()
+        .expect("failed to execute airsspec mcp");
+
+    assert_eq!(
+        output.status.code(),
+        Some(3),
+        "airsspec mcp outside workspace should exit with code 3",
+    );
+}
+
+/// Marks the workspace in `dir` as locked by this (running) test process,
+/// the way a concurrent `airsspec` process would.
+fn hold_workspace_lock(dir: &Path) {
+    let airsspec = dir.join(".airsspec");
+    fs::create_dir_all(&airsspec).unwrap();
+    fs::write(airsspec.join(".lock"), format!("{}\n", std::process::id())).unwrap();
+}
+
+#[test]
+fn test_mcp_locked_workspace() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+    hold_workspace_lock(temp.path());
+
+    let output = airsspec_cmd()
+        .arg("mcp")
+        .current_dir(temp.path())
+        .env_remove("AIRSSPEC_WORKSPACE")
+        .output()
+        .expect("failed to execute airsspec mcp");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("locked by another process"),
+        "error output should say the workspace is locked, got: {stderr}"
+    );
+    assert!(
+        temp.path().join(".airsspec/.lock").exists(),
+        "the other process's lock must be left in place"
+    );
+}
+
+#[test]
+fn test_init_locked_workspace() {
+    let temp = tempfile::tempdir().unwrap();
+    hold_workspace_lock(temp.path());
+
+    let output = airsspec_cmd()
+        .args(["init", "--name", "locked-project"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec init");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("locked by another process"),
+        "error output should say the workspace is locked, got: {stderr}"
+    );
+    assert!(
+        !temp.path().join(".airsspec/config.toml").exists(),
+        "init must not write into a locked workspace"
+    );
+}
+
 /// Creates a minimal valid `.airsspec/` workspace structure in `dir`.
 fn create_valid_workspace(dir: &Path) {
     let airsspec = dir.join(".airsspec");
@@ -192,6 +326,79 @@ fn test_validate_valid_workspace() {
     );
 }
 
+#[test]
+fn test_validate_quiet_passing_prints_nothing() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+    create_test_spec_yaml(&temp.path().join(".airsspec/specs"));
+
+    let output = airsspec_cmd()
+        .args(["validate", "--quiet"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec validate --quiet");
+
+    assert!(output.status.success());
+    assert!(
+        output.stdout.is_empty(),
+        "quiet validate should print nothing on success, got: {}",
+        String::from_utf8_lossy(&output.stdout),
+    );
+}
+
+#[test]
+fn test_validate_quiet_summary_line_prints_only_summary() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+    create_test_spec_yaml(&temp.path().join(".airsspec/specs"));
+
+    let output = airsspec_cmd()
+        .args(["validate", "--quiet", "--summary-line"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec validate --quiet --summary-line");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        stdout.lines().count(),
+        1,
+        "quiet output should be only the summary line, got: {stdout}"
+    );
+    assert!(
+        stdout.starts_with("AIRSSPEC_RESULT errors=0 ") && stdout.ends_with("specs=1 valid=true\n"),
+        "--summary-line should still print its line under --quiet, got: {stdout}"
+    );
+}
+
+#[test]
+fn test_validate_quiet_failing_prints_only_errors() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+    fs::write(
+        temp.path().join(".airsspec/specs/1000000-bad.yaml"),
+        "this is not valid yaml: [[[",
+    )
+    .unwrap();
+
+    let output = airsspec_cmd()
+        .args(["validate", "--quiet"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec validate --quiet");
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout
+            .lines()
+            .all(|line| line.contains("Failed to load spec")),
+        "quiet validate should print only error lines, got: {stdout}"
+    );
+    assert!(!stdout.is_empty());
+    assert!(!stdout.contains("Status:"));
+}
+
 #[test]
 fn test_validate_no_workspace() {
     let temp = tempfile::tempdir().unwrap();
@@ -202,15 +409,16 @@ fn test_validate_no_workspace() {
         .output()
         .expect("failed to execute airsspec validate");
 
-    assert!(
-        !output.status.success(),
-        "airsspec validate outside workspace should exit with non-zero code",
+    assert_eq!(
+        output.status.code(),
+        Some(3),
+        "airsspec validate outside workspace should exit with code 3",
     );
 
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
-        stderr.contains("validation failed"),
-        "stderr should mention validation failure, got: {stderr}",
+        stderr.contains("No AirsSpec workspace found") && stderr.contains("airsspec init"),
+        "stderr should explain how to create a workspace, got: {stderr}",
     );
 }
 
@@ -301,3 +509,294 @@ fn test_mcp_help() {
         "mcp --help should describe the --debug flag"
     );
 }
+
+#[test]
+fn test_doctor_no_workspace() {
+    let temp = tempfile::tempdir().unwrap();
+
+    let output = airsspec_cmd()
+        .arg("doctor")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec doctor");
+
+    assert_eq!(
+        output.status.code(),
+        Some(3),
+        "airsspec doctor outside workspace should exit with code 3",
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("No AirsSpec workspace found"),
+        "stderr should report the missing workspace, got: {stderr}",
+    );
+}
+
+#[test]
+fn test_workspace_flag_selects_workspace() {
+    let workspace = tempfile::tempdir().unwrap();
+    create_valid_workspace(workspace.path());
+    let elsewhere = tempfile::tempdir().unwrap();
+
+    let output = airsspec_cmd()
+        .args(["doctor", "--workspace"])
+        .arg(workspace.path())
+        .current_dir(elsewhere.path())
+        .env_remove("AIRSSPEC_WORKSPACE")
+        .output()
+        .expect("failed to execute airsspec doctor");
+
+    assert!(
+        output.status.success(),
+        "--workspace should select the workspace, stderr: {}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+#[test]
+fn test_workspace_env_var_selects_workspace() {
+    let workspace = tempfile::tempdir().unwrap();
+    create_valid_workspace(workspace.path());
+    let elsewhere = tempfile::tempdir().unwrap();
+
+    let output = airsspec_cmd()
+        .arg("validate")
+        .current_dir(elsewhere.path())
+        .env("AIRSSPEC_WORKSPACE", workspace.path())
+        .output()
+        .expect("failed to execute airsspec validate");
+
+    assert!(
+        output.status.success(),
+        "AIRSSPEC_WORKSPACE should select the workspace, stderr: {}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+#[test]
+fn test_workspace_flag_invalid_path() {
+    let temp = tempfile::tempdir().unwrap();
+    let missing = temp.path().join("missing");
+
+    let output = airsspec_cmd()
+        .args(["doctor", "--workspace"])
+        .arg(&missing)
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec doctor");
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("does not exist or is not a directory") && stderr.contains("missing"),
+        "stderr should name the invalid path, got: {stderr}",
+    );
+}
+
+#[test]
+fn test_doctor_healthy_workspace() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+    create_test_spec_yaml(&temp.path().join(".airsspec/specs"));
+
+    let output = airsspec_cmd()
+        .arg("doctor")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec doctor");
+
+    assert!(
+        output.status.success(),
+        "airsspec doctor in healthy workspace should exit with code 0, stderr: {}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+}
+
+#[test]
+fn test_doctor_reports_broken_config() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+    fs::write(temp.path().join(".airsspec/config.toml"), "not = [valid").unwrap();
+
+    let output = airsspec_cmd()
+        .arg("doctor")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec doctor");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Failed to parse config.toml"),
+        "output should report the broken config, got: {stdout}",
+    );
+}
+
+#[test]
+fn test_doctor_reports_missing_config() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+    fs::remove_file(temp.path().join(".airsspec/config.toml")).unwrap();
+
+    let output = airsspec_cmd()
+        .arg("doctor")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec doctor");
+
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "a missing config is a finding, not a missing workspace",
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("config.toml"),
+        "output should report the missing config, got: {stdout}",
+    );
+}
+
+#[test]
+fn test_doctor_reports_orphaned_plan_and_dangling_dependency() {
+    let temp = tempfile::tempdir().unwrap();
+    create_valid_workspace(temp.path());
+    let specs_dir = temp.path().join(".airsspec/specs");
+
+    // Spec depending on a spec that does not exist
+    let spec_yaml = "\
+id: 1000000-test-feature
+metadata:
+  title: Test Feature
+  description: A test feature for doctor
+  category: feature
+  dependencies:
+  - spec_id: 9999999-missing-spec
+    kind: blocked_by
+  created_at: '2026-01-01T00:00:00Z'
+  updated_at: '2026-01-01T00:00:00Z'
+content: '# Test Feature'
+";
+    fs::write(specs_dir.join("1000000-test-feature.yaml"), spec_yaml).unwrap();
+
+    // Plan whose spec does not exist
+    let plan_yaml = "\
+spec_id: 2000000-orphaned-spec
+approach: Orphaned approach
+steps:
+- index: 0
+  title: Step 1
+  description: First step
+  complexity: medium
+  status: pending
+  notes: null
+created_at: '2026-01-01T00:00:00Z'
+updated_at: '2026-01-01T00:00:00Z'
+";
+    fs::write(specs_dir.join("2000000-orphaned-spec.plan.yaml"), plan_yaml).unwrap();
+
+    let output = airsspec_cmd()
+        .arg("doctor")
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec doctor");
+
+    assert!(
+        !output.status.success(),
+        "airsspec doctor with integrity errors should exit with non-zero code",
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("2000000-orphaned-spec"),
+        "output should report the orphaned plan, got: {stdout}",
+    );
+    assert!(
+        stdout.contains("9999999-missing-spec"),
+        "output should report the dangling dependency, got: {stdout}",
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("integrity check failed"),
+        "stderr should mention the integrity failure, got: {stderr}",
+    );
+}
+
+#[test]
+fn test_validate_file_valid_spec() {
+    let temp = tempfile::tempdir().unwrap();
+    create_test_spec_yaml(temp.path());
+
+    let output = airsspec_cmd()
+        .args(["validate-file", "1000000-test-feature.yaml"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec validate-file");
+
+    assert!(
+        output.status.success(),
+        "valid spec file should exit with code 0, stderr: {}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("No issues found"),
+        "report should show no issues, got: {stdout}"
+    );
+}
+
+#[test]
+fn test_validate_file_invalid_spec() {
+    let temp = tempfile::tempdir().unwrap();
+    let spec_yaml = "\
+id: 1000000-untitled
+metadata:
+  title: ''
+  description: Missing a title
+  category: feature
+  dependencies: []
+  created_at: '2026-01-01T00:00:00Z'
+  updated_at: '2026-01-01T00:00:00Z'
+content: '# Untitled'
+";
+    fs::write(temp.path().join("1000000-untitled.yaml"), spec_yaml).unwrap();
+
+    let output = airsspec_cmd()
+        .args(["validate-file", "1000000-untitled.yaml"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec validate-file");
+
+    assert!(
+        !output.status.success(),
+        "spec file with errors should exit with non-zero code"
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Title cannot be empty"),
+        "report should list the error, got: {stdout}"
+    );
+}
+
+#[test]
+fn test_validate_file_unrecognized_type() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("notes.md"), "# Notes").unwrap();
+
+    let output = airsspec_cmd()
+        .args(["validate-file", "notes.md"])
+        .current_dir(temp.path())
+        .output()
+        .expect("failed to execute airsspec validate-file");
+
+    assert!(
+        !output.status.success(),
+        "unrecognized file should exit with non-zero code"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("unrecognized file type"),
+        "stderr should explain the file type is unknown, got: {stderr}"
+    );
+}
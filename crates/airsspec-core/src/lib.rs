@@ -130,7 +130,7 @@ pub use state::{BuildProgress, StateError, StateMachine, WorkflowState};
 
 // Validation framework re-exports
 pub use validation::{
-    DependencyValidator, DirectoryStructureValidator, SpecContentValidator,
+    DependencyValidator, DirectoryStructureValidator, PlanReferenceValidator, SpecContentValidator,
     StateTransitionValidator, ValidatablePlan, ValidatableSpec, ValidationContext,
     ValidationContextBuilder, ValidationIssue, ValidationReport, ValidationSeverity, Validator,
     ValidatorExt,
@@ -43,6 +43,15 @@ pub enum PlanError {
         total: usize,
     },
 
+    /// A snapshot was taken from a plan with a different number of steps.
+    #[error("snapshot has {snapshot} steps but plan has {total} steps")]
+    SnapshotMismatch {
+        /// The number of steps in the snapshot.
+        snapshot: usize,
+        /// The number of steps in the plan.
+        total: usize,
+    },
+
     /// I/O error (stored as string since `io::Error` doesn't impl Clone/Eq).
     #[error("I/O error: {0}")]
     Io(String),
@@ -100,6 +109,15 @@ mod tests {
         assert!(msg.contains("5 steps"));
     }
 
+    #[test]
+    fn test_snapshot_mismatch_error() {
+        let err = PlanError::SnapshotMismatch {
+            snapshot: 2,
+            total: 3,
+        };
+        assert_eq!(err.to_string(), "snapshot has 2 steps but plan has 3 steps");
+    }
+
     #[test]
     fn test_error_clone() {
         let err = PlanError::InvalidFormat("test".to_string());
@@ -10,6 +10,7 @@
 //! - [`Complexity`] - Complexity estimate for steps
 //! - [`PlanBuilder`] - Builder pattern for creating plans
 //! - [`StepBuilder`] - Builder pattern for creating steps
+//! - [`PlanSnapshot`] - Step statuses captured for undo
 //! - [`PlanError`] - Domain-specific errors
 //! - [`PlanStorage`] - Trait for plan persistence
 //!
@@ -64,6 +65,7 @@
 
 mod builder;
 mod error;
+mod snapshot;
 mod step;
 mod storage;
 mod types;
@@ -72,6 +74,7 @@ mod validator;
 
 pub use builder::PlanBuilder;
 pub use error::PlanError;
+pub use snapshot::PlanSnapshot;
 pub use step::{Complexity, PlanStep, StepBuilder, StepStatus};
 pub use storage::{PlanStorage, PlanStorageExt};
 pub use types::Plan;
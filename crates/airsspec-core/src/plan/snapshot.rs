@@ -0,0 +1,47 @@
+//! Point-in-time copies of plan step statuses.
+
+use serde::{Deserialize, Serialize};
+
+use super::step::StepStatus;
+
+/// The status of every step in a plan at one point in time, taken with
+/// [`Plan::snapshot`](super::Plan::snapshot) and reapplied with
+/// [`Plan::restore`](super::Plan::restore), e.g. to undo a failed build.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::SpecId;
+/// use airsspec_core::plan::{Plan, PlanStep, StepStatus};
+///
+/// let spec_id = SpecId::new(1_737_734_400, "test");
+/// let plan = Plan::new(spec_id, "Strategy", vec![PlanStep::new(0, "Step 1", "")]);
+///
+/// let snapshot = plan.snapshot();
+/// assert_eq!(snapshot.statuses(), [StepStatus::Pending]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanSnapshot {
+    /// Step statuses, in step order.
+    statuses: Vec<StepStatus>,
+}
+
+impl PlanSnapshot {
+    /// Creates a snapshot from step statuses, in step order.
+    #[must_use]
+    pub fn new(statuses: Vec<StepStatus>) -> Self {
+        Self { statuses }
+    }
+
+    /// Returns the captured step statuses, in step order.
+    #[must_use]
+    pub fn statuses(&self) -> &[StepStatus] {
+        &self.statuses
+    }
+
+    /// Returns the number of steps captured.
+    #[must_use]
+    pub fn step_count(&self) -> usize {
+        self.statuses.len()
+    }
+}
@@ -118,6 +118,14 @@ pub struct PlanStep {
 
     /// Implementation notes (filled in during build phase).
     notes: Option<String>,
+
+    /// Indices of the steps that must be finished before this one can start.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    depends_on: Vec<usize>,
+
+    /// Free-form labels for filtering (e.g. "db", "api").
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
 }
 
 impl PlanStep {
@@ -145,6 +153,8 @@ impl PlanStep {
             complexity: Complexity::default(),
             status: StepStatus::default(),
             notes: None,
+            depends_on: Vec::new(),
+            tags: Vec::new(),
         }
     }
 
@@ -184,6 +194,24 @@ impl PlanStep {
         self.notes.as_deref()
     }
 
+    /// Returns the indices of the steps this step depends on.
+    #[must_use]
+    pub fn depends_on(&self) -> &[usize] {
+        &self.depends_on
+    }
+
+    /// Returns the step's tags.
+    #[must_use]
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Returns true if the step carries `tag`.
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
     /// Sets the step complexity.
     pub fn set_complexity(&mut self, complexity: Complexity) {
         self.complexity = complexity;
@@ -199,6 +227,24 @@ impl PlanStep {
         self.notes = Some(notes.into());
     }
 
+    /// Sets the indices of the steps this step depends on.
+    pub fn set_depends_on(&mut self, depends_on: Vec<usize>) {
+        self.depends_on = depends_on;
+    }
+
+    /// Adds a tag, unless the step already carries it.
+    pub fn add_tag(&mut self, tag: impl Into<String>) {
+        let tag = tag.into();
+        if !self.has_tag(&tag) {
+            self.tags.push(tag);
+        }
+    }
+
+    /// Sets the step's tags, replacing any existing ones.
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags = tags;
+    }
+
     /// Clears implementation notes.
     pub fn clear_notes(&mut self) {
         self.notes = None;
@@ -244,6 +290,8 @@ pub struct StepBuilder {
     title: Option<String>,
     description: Option<String>,
     complexity: Option<Complexity>,
+    depends_on: Vec<usize>,
+    tags: Vec<String>,
 }
 
 impl StepBuilder {
@@ -281,6 +329,23 @@ impl StepBuilder {
         self
     }
 
+    /// Sets the indices of the steps this step depends on.
+    #[must_use]
+    pub fn depends_on(mut self, depends_on: Vec<usize>) -> Self {
+        self.depends_on = depends_on;
+        self
+    }
+
+    /// Adds a tag to the step, unless it is already present.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
     /// Builds the `PlanStep`.
     ///
     /// # Errors
@@ -307,6 +372,8 @@ impl StepBuilder {
             complexity: self.complexity.unwrap_or_default(),
             status: StepStatus::default(),
             notes: None,
+            depends_on: self.depends_on,
+            tags: self.tags,
         })
     }
 }
@@ -422,6 +489,62 @@ mod tests {
     }
 
     // StepBuilder tests
+    #[test]
+    fn test_plan_step_depends_on_serde() {
+        let mut step = PlanStep::new(1, "Dependent", "");
+        let json = serde_json::to_string(&step).unwrap();
+        assert!(!json.contains("depends_on"));
+
+        step.set_depends_on(vec![0]);
+        let json = serde_json::to_string(&step).unwrap();
+        let parsed: PlanStep = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.depends_on(), &[0]);
+    }
+
+    #[test]
+    fn test_plan_step_tags() {
+        let mut step = PlanStep::new(0, "Schema", "");
+        step.add_tag("db");
+        step.add_tag("db");
+        step.add_tag("migration");
+
+        assert_eq!(step.tags(), &["db", "migration"]);
+        assert!(step.has_tag("db"));
+        assert!(!step.has_tag("api"));
+    }
+
+    #[test]
+    fn test_step_builder_tags() {
+        let step = StepBuilder::new()
+            .index(0)
+            .title("Endpoints")
+            .tag("api")
+            .tag("http")
+            .build()
+            .unwrap();
+
+        assert_eq!(step.tags(), &["api", "http"]);
+    }
+
+    #[test]
+    fn test_builder_and_add_tag_deduplicate_alike() {
+        let built = StepBuilder::new()
+            .index(0)
+            .title("Schema")
+            .tag("db")
+            .tag("db")
+            .tag("migration")
+            .build()
+            .unwrap();
+        let mut added = PlanStep::new(0, "Schema", "");
+        for tag in ["db", "db", "migration"] {
+            added.add_tag(tag);
+        }
+
+        assert_eq!(built.tags(), &["db", "migration"]);
+        assert_eq!(built.tags(), added.tags());
+    }
+
     #[test]
     fn test_step_builder_minimal() {
         let step = StepBuilder::new().index(0).title("Test").build().unwrap();
@@ -2,13 +2,17 @@
 //!
 //! This module defines the main Plan type used throughout the system.
 
+use std::fmt::Write as _;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::spec::SpecId;
+use crate::utils::clock::{Clock, SystemClock};
 
 use super::error::PlanError;
-use super::step::{PlanStep, StepStatus};
+use super::snapshot::PlanSnapshot;
+use super::step::{Complexity, PlanStep, StepStatus};
 
 /// An implementation plan for a specification.
 ///
@@ -71,7 +75,18 @@ impl Plan {
     /// ```
     #[must_use]
     pub fn new(spec_id: SpecId, approach: impl Into<String>, steps: Vec<PlanStep>) -> Self {
-        let now = Utc::now();
+        Self::new_with_clock(spec_id, approach, steps, &SystemClock)
+    }
+
+    /// Creates a new plan, reading the creation time from `clock`.
+    #[must_use]
+    pub fn new_with_clock(
+        spec_id: SpecId,
+        approach: impl Into<String>,
+        steps: Vec<PlanStep>,
+        clock: &impl Clock,
+    ) -> Self {
+        let now = clock.now();
         Self {
             spec_id,
             approach: approach.into(),
@@ -133,6 +148,12 @@ impl Plan {
         self.steps.get_mut(index)
     }
 
+    /// Re-targets the plan at another spec, e.g. after the spec is renamed.
+    pub fn set_spec_id(&mut self, spec_id: SpecId) {
+        self.spec_id = spec_id;
+        self.touch();
+    }
+
     /// Sets the implementation approach.
     pub fn set_approach(&mut self, approach: impl Into<String>) {
         self.approach = approach.into();
@@ -147,7 +168,12 @@ impl Plan {
 
     /// Updates the `updated_at` timestamp to the current time.
     pub fn touch(&mut self) {
-        self.updated_at = Utc::now();
+        self.touch_with_clock(&SystemClock);
+    }
+
+    /// Updates the `updated_at` timestamp to the time reported by `clock`.
+    pub fn touch_with_clock(&mut self, clock: &impl Clock) {
+        self.updated_at = clock.now();
     }
 
     /// Marks a step as completed with optional notes.
@@ -171,6 +197,101 @@ impl Plan {
         Ok(())
     }
 
+    /// Marks the steps at `completed_indices` as completed, e.g. after a
+    /// passing verification run.
+    ///
+    /// Steps that are already completed are left as they are, and existing
+    /// notes are kept. All indices are checked before anything changes, so
+    /// an invalid index leaves the plan untouched.
+    ///
+    /// Returns the number of steps whose status changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PlanError::StepIndexOutOfBounds` for the first invalid index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecId;
+    /// use airsspec_core::plan::{Plan, PlanStep};
+    ///
+    /// let spec_id = SpecId::new(1_737_734_400, "test");
+    /// let mut plan = Plan::new(spec_id, "Strategy", vec![
+    ///     PlanStep::new(0, "Step 1", ""),
+    ///     PlanStep::new(1, "Step 2", ""),
+    /// ]);
+    ///
+    /// assert_eq!(plan.apply_verification(&[0, 1]).unwrap(), 2);
+    /// assert_eq!(plan.apply_verification(&[0, 1]).unwrap(), 0);
+    /// ```
+    pub fn apply_verification(&mut self, completed_indices: &[usize]) -> Result<usize, PlanError> {
+        let total = self.steps.len();
+        if let Some(&index) = completed_indices.iter().find(|&&index| index >= total) {
+            return Err(PlanError::StepIndexOutOfBounds { index, total });
+        }
+
+        let mut changed = 0;
+        for &index in completed_indices {
+            let step = &mut self.steps[index];
+            if !step.is_completed() {
+                step.set_status(StepStatus::Completed);
+                changed += 1;
+            }
+        }
+
+        if changed > 0 {
+            self.touch();
+        }
+        Ok(changed)
+    }
+
+    /// Captures the status of every step, to be reapplied later with
+    /// [`restore`](Self::restore).
+    #[must_use]
+    pub fn snapshot(&self) -> PlanSnapshot {
+        PlanSnapshot::new(self.steps.iter().map(PlanStep::status).collect())
+    }
+
+    /// Resets every step's status to the one captured in `snapshot`.
+    ///
+    /// Only statuses change; titles, notes and other step fields are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PlanError::SnapshotMismatch`, leaving the plan untouched, if
+    /// the snapshot's step count differs from the plan's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecId;
+    /// use airsspec_core::plan::{Plan, PlanStep};
+    ///
+    /// let spec_id = SpecId::new(1_737_734_400, "test");
+    /// let mut plan = Plan::new(spec_id, "Strategy", vec![PlanStep::new(0, "Step 1", "")]);
+    ///
+    /// let before = plan.snapshot();
+    /// plan.complete_step(0, None).unwrap();
+    /// plan.restore(&before).unwrap();
+    /// assert_eq!(plan.completed_steps(), 0);
+    /// ```
+    pub fn restore(&mut self, snapshot: &PlanSnapshot) -> Result<(), PlanError> {
+        let total = self.steps.len();
+        if snapshot.step_count() != total {
+            return Err(PlanError::SnapshotMismatch {
+                snapshot: snapshot.step_count(),
+                total,
+            });
+        }
+
+        for (step, &status) in self.steps.iter_mut().zip(snapshot.statuses()) {
+            step.set_status(status);
+        }
+        self.touch();
+        Ok(())
+    }
+
     /// Returns the completion percentage (0-100).
     ///
     /// # Examples
@@ -264,6 +385,198 @@ impl Plan {
             .iter()
             .position(|s| s.status() != StepStatus::Completed && s.status() != StepStatus::Skipped)
     }
+
+    /// Returns the steps carrying `tag`, in plan order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecId;
+    /// use airsspec_core::plan::{Plan, PlanStep};
+    ///
+    /// let mut schema = PlanStep::new(0, "Schema", "");
+    /// schema.add_tag("db");
+    /// let plan = Plan::new(
+    ///     SpecId::new(1_737_734_400, "user-auth"),
+    ///     "Incremental",
+    ///     vec![schema, PlanStep::new(1, "Endpoints", "")],
+    /// );
+    ///
+    /// assert_eq!(plan.steps_with_tag("db").len(), 1);
+    /// assert!(plan.steps_with_tag("api").is_empty());
+    /// ```
+    #[must_use]
+    pub fn steps_with_tag(&self, tag: &str) -> Vec<&PlanStep> {
+        self.steps.iter().filter(|step| step.has_tag(tag)).collect()
+    }
+
+    /// Returns the indices of the steps that can be worked on right now.
+    ///
+    /// A step is actionable when it is [`Pending`](StepStatus::Pending) and
+    /// every step it [depends on](PlanStep::depends_on) is completed or
+    /// skipped. A dependency on an index outside the plan is never
+    /// satisfied. Indices are returned in plan order; a plan with nothing
+    /// left to start returns an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecId;
+    /// use airsspec_core::plan::{Plan, PlanStep};
+    ///
+    /// let mut api = PlanStep::new(1, "Implement API", "");
+    /// api.set_depends_on(vec![0]);
+    /// let mut plan = Plan::new(
+    ///     SpecId::new(1_737_734_400, "user-auth"),
+    ///     "Incremental",
+    ///     vec![PlanStep::new(0, "Setup database", ""), api],
+    /// );
+    ///
+    /// assert_eq!(plan.next_actionable_steps(), vec![0]);
+    /// plan.complete_step(0, None).unwrap();
+    /// assert_eq!(plan.next_actionable_steps(), vec![1]);
+    /// ```
+    #[must_use]
+    pub fn next_actionable_steps(&self) -> Vec<usize> {
+        let is_done = |index: &usize| {
+            self.steps.get(*index).is_some_and(|step| {
+                matches!(step.status(), StepStatus::Completed | StepStatus::Skipped)
+            })
+        };
+        self.steps
+            .iter()
+            .enumerate()
+            .filter(|(_, step)| {
+                step.status() == StepStatus::Pending && step.depends_on().iter().all(is_done)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Renders the plan as a GitHub-style Markdown checklist.
+    ///
+    /// Completed steps are checked; every other status is unchecked. Each
+    /// item shows the step title and complexity, under a heading and a
+    /// progress line based on [`completion_percentage`](Self::completion_percentage).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecId;
+    /// use airsspec_core::plan::{Plan, PlanStep};
+    ///
+    /// let mut plan = Plan::new(
+    ///     SpecId::new(1_737_734_400, "user-auth"),
+    ///     "Incremental",
+    ///     vec![PlanStep::new(0, "Setup database", "")],
+    /// );
+    /// plan.complete_step(0, None).unwrap();
+    ///
+    /// assert!(plan.to_markdown_checklist().contains("- [x] Setup database (medium)"));
+    /// ```
+    #[must_use]
+    pub fn to_markdown_checklist(&self) -> String {
+        let mut output = format!("## Plan: {}\n\n", self.spec_id);
+        let _ = writeln!(
+            output,
+            "Progress: {}% ({}/{} steps)\n",
+            self.completion_percentage(),
+            self.completed_steps(),
+            self.steps.len()
+        );
+        for step in &self.steps {
+            let mark = if step.is_completed() { 'x' } else { ' ' };
+            let _ = writeln!(
+                output,
+                "- [{mark}] {} ({})",
+                step.title(),
+                step.complexity()
+            );
+        }
+        output
+    }
+
+    /// Builds a plan from a Markdown checklist, the inverse of
+    /// [`to_markdown_checklist`](Self::to_markdown_checklist).
+    ///
+    /// Each `- [ ]` line becomes a pending step and each `- [x]` line a
+    /// completed one, in order, with the item text as its title. A trailing
+    /// complexity such as `(simple)`, as written by `to_markdown_checklist`,
+    /// sets the step's complexity instead of staying in the title. All other
+    /// lines are ignored, so text without a checklist yields a plan with no
+    /// steps. The plan's approach is left empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlanError::InvalidFormat`] if a checklist item has no title.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecId;
+    /// use airsspec_core::plan::{Plan, StepStatus};
+    ///
+    /// let text = "- [x] Setup database\n- [ ] Add login (simple)\n";
+    /// let plan = Plan::from_markdown_checklist(text, SpecId::new(1_737_734_400, "user-auth"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(plan.step_count(), 2);
+    /// assert_eq!(plan.steps()[0].status(), StepStatus::Completed);
+    /// assert_eq!(plan.steps()[1].title(), "Add login");
+    /// ```
+    pub fn from_markdown_checklist(text: &str, spec_id: SpecId) -> Result<Self, PlanError> {
+        let mut steps = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            let (checked, item) = if let Some(item) = line.strip_prefix("- [ ]") {
+                (false, item)
+            } else if let Some(item) = line
+                .strip_prefix("- [x]")
+                .or_else(|| line.strip_prefix("- [X]"))
+            {
+                (true, item)
+            } else {
+                continue;
+            };
+
+            let (title, complexity) = split_complexity(item.trim());
+            if title.is_empty() {
+                return Err(PlanError::InvalidFormat(format!(
+                    "checklist item on line {} has no title",
+                    number + 1
+                )));
+            }
+
+            let mut step = PlanStep::new(steps.len(), title, "");
+            if let Some(complexity) = complexity {
+                step.set_complexity(complexity);
+            }
+            if checked {
+                step.set_status(StepStatus::Completed);
+            }
+            steps.push(step);
+        }
+
+        Ok(Self::new(spec_id, "", steps))
+    }
+}
+
+/// Splits a trailing `(complexity)` off a checklist item, if present.
+fn split_complexity(item: &str) -> (&str, Option<Complexity>) {
+    let parsed = item
+        .strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once(" ("))
+        .and_then(|(title, label)| {
+            let complexity = match label {
+                "trivial" => Complexity::Trivial,
+                "simple" => Complexity::Simple,
+                "medium" => Complexity::Medium,
+                "complex" => Complexity::Complex,
+                _ => return None,
+            };
+            Some((title.trim_end(), Some(complexity)))
+        });
+    parsed.unwrap_or((item, None))
 }
 
 #[cfg(test)]
@@ -330,6 +643,16 @@ mod tests {
         assert!(plan.updated_at() > initial_updated);
     }
 
+    #[test]
+    fn test_plan_set_spec_id() {
+        let mut plan = Plan::new(test_spec_id(), "Approach", test_steps());
+        let renamed = SpecId::new(1_737_734_400, "renamed-spec");
+
+        plan.set_spec_id(renamed.clone());
+
+        assert_eq!(plan.spec_id(), &renamed);
+    }
+
     #[test]
     fn test_plan_add_step() {
         let mut plan = Plan::new(test_spec_id(), "Approach", test_steps());
@@ -364,6 +687,83 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_apply_verification_marks_steps() {
+        let mut plan = Plan::new(test_spec_id(), "Approach", test_steps());
+        plan.step_mut(2).unwrap().set_notes("Keep me");
+
+        let changed = plan.apply_verification(&[0, 2]).unwrap();
+
+        assert_eq!(changed, 2);
+        assert!(plan.step(0).unwrap().is_completed());
+        assert!(!plan.step(1).unwrap().is_completed());
+        assert!(plan.step(2).unwrap().is_completed());
+        assert_eq!(plan.step(2).unwrap().notes(), Some("Keep me"));
+    }
+
+    #[test]
+    fn test_apply_verification_out_of_range_changes_nothing() {
+        let mut plan = Plan::new(test_spec_id(), "Approach", test_steps());
+
+        let result = plan.apply_verification(&[0, 7]);
+
+        assert_eq!(
+            result,
+            Err(PlanError::StepIndexOutOfBounds { index: 7, total: 3 })
+        );
+        assert_eq!(plan.completed_steps(), 0);
+    }
+
+    #[test]
+    fn test_apply_verification_is_idempotent() {
+        let mut plan = Plan::new(test_spec_id(), "Approach", test_steps());
+        plan.complete_step(1, None).unwrap();
+
+        assert_eq!(plan.apply_verification(&[0, 1, 1]).unwrap(), 1);
+        assert_eq!(plan.apply_verification(&[0, 1]).unwrap(), 0);
+        assert_eq!(plan.completed_steps(), 2);
+    }
+
+    #[test]
+    fn test_snapshot_restore_roundtrip() {
+        let mut plan = Plan::new(test_spec_id(), "Approach", test_steps());
+        plan.step_mut(1).unwrap().set_status(StepStatus::InProgress);
+        let before = plan.snapshot();
+
+        plan.complete_step(0, Some("Done".to_string())).unwrap();
+        plan.complete_step(1, None).unwrap();
+        plan.step_mut(2).unwrap().set_status(StepStatus::Blocked);
+        plan.restore(&before).unwrap();
+
+        assert_eq!(plan.snapshot(), before);
+        assert_eq!(
+            before.statuses(),
+            [
+                StepStatus::Pending,
+                StepStatus::InProgress,
+                StepStatus::Pending
+            ]
+        );
+        assert_eq!(plan.step(0).unwrap().notes(), Some("Done"));
+    }
+
+    #[test]
+    fn test_restore_with_mismatched_step_count_changes_nothing() {
+        let mut plan = Plan::new(test_spec_id(), "Approach", test_steps());
+        let snapshot = PlanSnapshot::new(vec![StepStatus::Completed; 2]);
+
+        let result = plan.restore(&snapshot);
+
+        assert_eq!(
+            result,
+            Err(PlanError::SnapshotMismatch {
+                snapshot: 2,
+                total: 3
+            })
+        );
+        assert_eq!(plan.completed_steps(), 0);
+    }
+
     #[test]
     fn test_plan_completion_percentage() {
         let mut plan = Plan::new(test_spec_id(), "Approach", test_steps());
@@ -464,4 +864,164 @@ mod tests {
         let cloned = plan.clone();
         assert_eq!(plan, cloned);
     }
+
+    #[test]
+    fn test_plan_to_markdown_checklist() {
+        let mut plan = Plan::new(test_spec_id(), "Approach", test_steps());
+        plan.complete_step(0, None).unwrap();
+        plan.step_mut(1).unwrap().set_status(StepStatus::InProgress);
+
+        let markdown = plan.to_markdown_checklist();
+
+        let expected_progress = format!(
+            "Progress: {}% (1/{} steps)",
+            plan.completion_percentage(),
+            plan.step_count()
+        );
+        assert!(markdown.starts_with("## Plan: 1737734400-test-spec\n"));
+        assert!(markdown.contains(&expected_progress));
+        assert!(markdown.contains("- [x] Step 1 (medium)\n"));
+        assert!(markdown.contains("- [ ] Step 2 (medium)\n"));
+    }
+
+    #[test]
+    fn test_from_markdown_checklist_roundtrips_statuses() {
+        let mut steps = test_steps();
+        steps[2].set_complexity(Complexity::Simple);
+        let mut plan = Plan::new(test_spec_id(), "Approach", steps);
+        plan.complete_step(0, None).unwrap();
+        plan.complete_step(2, None).unwrap();
+
+        let parsed =
+            Plan::from_markdown_checklist(&plan.to_markdown_checklist(), test_spec_id()).unwrap();
+
+        let summary: Vec<(usize, &str, StepStatus, Complexity)> = parsed
+            .steps()
+            .iter()
+            .map(|s| (s.index(), s.title(), s.status(), s.complexity()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (0, "Step 1", StepStatus::Completed, Complexity::Medium),
+                (1, "Step 2", StepStatus::Pending, Complexity::Medium),
+                (2, "Step 3", StepStatus::Completed, Complexity::Simple),
+            ]
+        );
+        assert_eq!(parsed.spec_id(), &test_spec_id());
+    }
+
+    #[test]
+    fn test_from_markdown_checklist_keeps_unknown_parentheses_in_title() {
+        let text = "Intro\n  - [X] Write docs (draft)\n* [ ] Not a step\n";
+
+        let plan = Plan::from_markdown_checklist(text, test_spec_id()).unwrap();
+
+        assert_eq!(plan.step_count(), 1);
+        assert_eq!(plan.steps()[0].title(), "Write docs (draft)");
+        assert!(plan.steps()[0].is_completed());
+    }
+
+    #[test]
+    fn test_from_markdown_checklist_without_items_is_empty() {
+        let plan = Plan::from_markdown_checklist("# Notes\n\nNothing to do yet.\n", test_spec_id())
+            .unwrap();
+
+        assert_eq!(plan.step_count(), 0);
+    }
+
+    #[test]
+    fn test_from_markdown_checklist_rejects_empty_item() {
+        let result = Plan::from_markdown_checklist("- [ ] Setup\n- [ ]\n", test_spec_id());
+
+        assert!(matches!(result, Err(PlanError::InvalidFormat(msg)) if msg.contains("line 2")));
+    }
+
+    #[test]
+    fn test_steps_with_tag() {
+        let mut steps = test_steps();
+        steps[0].add_tag("db");
+        steps[1].add_tag("api");
+        steps[2].set_tags(vec!["db".to_string(), "test".to_string()]);
+        let plan = Plan::new(test_spec_id(), "Tagged", steps);
+
+        let titles: Vec<&str> = plan
+            .steps_with_tag("db")
+            .iter()
+            .map(|s| s.title())
+            .collect();
+        assert_eq!(titles, vec!["Step 1", "Step 3"]);
+        assert_eq!(plan.steps_with_tag("api").len(), 1);
+        assert!(plan.steps_with_tag("ui").is_empty());
+    }
+
+    /// Diamond: 0 -> {1, 2} -> 3.
+    fn diamond() -> Plan {
+        let mut steps = vec![
+            PlanStep::new(0, "Root", ""),
+            PlanStep::new(1, "Left", ""),
+            PlanStep::new(2, "Right", ""),
+            PlanStep::new(3, "Join", ""),
+        ];
+        steps[1].set_depends_on(vec![0]);
+        steps[2].set_depends_on(vec![0]);
+        steps[3].set_depends_on(vec![1, 2]);
+        Plan::new(test_spec_id(), "Diamond", steps)
+    }
+
+    #[test]
+    fn test_next_actionable_steps_diamond() {
+        let mut plan = diamond();
+        assert_eq!(plan.next_actionable_steps(), vec![0]);
+
+        plan.complete_step(0, None).unwrap();
+        assert_eq!(plan.next_actionable_steps(), vec![1, 2]);
+
+        plan.complete_step(1, None).unwrap();
+        assert_eq!(plan.next_actionable_steps(), vec![2]);
+
+        plan.complete_step(2, None).unwrap();
+        assert_eq!(plan.next_actionable_steps(), vec![3]);
+    }
+
+    #[test]
+    fn test_next_actionable_steps_excludes_started_and_done() {
+        let mut plan = diamond();
+        plan.complete_step(0, None).unwrap();
+        plan.step_mut(1).unwrap().set_status(StepStatus::InProgress);
+        assert_eq!(plan.next_actionable_steps(), vec![2]);
+
+        plan.apply_verification(&[1, 2, 3]).unwrap();
+        assert!(plan.next_actionable_steps().is_empty());
+    }
+
+    #[test]
+    fn test_next_actionable_steps_skipped_and_missing_dependencies() {
+        let mut plan = diamond();
+        plan.step_mut(0).unwrap().set_status(StepStatus::Skipped);
+        plan.step_mut(2).unwrap().set_depends_on(vec![0, 9]);
+
+        assert_eq!(plan.next_actionable_steps(), vec![1]);
+    }
+
+    #[test]
+    fn test_new_and_touch_with_fixed_clock() {
+        use crate::utils::clock::FixedClock;
+
+        let created = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+        let later = DateTime::from_timestamp(1_737_738_000, 0).unwrap();
+
+        let mut plan = Plan::new_with_clock(
+            test_spec_id(),
+            "Approach",
+            vec![],
+            &FixedClock::new(created),
+        );
+        assert_eq!(plan.created_at(), created);
+        assert_eq!(plan.updated_at(), created);
+
+        plan.touch_with_clock(&FixedClock::new(later));
+        assert_eq!(plan.created_at(), created);
+        assert_eq!(plan.updated_at(), later);
+    }
 }
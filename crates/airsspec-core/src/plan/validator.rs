@@ -3,9 +3,9 @@
 //! This module provides validation for implementation plans
 //! using the validation framework from [`crate::validation`].
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
-use super::step::StepStatus;
+use super::step::{PlanStep, StepStatus};
 use super::types::Plan;
 
 // Re-export validation types from validation module for convenience
@@ -16,7 +16,8 @@ pub use crate::validation::{ValidationIssue, ValidationReport, ValidationSeverit
 /// Currently validates:
 /// - Plan has at least one step
 /// - Step indices are sequential (0, 1, 2, ...)
-/// - No duplicate step indices
+/// - No duplicate step indices (error)
+/// - No gaps between step indices (warning)
 /// - Approach is recommended (warning if empty)
 /// - Steps have titles (error if empty)
 /// - Blocked steps should have notes (warning)
@@ -84,7 +85,10 @@ fn validate_approach(plan: &Plan, report: &mut ValidationReport) {
     }
 }
 
-/// Validates step indices are sequential and unique.
+/// Validates step indices are sequential, unique, and contiguous.
+///
+/// Every duplicate and every gap is reported, not just the first. A gap is
+/// reported once as a range, however many indices it skips.
 fn validate_step_indices(plan: &Plan, report: &mut ValidationReport) {
     let steps = plan.steps();
     if steps.is_empty() {
@@ -110,11 +114,29 @@ fn validate_step_indices(plan: &Plan, report: &mut ValidationReport) {
     for (pos, step) in steps.iter().enumerate() {
         if !seen_indices.insert(step.index()) {
             report.add_issue(
-                ValidationIssue::warning(format!("Duplicate step index: {}", step.index()))
+                ValidationIssue::error(format!("Duplicate step index: {}", step.index()))
                     .with_field(format!("steps[{pos}].index")),
             );
         }
     }
+
+    // Check for gaps between the indices in use (e.g. 0, 1, 3 skips 2)
+    let indices: BTreeSet<usize> = steps.iter().map(PlanStep::index).collect();
+    let mut next = 0;
+    for &index in &indices {
+        if index > next {
+            let skipped = if index - 1 == next {
+                next.to_string()
+            } else {
+                format!("{next}..={}", index - 1)
+            };
+            report.add_issue(
+                ValidationIssue::warning(format!("Step indices skip {skipped}"))
+                    .with_field("steps"),
+            );
+        }
+        next = index.saturating_add(1);
+    }
 }
 
 /// Validates step content.
@@ -164,7 +186,6 @@ fn validate_blocked_steps(plan: &Plan, report: &mut ValidationReport) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::plan::step::PlanStep;
     use crate::spec::SpecId;
 
     fn test_spec_id() -> SpecId {
@@ -241,6 +262,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_duplicate_step_indices_are_errors() {
+        let plan = Plan::new(
+            test_spec_id(),
+            "Approach",
+            vec![
+                PlanStep::new(0, "Step 0", "First"),
+                PlanStep::new(1, "Step 1", "Second"),
+                PlanStep::new(1, "Step 1 again", "Third"),
+                PlanStep::new(0, "Step 0 again", "Fourth"),
+            ],
+        );
+
+        let report = validate_plan(&plan);
+
+        assert!(!report.is_valid());
+        let duplicates: Vec<(&str, Option<&str>)> = report
+            .errors()
+            .iter()
+            .filter(|e| e.message().starts_with("Duplicate step index"))
+            .map(|e| (e.message(), e.field()))
+            .collect();
+        assert_eq!(
+            duplicates,
+            [
+                ("Duplicate step index: 1", Some("steps[2].index")),
+                ("Duplicate step index: 0", Some("steps[3].index")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_step_index_gap_is_warning() {
+        let plan = Plan::new(
+            test_spec_id(),
+            "Approach",
+            vec![
+                PlanStep::new(0, "Step 0", "First"),
+                PlanStep::new(1, "Step 1", "Second"),
+                PlanStep::new(3, "Step 3", "Fourth"),
+            ],
+        );
+
+        let report = validate_plan(&plan);
+
+        assert!(report.is_valid());
+        let gaps: Vec<&str> = report
+            .warnings()
+            .iter()
+            .filter(|w| w.field() == Some("steps"))
+            .map(|w| w.message())
+            .collect();
+        assert_eq!(gaps, ["Step indices skip 2"]);
+    }
+
+    #[test]
+    fn test_validate_step_index_gap_is_one_issue_per_range() {
+        let plan = Plan::new(
+            test_spec_id(),
+            "Approach",
+            vec![
+                PlanStep::new(2, "Step 2", "Third"),
+                PlanStep::new(5, "Step 5", "Sixth"),
+                PlanStep::new(usize::MAX, "Last", "Far away"),
+            ],
+        );
+
+        let report = validate_plan(&plan);
+
+        let gaps: Vec<&str> = report
+            .warnings()
+            .iter()
+            .filter(|w| w.field() == Some("steps"))
+            .map(|w| w.message())
+            .collect();
+        assert_eq!(
+            gaps,
+            [
+                "Step indices skip 0..=1".to_string(),
+                "Step indices skip 3..=4".to_string(),
+                format!("Step indices skip 6..={}", usize::MAX - 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_empty_plan_has_no_index_issues() {
+        let plan = Plan::new(test_spec_id(), "Approach", vec![]);
+
+        let report = validate_plan(&plan);
+
+        let messages: Vec<&str> = report
+            .issues()
+            .iter()
+            .map(ValidationIssue::message)
+            .collect();
+        assert_eq!(messages, ["Plan must have at least one step"]);
+    }
+
     #[test]
     fn test_validate_empty_step_title() {
         // For testing, we deserialize a plan with empty title
@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 /// Lifecycle state of a specification or plan.
 ///
 /// State transitions are managed by [`StateMachine`](crate::state::StateMachine).
+/// States are ordered by declaration, which is also the board column order
+/// used by [`group_specs_by_state`](crate::spec::group_specs_by_state).
 ///
 /// # State Transition Diagram
 ///
@@ -28,7 +30,9 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(format!("{state}"), "draft");
 /// ```
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum LifecycleState {
     /// Work in progress, not ready for implementation.
@@ -0,0 +1,37 @@
+//! Sign-offs recorded on specifications.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A sign-off on a specification, e.g. before it becomes active.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::Approval;
+/// use chrono::DateTime;
+///
+/// let at = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+/// let approval = Approval::new("alice", at);
+///
+/// assert_eq!(approval.approver, "alice");
+/// assert_eq!(approval.approved_at, at);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Approval {
+    /// Name of the person who signed off.
+    pub approver: String,
+    /// When the sign-off was given.
+    pub approved_at: DateTime<Utc>,
+}
+
+impl Approval {
+    /// Creates a new approval.
+    #[must_use]
+    pub fn new(approver: impl Into<String>, approved_at: DateTime<Utc>) -> Self {
+        Self {
+            approver: approver.into(),
+            approved_at,
+        }
+    }
+}
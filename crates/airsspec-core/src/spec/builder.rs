@@ -3,12 +3,12 @@
 //! Per ADR-002 (modular monolith), the builder is in its own file.
 //! This provides a fluent API for creating specs with validation on build.
 
-use chrono::Utc;
+use crate::utils::clock::{Clock, SystemClock};
 
 use super::category::Category;
 use super::dependency::Dependency;
 use super::error::SpecError;
-use super::id::SpecId;
+use super::id::{IdGranularity, SpecId};
 use super::types::{Spec, SpecMetadata};
 
 /// Builder for constructing [`Spec`] instances with validation.
@@ -26,6 +26,7 @@ use super::types::{Spec, SpecMetadata};
 /// - `category` - Defaults to `Category::Feature`
 /// - `content` - Defaults to empty string
 /// - `dependencies` - Defaults to empty vec
+/// - `id` / `timestamp` - Default to an ID generated from the clock and title
 ///
 /// # Examples
 ///
@@ -54,11 +55,23 @@ pub struct SpecBuilder {
     /// The category of the specification.
     category: Option<Category>,
 
+    /// The owner of the specification.
+    owner: Option<String>,
+
     /// Dependencies on other specifications.
     dependencies: Vec<Dependency>,
 
     /// The content of the specification.
     content: Option<String>,
+
+    /// Resolution of the ID timestamp.
+    id_granularity: IdGranularity,
+
+    /// Explicit ID, used instead of generating one.
+    id: Option<SpecId>,
+
+    /// Explicit ID timestamp, used instead of the clock.
+    timestamp: Option<i64>,
 }
 
 impl SpecBuilder {
@@ -139,6 +152,30 @@ impl SpecBuilder {
         self
     }
 
+    /// Sets the owner of the specification.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Person or team accountable for the spec
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecBuilder;
+    ///
+    /// let spec = SpecBuilder::new()
+    ///     .title("Feature")
+    ///     .owner("platform-team")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(spec.owner(), Some("platform-team"));
+    /// ```
+    #[must_use]
+    pub fn owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
     /// Adds a dependency to the specification.
     ///
     /// Can be called multiple times to add multiple dependencies.
@@ -212,10 +249,88 @@ impl SpecBuilder {
         self
     }
 
+    /// Sets the resolution of the generated ID's timestamp.
+    ///
+    /// Defaults to [`IdGranularity::Seconds`]; typically set from
+    /// [`ProjectConfig::id_granularity`](crate::workspace::ProjectConfig::id_granularity).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{IdGranularity, SpecBuilder};
+    /// use airsspec_core::utils::clock::FixedClock;
+    /// use chrono::DateTime;
+    ///
+    /// let at = DateTime::from_timestamp_millis(1_737_734_400_123).unwrap();
+    /// let spec = SpecBuilder::new()
+    ///     .title("User Auth")
+    ///     .id_granularity(IdGranularity::Millis)
+    ///     .build_with_clock(&FixedClock::new(at))
+    ///     .unwrap();
+    /// assert_eq!(spec.id().as_str(), "1737734400123-user-auth");
+    /// ```
+    #[must_use]
+    pub fn id_granularity(mut self, id_granularity: IdGranularity) -> Self {
+        self.id_granularity = id_granularity;
+        self
+    }
+
+    /// Uses `id` as the spec ID instead of generating one, e.g. when
+    /// re-importing an exported spec.
+    ///
+    /// The title is then not turned into a slug, and any
+    /// [`timestamp`](Self::timestamp) or
+    /// [`id_granularity`](Self::id_granularity) is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{SpecBuilder, SpecId};
+    ///
+    /// let spec = SpecBuilder::new()
+    ///     .title("Renamed Later")
+    ///     .id(SpecId::new(1_737_734_400, "user-auth"))
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(spec.id().as_str(), "1737734400-user-auth");
+    /// ```
+    #[must_use]
+    pub fn id(mut self, id: SpecId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Uses `timestamp` for the generated spec ID instead of reading the
+    /// clock. The slug is still derived from the title.
+    ///
+    /// The value is used as given, so it should match the
+    /// [`id_granularity`](Self::id_granularity) in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecBuilder;
+    ///
+    /// let spec = SpecBuilder::new()
+    ///     .title("User Auth")
+    ///     .timestamp(1_737_734_400)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(spec.id().as_str(), "1737734400-user-auth");
+    /// ```
+    #[must_use]
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
     /// Builds the specification, validating all required fields.
     ///
-    /// Generates a `SpecId` from the current timestamp and a slug derived
-    /// from the title.
+    /// Unless an [`id`](Self::id) was given, generates a `SpecId` from the
+    /// current (or [given](Self::timestamp)) timestamp and a slug derived
+    /// from the title. Dependencies are sorted by spec ID then kind, and
+    /// exact duplicates are dropped, so the saved spec is stable regardless
+    /// of the order they were added in.
     ///
     /// # Errors
     ///
@@ -238,6 +353,31 @@ impl SpecBuilder {
     /// assert!(result.is_err());
     /// ```
     pub fn build(self) -> Result<Spec, SpecError> {
+        self.build_with_clock(&SystemClock)
+    }
+
+    /// Builds the specification, reading the ID timestamp and metadata
+    /// timestamps from `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`build`](Self::build).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecBuilder;
+    /// use airsspec_core::utils::clock::FixedClock;
+    /// use chrono::DateTime;
+    ///
+    /// let at = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+    /// let spec = SpecBuilder::new()
+    ///     .title("User Auth")
+    ///     .build_with_clock(&FixedClock::new(at))
+    ///     .unwrap();
+    /// assert_eq!(spec.id().as_str(), "1737734400-user-auth");
+    /// ```
+    pub fn build_with_clock(self, clock: &impl Clock) -> Result<Spec, SpecError> {
         // Validate required field: title
         let title = self
             .title
@@ -247,22 +387,34 @@ impl SpecBuilder {
             return Err(SpecError::MissingField("title cannot be empty".to_string()));
         }
 
-        // Generate slug from title
-        let slug = generate_slug(&title);
-
-        // Create SpecId with current timestamp
-        let timestamp = Utc::now().timestamp();
-        let id = SpecId::try_new(timestamp, &slug)?;
+        // Use the explicit ID, or generate one from the title and timestamp
+        let id = if let Some(id) = self.id {
+            id
+        } else {
+            let slug = generate_slug(&title);
+            let timestamp = self
+                .timestamp
+                .unwrap_or_else(|| self.id_granularity.timestamp_of(clock.now()));
+            SpecId::try_new(timestamp, &slug)?
+        };
 
         // Build metadata with defaults for optional fields
-        let mut metadata = SpecMetadata::new(title, self.description.unwrap_or_default());
+        let mut metadata =
+            SpecMetadata::new_with_clock(title, self.description.unwrap_or_default(), clock);
 
         if let Some(category) = self.category {
             metadata.set_category(category);
         }
 
+        if let Some(owner) = self.owner {
+            metadata.set_owner(owner);
+        }
+
         if !self.dependencies.is_empty() {
-            metadata.set_dependencies(self.dependencies);
+            let mut dependencies = self.dependencies;
+            dependencies.sort();
+            dependencies.dedup();
+            metadata.set_dependencies(dependencies);
         }
 
         // Build the spec
@@ -360,6 +512,7 @@ mod tests {
             .title("Full Spec")
             .description("A complete specification")
             .category(Category::BugFix)
+            .owner("platform-team")
             .dependency(dep)
             .content("# Full Spec\n\nContent here.")
             .build()
@@ -368,6 +521,7 @@ mod tests {
         assert_eq!(spec.title(), "Full Spec");
         assert_eq!(spec.description(), "A complete specification");
         assert_eq!(spec.category(), Category::BugFix);
+        assert_eq!(spec.owner(), Some("platform-team"));
         assert_eq!(spec.dependencies().len(), 1);
         assert_eq!(spec.content(), "# Full Spec\n\nContent here.");
     }
@@ -388,6 +542,69 @@ mod tests {
         assert_eq!(spec.dependencies().len(), 2);
     }
 
+    #[test]
+    fn test_builder_removes_exact_duplicate_dependencies() {
+        let dep_id = SpecId::new(1_737_734_400, "dep");
+
+        let spec = SpecBuilder::new()
+            .title("Dup Dep")
+            .dependency(Dependency::blocked_by(dep_id.clone()))
+            .dependency(Dependency::blocked_by(dep_id.clone()))
+            .build()
+            .unwrap();
+
+        assert_eq!(spec.dependencies(), &[Dependency::blocked_by(dep_id)]);
+    }
+
+    #[test]
+    fn test_builder_keeps_distinct_kinds_to_same_spec() {
+        let dep_id = SpecId::new(1_737_734_400, "dep");
+
+        let spec = SpecBuilder::new()
+            .title("Two Kinds")
+            .dependency(Dependency::related_to(dep_id.clone()))
+            .dependency(Dependency::blocked_by(dep_id.clone()))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            spec.dependencies(),
+            &[
+                Dependency::blocked_by(dep_id.clone()),
+                Dependency::related_to(dep_id),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_builder_sorts_dependencies() {
+        let first = SpecId::new(1_737_734_400, "alpha");
+        let second = SpecId::new(1_737_734_400, "beta");
+        let third = SpecId::new(1_737_734_500, "alpha");
+        let build = |deps: Vec<Dependency>| {
+            SpecBuilder::new()
+                .title("Sorted")
+                .dependencies(deps)
+                .build()
+                .unwrap()
+        };
+
+        let forward = build(vec![
+            Dependency::blocked_by(first.clone()),
+            Dependency::related_to(second.clone()),
+            Dependency::blocked_by(third.clone()),
+        ]);
+        let reversed = build(vec![
+            Dependency::blocked_by(third.clone()),
+            Dependency::related_to(second.clone()),
+            Dependency::blocked_by(first.clone()),
+        ]);
+
+        let ids: Vec<&SpecId> = forward.dependencies().iter().map(|d| &d.spec_id).collect();
+        assert_eq!(ids, vec![&first, &second, &third]);
+        assert_eq!(forward.dependencies(), reversed.dependencies());
+    }
+
     #[test]
     fn test_builder_missing_title() {
         let result = SpecBuilder::new().build();
@@ -499,4 +716,95 @@ mod tests {
         assert!(debug.contains("SpecBuilder"));
         assert!(debug.contains("Debug Test"));
     }
+
+    #[test]
+    fn test_build_with_fixed_clock() {
+        let at = chrono::DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+        let clock = crate::utils::clock::FixedClock::new(at);
+
+        let spec = SpecBuilder::new()
+            .title("Clocked Spec")
+            .build_with_clock(&clock)
+            .unwrap();
+
+        assert_eq!(spec.id().as_str(), "1737734400-clocked-spec");
+        assert_eq!(spec.metadata().created_at(), at);
+        assert_eq!(spec.metadata().updated_at(), at);
+    }
+
+    /// Counts distinct IDs among 100 same-title specs built 3ms apart.
+    fn distinct_ids_in_burst(id_granularity: IdGranularity) -> usize {
+        use std::cell::Cell;
+        use std::collections::HashSet;
+
+        struct SteppingClock(Cell<chrono::DateTime<chrono::Utc>>);
+        impl Clock for SteppingClock {
+            fn now(&self) -> chrono::DateTime<chrono::Utc> {
+                self.0.get()
+            }
+        }
+
+        let start = chrono::DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+        let clock = SteppingClock(Cell::new(start));
+        let mut ids = HashSet::new();
+        for _ in 0..100 {
+            let spec = SpecBuilder::new()
+                .title("Bulk Import")
+                .id_granularity(id_granularity)
+                .build_with_clock(&clock)
+                .unwrap();
+            ids.insert(spec.id().clone());
+            clock
+                .0
+                .set(clock.0.get() + chrono::Duration::milliseconds(3));
+        }
+        ids.len()
+    }
+
+    #[test]
+    fn test_millisecond_ids_reduce_collisions() {
+        assert_eq!(distinct_ids_in_burst(IdGranularity::Seconds), 1);
+        assert_eq!(distinct_ids_in_burst(IdGranularity::Millis), 100);
+    }
+
+    #[test]
+    fn test_explicit_timestamp_is_deterministic_and_keeps_title_slug() {
+        let build = || {
+            SpecBuilder::new()
+                .title("User Auth")
+                .timestamp(1_737_734_400)
+                .build()
+                .unwrap()
+        };
+
+        let first = build();
+        let second = build();
+
+        assert_eq!(first.id().as_str(), "1737734400-user-auth");
+        assert_eq!(first.id(), second.id());
+    }
+
+    #[test]
+    fn test_explicit_id_skips_slug_generation() {
+        let id = SpecId::new(1_000, "kept-slug");
+
+        let spec = SpecBuilder::new()
+            .title("Completely Different Title")
+            .timestamp(2_000)
+            .id_granularity(IdGranularity::Millis)
+            .id(id.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(spec.id(), &id);
+        assert_eq!(spec.title(), "Completely Different Title");
+    }
+
+    #[test]
+    fn test_explicit_id_still_requires_title() {
+        let result = SpecBuilder::new()
+            .id(SpecId::new(1_000, "no-title"))
+            .build();
+        assert!(matches!(result, Err(SpecError::MissingField(_))));
+    }
 }
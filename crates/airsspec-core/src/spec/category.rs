@@ -1,7 +1,13 @@
 //! Specification categorization.
 
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+use crate::shared::Phase;
+
+use super::error::SpecError;
+
 /// Category of a specification.
 ///
 /// Used to classify the type of work a spec represents.
@@ -37,6 +43,47 @@ pub enum Category {
     Infrastructure,
 }
 
+impl Category {
+    /// Every category, in declaration order.
+    pub const ALL: [Self; 6] = [
+        Self::Feature,
+        Self::Enhancement,
+        Self::BugFix,
+        Self::Refactor,
+        Self::Documentation,
+        Self::Infrastructure,
+    ];
+
+    /// Returns the phase a new spec of this category should start in.
+    ///
+    /// Work whose requirements still need writing down starts at the
+    /// beginning; work whose goal is already clear skips ahead:
+    ///
+    /// | Category | Starting phase |
+    /// |----------|----------------|
+    /// | `Feature`, `Enhancement`, `Infrastructure` | [`Phase::Spec`] |
+    /// | `BugFix`, `Refactor` | [`Phase::Plan`] |
+    /// | `Documentation` | [`Phase::Build`] |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::shared::Phase;
+    /// use airsspec_core::spec::Category;
+    ///
+    /// assert_eq!(Category::Feature.suggested_starting_phase(), Phase::Spec);
+    /// assert_eq!(Category::BugFix.suggested_starting_phase(), Phase::Plan);
+    /// ```
+    #[must_use]
+    pub fn suggested_starting_phase(&self) -> Phase {
+        match self {
+            Self::Feature | Self::Enhancement | Self::Infrastructure => Phase::Spec,
+            Self::BugFix | Self::Refactor => Phase::Plan,
+            Self::Documentation => Phase::Build,
+        }
+    }
+}
+
 impl std::fmt::Display for Category {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -51,6 +98,62 @@ impl std::fmt::Display for Category {
     }
 }
 
+impl FromStr for Category {
+    type Err = SpecError;
+
+    /// Parses a category name from user input such as a CLI flag.
+    ///
+    /// Matching ignores case, surrounding whitespace, and spaces, hyphens
+    /// and underscores within the name, so `"Bug Fix"` and `"bug-fix"`
+    /// both parse as [`BugFix`](Self::BugFix). The aliases `feat`, `fix`,
+    /// `bug`, `docs`, `doc`, and `infra` are also accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecError::InvalidFormat`] listing the valid names if
+    /// `s` is not a known category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::Category;
+    ///
+    /// assert_eq!("Bug Fix".parse::<Category>().unwrap(), Category::BugFix);
+    /// assert_eq!("docs".parse::<Category>().unwrap(), Category::Documentation);
+    /// assert!("chore".parse::<Category>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s
+            .trim()
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .collect::<String>()
+            .to_lowercase();
+
+        let alias = match normalized.as_str() {
+            "feat" => Some(Self::Feature),
+            "fix" | "bug" => Some(Self::BugFix),
+            "docs" | "doc" => Some(Self::Documentation),
+            "infra" => Some(Self::Infrastructure),
+            _ => None,
+        };
+        alias
+            .or_else(|| {
+                Self::ALL
+                    .into_iter()
+                    .find(|category| category.to_string() == normalized)
+            })
+            .ok_or_else(|| {
+                let valid: Vec<String> = Self::ALL.iter().map(ToString::to_string).collect();
+                SpecError::InvalidFormat(format!(
+                    "unknown category '{}', expected one of: {}",
+                    s.trim(),
+                    valid.join(", ")
+                ))
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,6 +174,38 @@ mod tests {
         assert_eq!(format!("{}", Category::Infrastructure), "infrastructure");
     }
 
+    #[test]
+    fn test_all_lists_each_category_once() {
+        use std::collections::HashSet;
+
+        let unique: HashSet<Category> = Category::ALL.into_iter().collect();
+        assert_eq!(unique.len(), Category::ALL.len());
+        assert_eq!(Category::ALL[0], Category::default());
+    }
+
+    #[test]
+    fn test_suggested_starting_phase() {
+        assert_eq!(Category::Feature.suggested_starting_phase(), Phase::Spec);
+        assert_eq!(
+            Category::Enhancement.suggested_starting_phase(),
+            Phase::Spec
+        );
+        assert_eq!(
+            Category::Infrastructure.suggested_starting_phase(),
+            Phase::Spec
+        );
+        assert_eq!(Category::BugFix.suggested_starting_phase(), Phase::Plan);
+        assert_eq!(Category::Refactor.suggested_starting_phase(), Phase::Plan);
+        assert_eq!(
+            Category::Documentation.suggested_starting_phase(),
+            Phase::Build
+        );
+        assert_eq!(
+            Category::default().suggested_starting_phase(),
+            Phase::default()
+        );
+    }
+
     #[test]
     fn test_clone_copy() {
         let cat = Category::BugFix;
@@ -112,4 +247,44 @@ mod tests {
         let cat: Category = serde_json::from_str("\"enhancement\"").unwrap();
         assert_eq!(cat, Category::Enhancement);
     }
+
+    #[test]
+    fn test_from_str_each_variant() {
+        for category in Category::ALL {
+            assert_eq!(category.to_string().parse::<Category>(), Ok(category));
+        }
+        assert_eq!("Feature".parse::<Category>(), Ok(Category::Feature));
+        assert_eq!("Bug Fix".parse::<Category>(), Ok(Category::BugFix));
+        assert_eq!("BUG_FIX".parse::<Category>(), Ok(Category::BugFix));
+        assert_eq!("Refactor".parse::<Category>(), Ok(Category::Refactor));
+    }
+
+    #[test]
+    fn test_from_str_aliases() {
+        assert_eq!("fix".parse::<Category>(), Ok(Category::BugFix));
+        assert_eq!("feat".parse::<Category>(), Ok(Category::Feature));
+        assert_eq!("Docs".parse::<Category>(), Ok(Category::Documentation));
+        assert_eq!("infra".parse::<Category>(), Ok(Category::Infrastructure));
+    }
+
+    #[test]
+    fn test_from_str_trims_whitespace() {
+        assert_eq!(
+            "  enhancement\n".parse::<Category>(),
+            Ok(Category::Enhancement)
+        );
+    }
+
+    #[test]
+    fn test_from_str_invalid_lists_options() {
+        let err = " chore ".parse::<Category>().unwrap_err();
+        assert_eq!(
+            err,
+            SpecError::InvalidFormat(
+                "unknown category 'chore', expected one of: feature, enhancement, bugfix, \
+                 refactor, documentation, infrastructure"
+                    .to_string()
+            )
+        );
+    }
 }
@@ -19,7 +19,9 @@ use super::id::SpecId;
 ///
 /// assert_eq!(dep.kind, DependencyKind::BlockedBy);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Dependencies order by `spec_id`, then by `kind`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Dependency {
     /// The spec this dependency points to.
     pub spec_id: SpecId,
@@ -73,7 +75,9 @@ impl Dependency {
 /// assert_eq!(default_kind, DependencyKind::RelatedTo);
 /// ```
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum DependencyKind {
     /// This spec is blocked by another spec (hard dependency).
@@ -89,6 +93,39 @@ pub enum DependencyKind {
     ParentOf,
 }
 
+impl DependencyKind {
+    /// Returns `true` if a spec cannot sensibly have both `self` and `other`
+    /// dependencies on the same target.
+    ///
+    /// The relation is symmetric. Conflict matrix:
+    ///
+    /// |             | `BlockedBy` | `RelatedTo` | `ChildOf` | `ParentOf` |
+    /// |-------------|-------------|-------------|-----------|------------|
+    /// | `BlockedBy` |             |             |           |            |
+    /// | `RelatedTo` |             |             |           |            |
+    /// | `ChildOf`   |             |             |           | conflict   |
+    /// | `ParentOf`  |             |             | conflict  |            |
+    ///
+    /// A spec cannot be both the child and the parent of the same spec.
+    /// `RelatedTo` is informational and combines with anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::DependencyKind;
+    ///
+    /// assert!(DependencyKind::ChildOf.conflicts_with(DependencyKind::ParentOf));
+    /// assert!(!DependencyKind::BlockedBy.conflicts_with(DependencyKind::RelatedTo));
+    /// ```
+    #[must_use]
+    pub fn conflicts_with(self, other: Self) -> bool {
+        matches!(
+            (self, other),
+            (Self::ChildOf, Self::ParentOf) | (Self::ParentOf, Self::ChildOf)
+        )
+    }
+}
+
 impl std::fmt::Display for DependencyKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -155,6 +192,19 @@ mod tests {
         assert_eq!(format!("{}", DependencyKind::ParentOf), "parent_of");
     }
 
+    #[test]
+    fn test_dependency_kind_conflicts_with() {
+        use DependencyKind::{BlockedBy, ChildOf, ParentOf, RelatedTo};
+
+        let all = [BlockedBy, RelatedTo, ChildOf, ParentOf];
+        for a in all {
+            for b in all {
+                let expected = matches!((a, b), (ChildOf, ParentOf) | (ParentOf, ChildOf));
+                assert_eq!(a.conflicts_with(b), expected, "{a} vs {b}");
+            }
+        }
+    }
+
     #[test]
     fn test_dependency_kind_copy() {
         let kind = DependencyKind::BlockedBy;
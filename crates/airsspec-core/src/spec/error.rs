@@ -5,6 +5,8 @@
 
 use thiserror::Error;
 
+use super::id::IdPart;
+
 /// Errors specific to the Spec domain.
 ///
 /// # Examples
@@ -26,6 +28,16 @@ pub enum SpecError {
     #[error("invalid spec ID: {0}")]
     InvalidId(String),
 
+    /// A part of the spec ID breaks the ADR-003 rules enforced by
+    /// [`SpecId::parse_strict`](super::SpecId::parse_strict).
+    #[error("invalid spec ID {part}: {reason}")]
+    InvalidIdPart {
+        /// The part that failed.
+        part: IdPart,
+        /// Why it failed.
+        reason: String,
+    },
+
     /// The spec content or structure is invalid.
     #[error("invalid spec format: {0}")]
     InvalidFormat(String),
@@ -38,6 +50,26 @@ pub enum SpecError {
     #[error("spec already exists: {0}")]
     AlreadyExists(String),
 
+    /// The stored spec changed since it was last read.
+    #[error("spec was modified externally: {0}")]
+    Conflict(String),
+
+    /// Specs depend on each other in a cycle (e.g. `a -> b -> a`).
+    #[error("circular dependency: {0}")]
+    CircularDependency(String),
+
+    /// A content include directive names a file that does not exist.
+    #[error("include not found: {0}")]
+    IncludeNotFound(String),
+
+    /// Content includes form a cycle (e.g. `a.md -> b.md -> a.md`).
+    #[error("circular include: {0}")]
+    CircularInclude(String),
+
+    /// A content include directive names a file outside the base directory.
+    #[error("include outside base directory: {0}")]
+    IncludeOutsideBase(String),
+
     /// I/O error (stored as string since `io::Error` doesn't impl Clone/Eq).
     #[error("I/O error: {0}")]
     Io(String),
@@ -93,6 +125,46 @@ mod tests {
         assert!(msg.contains("spec already exists"));
     }
 
+    #[test]
+    fn test_conflict_error() {
+        let err = SpecError::Conflict("1737734400-edited".to_string());
+
+        let msg = err.to_string();
+        assert!(msg.contains("modified externally"));
+        assert!(msg.contains("1737734400-edited"));
+    }
+
+    #[test]
+    fn test_circular_dependency_error() {
+        let err = SpecError::CircularDependency("1-a -> 2-b -> 1-a".to_string());
+
+        assert_eq!(err.to_string(), "circular dependency: 1-a -> 2-b -> 1-a");
+    }
+
+    #[test]
+    fn test_include_not_found_error() {
+        let err = SpecError::IncludeNotFound("snippets/auth.md".to_string());
+
+        assert_eq!(err.to_string(), "include not found: snippets/auth.md");
+    }
+
+    #[test]
+    fn test_circular_include_error() {
+        let err = SpecError::CircularInclude("a.md -> b.md -> a.md".to_string());
+
+        assert_eq!(err.to_string(), "circular include: a.md -> b.md -> a.md");
+    }
+
+    #[test]
+    fn test_include_outside_base_error() {
+        let err = SpecError::IncludeOutsideBase("../secrets.md".to_string());
+
+        assert_eq!(
+            err.to_string(),
+            "include outside base directory: ../secrets.md"
+        );
+    }
+
     #[test]
     fn test_error_clone() {
         let err = SpecError::InvalidId("test".to_string());
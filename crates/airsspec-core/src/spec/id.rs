@@ -4,9 +4,11 @@
 
 use std::fmt::{self, Display};
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::error::SpecError;
+use crate::utils::slug::SEPARATORS;
 
 /// Unique identifier for a specification.
 ///
@@ -14,9 +16,12 @@ use super::error::SpecError;
 ///
 /// # Format Specification (ADR-003)
 ///
-/// - **Timestamp**: Unix timestamp (seconds since epoch)
+/// - **Timestamp**: Unix timestamp, in seconds or (see [`IdGranularity`])
+///   milliseconds since epoch; values of 12 or more digits are milliseconds
 /// - **Separator**: Single hyphen (`-`)
-/// - **Slug**: Lowercase, alphanumeric + hyphens, max 50 chars
+/// - **Slug**: Lowercase, alphanumeric, words joined by hyphens or
+///   underscores (see [`slug::SEPARATORS`](crate::utils::slug::SEPARATORS)),
+///   max 50 chars
 ///
 /// # Examples
 ///
@@ -33,9 +38,75 @@ use super::error::SpecError;
 /// let parsed = SpecId::parse("1737734400-user-auth").unwrap();
 /// assert_eq!(id, parsed);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct SpecId(String);
 
+/// Smallest magnitude of a millisecond timestamp: 12 digits, i.e. from
+/// March 1973 in milliseconds, or the year 5138 in seconds.
+const MIN_MILLIS_TIMESTAMP: i64 = 100_000_000_000;
+
+/// Resolution of the timestamp in newly generated spec IDs.
+///
+/// Millisecond IDs make collisions unlikely when many specs with the same
+/// title are created in quick succession. Both widths parse regardless of
+/// the setting.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::IdGranularity;
+/// use chrono::DateTime;
+///
+/// let at = DateTime::from_timestamp_millis(1_737_734_400_123).unwrap();
+/// assert_eq!(IdGranularity::Seconds.timestamp_of(at), 1_737_734_400);
+/// assert_eq!(IdGranularity::Millis.timestamp_of(at), 1_737_734_400_123);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdGranularity {
+    /// Seconds since epoch (e.g. `1737734400-user-auth`).
+    #[default]
+    Seconds,
+    /// Milliseconds since epoch (e.g. `1737734400123-user-auth`).
+    Millis,
+}
+
+impl IdGranularity {
+    /// Returns the ID timestamp for `at` at this granularity.
+    #[must_use]
+    pub fn timestamp_of(self, at: DateTime<Utc>) -> i64 {
+        match self {
+            Self::Seconds => at.timestamp(),
+            Self::Millis => at.timestamp_millis(),
+        }
+    }
+}
+
+/// The part of a spec ID rejected by [`SpecId::parse_strict`].
+///
+/// Carried by [`SpecError::InvalidIdPart`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdPart {
+    /// The ID is not `{timestamp}-{slug}` at all.
+    Format,
+    /// The timestamp before the first hyphen.
+    Timestamp,
+    /// The slug after the first hyphen.
+    Slug,
+}
+
+impl Display for IdPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Format => "format",
+            Self::Timestamp => "timestamp",
+            Self::Slug => "slug",
+        };
+        write!(f, "{s}")
+    }
+}
+
 impl SpecId {
     /// Maximum length for the slug portion (ADR-003).
     pub const MAX_SLUG_LENGTH: usize = 50;
@@ -169,7 +240,102 @@ impl SpecId {
         Ok(Self(s.to_string()))
     }
 
-    /// Returns the Unix timestamp portion.
+    /// Parses a `SpecId`, also enforcing the ADR-003 character rules that
+    /// [`parse`](Self::parse) accepts loosely.
+    ///
+    /// - The timestamp must be a positive integer of ASCII digits
+    /// - The slug must be lowercase ASCII letters, digits, and single
+    ///   separators (`-` or `_`, see
+    ///   [`slug::SEPARATORS`](crate::utils::slug::SEPARATORS)), neither
+    ///   starting nor ending with a separator, and at most
+    ///   [`MAX_SLUG_LENGTH`](Self::MAX_SLUG_LENGTH) characters
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecError::InvalidIdPart`] naming the [`IdPart`] that
+    /// failed, so callers can tell an unrelated name (bad format or
+    /// timestamp) from a spec ID with a malformed slug.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{IdPart, SpecError, SpecId};
+    ///
+    /// assert!(SpecId::parse_strict("1737734400-user-auth").is_ok());
+    ///
+    /// // Accepted by `parse`, rejected here
+    /// assert!(SpecId::parse("1737734400-User-Auth").is_ok());
+    /// let err = SpecId::parse_strict("1737734400-User-Auth").unwrap_err();
+    /// assert!(matches!(err, SpecError::InvalidIdPart { part: IdPart::Slug, .. }));
+    /// ```
+    pub fn parse_strict(s: &str) -> Result<Self, SpecError> {
+        let invalid = |part, reason: &str| SpecError::InvalidIdPart {
+            part,
+            reason: reason.to_string(),
+        };
+
+        if s.starts_with('-') {
+            return Err(invalid(IdPart::Timestamp, "must be positive"));
+        }
+        let Some((timestamp, slug)) = s.split_once('-') else {
+            return Err(invalid(IdPart::Format, "must be {timestamp}-{slug}"));
+        };
+
+        if timestamp.is_empty() || !timestamp.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid(IdPart::Timestamp, "must contain only digits"));
+        }
+        match timestamp.parse::<i64>() {
+            Ok(value) if value > 0 => {}
+            Ok(_) => return Err(invalid(IdPart::Timestamp, "must be positive")),
+            Err(_) => return Err(invalid(IdPart::Timestamp, "does not fit in an i64")),
+        }
+
+        if slug.is_empty() {
+            return Err(invalid(IdPart::Slug, "cannot be empty"));
+        }
+        if slug.len() > Self::MAX_SLUG_LENGTH {
+            return Err(SpecError::InvalidIdPart {
+                part: IdPart::Slug,
+                reason: format!(
+                    "exceeds maximum length of {} characters",
+                    Self::MAX_SLUG_LENGTH
+                ),
+            });
+        }
+        if !slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || SEPARATORS.contains(&c))
+        {
+            return Err(invalid(
+                IdPart::Slug,
+                "must contain only lowercase letters, digits, hyphens, and underscores",
+            ));
+        }
+        if slug.starts_with(SEPARATORS) || slug.ends_with(SEPARATORS) {
+            return Err(invalid(
+                IdPart::Slug,
+                "cannot start or end with a hyphen or underscore",
+            ));
+        }
+        let chars: Vec<char> = slug.chars().collect();
+        if chars
+            .windows(2)
+            .any(|pair| SEPARATORS.contains(&pair[0]) && SEPARATORS.contains(&pair[1]))
+        {
+            return Err(invalid(
+                IdPart::Slug,
+                "cannot contain consecutive hyphens or underscores",
+            ));
+        }
+
+        Ok(Self(s.to_string()))
+    }
+
+    /// Returns the Unix timestamp portion, in seconds.
+    ///
+    /// Millisecond timestamps (see [`IdGranularity`]) are truncated to
+    /// seconds; use [`timestamp_millis`](Self::timestamp_millis) to keep
+    /// the full precision.
     ///
     /// # Panics
     ///
@@ -186,6 +352,67 @@ impl SpecId {
     /// ```
     #[must_use]
     pub fn timestamp(&self) -> i64 {
+        let raw = self.raw_timestamp();
+        match self.granularity() {
+            IdGranularity::Seconds => raw,
+            IdGranularity::Millis => raw.div_euclid(1000),
+        }
+    }
+
+    /// Returns the timestamp portion in milliseconds.
+    ///
+    /// Second timestamps are scaled up, so IDs of either width compare on
+    /// the same scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal string representation is malformed (should never
+    /// happen as `SpecId` is only constructed via validated constructors).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecId;
+    ///
+    /// assert_eq!(SpecId::new(1_737_734_400, "a").timestamp_millis(), 1_737_734_400_000);
+    /// assert_eq!(SpecId::new(1_737_734_400_123, "a").timestamp_millis(), 1_737_734_400_123);
+    /// ```
+    #[must_use]
+    pub fn timestamp_millis(&self) -> i64 {
+        let raw = self.raw_timestamp();
+        match self.granularity() {
+            IdGranularity::Seconds => raw.saturating_mul(1000),
+            IdGranularity::Millis => raw,
+        }
+    }
+
+    /// Returns the granularity of the timestamp portion, inferred from its
+    /// width: 12 or more digits means milliseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal string representation is malformed (should never
+    /// happen as `SpecId` is only constructed via validated constructors).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{IdGranularity, SpecId};
+    ///
+    /// assert_eq!(SpecId::new(1_737_734_400, "a").granularity(), IdGranularity::Seconds);
+    /// assert_eq!(SpecId::new(1_737_734_400_123, "a").granularity(), IdGranularity::Millis);
+    /// ```
+    #[must_use]
+    pub fn granularity(&self) -> IdGranularity {
+        if self.raw_timestamp().unsigned_abs() >= MIN_MILLIS_TIMESTAMP.unsigned_abs() {
+            IdGranularity::Millis
+        } else {
+            IdGranularity::Seconds
+        }
+    }
+
+    /// Returns the timestamp portion as written, at either granularity.
+    fn raw_timestamp(&self) -> i64 {
         // Find the position where slug starts (after timestamp and first hyphen after digits)
         // Handle negative timestamps: "-86400-slug" -> timestamp is "-86400"
         let bytes = self.0.as_bytes();
@@ -342,6 +569,39 @@ mod tests {
         assert_eq!(id.slug(), "user-auth");
     }
 
+    #[test]
+    fn test_parse_millisecond_id() {
+        let id = SpecId::parse("1737734400123-user-auth").unwrap();
+        assert_eq!(id.granularity(), IdGranularity::Millis);
+        assert_eq!(id.timestamp(), 1_737_734_400);
+        assert_eq!(id.timestamp_millis(), 1_737_734_400_123);
+        assert_eq!(id.slug(), "user-auth");
+    }
+
+    #[test]
+    fn test_parse_second_id_keeps_seconds() {
+        let id = SpecId::parse("1737734400-user-auth").unwrap();
+        assert_eq!(id.granularity(), IdGranularity::Seconds);
+        assert_eq!(id.timestamp(), 1_737_734_400);
+        assert_eq!(id.timestamp_millis(), 1_737_734_400_000);
+    }
+
+    #[test]
+    fn test_granularity_timestamp_of() {
+        let at = DateTime::from_timestamp_millis(1_737_734_400_999).unwrap();
+        assert_eq!(IdGranularity::Seconds.timestamp_of(at), 1_737_734_400);
+        assert_eq!(IdGranularity::Millis.timestamp_of(at), 1_737_734_400_999);
+    }
+
+    #[test]
+    fn test_granularity_serde() {
+        assert_eq!(
+            serde_json::to_string(&IdGranularity::Millis).unwrap(),
+            "\"millis\""
+        );
+        assert_eq!(IdGranularity::default(), IdGranularity::Seconds);
+    }
+
     #[test]
     fn test_parse_slug_with_hyphens() {
         let id = SpecId::parse("1737734400-user-auth-system").unwrap();
@@ -429,4 +689,76 @@ mod tests {
         let parsed: SpecId = serde_json::from_str(&json).unwrap();
         assert_eq!(id, parsed);
     }
+
+    /// Returns the part `parse_strict` rejected in `s`.
+    fn strict_failure(s: &str) -> IdPart {
+        match SpecId::parse_strict(s) {
+            Err(SpecError::InvalidIdPart { part, .. }) => part,
+            other => panic!("expected InvalidIdPart for {s:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_strict_accepts_valid_ids() {
+        for s in ["1737734400-user-auth", "1737734400123-v2-api", "1-a"] {
+            let id = SpecId::parse_strict(s).unwrap();
+            assert_eq!(id, SpecId::parse(s).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_parse_strict_empty_slug() {
+        assert_eq!(strict_failure("1737734400-"), IdPart::Slug);
+    }
+
+    #[test]
+    fn test_parse_strict_negative_timestamp() {
+        assert_eq!(strict_failure("-86400-pre-epoch"), IdPart::Timestamp);
+        assert_eq!(strict_failure("0-epoch"), IdPart::Timestamp);
+    }
+
+    #[test]
+    fn test_parse_strict_non_numeric_timestamp() {
+        assert_eq!(strict_failure("abc-payment"), IdPart::Timestamp);
+        assert_eq!(strict_failure("+1737734400-payment"), IdPart::Timestamp);
+        assert_eq!(
+            strict_failure("99999999999999999999-payment"),
+            IdPart::Timestamp
+        );
+        assert_eq!(strict_failure("1737734400"), IdPart::Format);
+    }
+
+    #[test]
+    fn test_parse_strict_uppercase_slug() {
+        assert_eq!(strict_failure("1737734400-User-Auth"), IdPart::Slug);
+        assert_eq!(strict_failure("1737734400-user.auth"), IdPart::Slug);
+    }
+
+    #[test]
+    fn test_parse_strict_accepts_underscore_slug() {
+        let id = SpecId::parse_strict("1737734400-user_auth").unwrap();
+        assert_eq!(id.slug(), "user_auth");
+
+        let slug = crate::utils::slug::generate_with("My Feature Title", 50, '_').unwrap();
+        assert!(SpecId::parse_strict(&format!("1737734400-{slug}")).is_ok());
+    }
+
+    #[test]
+    fn test_parse_strict_hyphen_placement() {
+        assert_eq!(strict_failure("1737734400-user--auth"), IdPart::Slug);
+        assert_eq!(strict_failure("1737734400--user"), IdPart::Slug);
+        assert_eq!(strict_failure("1737734400-user-"), IdPart::Slug);
+        assert_eq!(strict_failure("1737734400-user__auth"), IdPart::Slug);
+        assert_eq!(strict_failure("1737734400-user_-auth"), IdPart::Slug);
+        assert_eq!(strict_failure("1737734400-_user"), IdPart::Slug);
+    }
+
+    #[test]
+    fn test_invalid_id_part_error_message() {
+        let err = SpecId::parse_strict("1737734400-user--auth").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid spec ID slug: cannot contain consecutive hyphens or underscores"
+        );
+    }
 }
@@ -0,0 +1,263 @@
+//! Folding a duplicate spec into the one that is kept.
+
+use super::types::{Spec, SpecMetadata};
+
+/// Custom field listing the IDs of specs merged into a spec.
+pub const MERGED_FROM_FIELD: &str = "merged_from";
+
+/// Custom field describing differences found while merging.
+pub const MERGE_NOTE_FIELD: &str = "merge_note";
+
+/// Merges `secondary` into `primary`, e.g. after finding two duplicate specs.
+///
+/// - Content sections of `secondary` whose heading `primary` lacks (compared
+///   case-insensitively) are appended, as is any text before its first
+///   heading that `primary` does not already contain
+/// - Tags are unioned, skipping tags `primary` has in another case
+/// - Dependencies are unioned, skipping duplicates and any dependency on
+///   `primary` itself
+/// - `secondary`'s ID is appended to the [`MERGED_FROM_FIELD`] custom field
+/// - If the categories differ, `primary` keeps its own and the difference is
+///   recorded in the [`MERGE_NOTE_FIELD`] custom field
+///
+/// `secondary` is not changed; archive it separately.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{SpecBuilder, merge_specs};
+///
+/// let mut primary = SpecBuilder::new()
+///     .title("Login")
+///     .content("## Goals\n\nSign in.")
+///     .build()
+///     .unwrap();
+/// let secondary = SpecBuilder::new()
+///     .title("Sign in")
+///     .content("## Goals\n\nLog in.\n\n## Risks\n\nLockouts.")
+///     .build()
+///     .unwrap();
+///
+/// merge_specs(&mut primary, &secondary);
+/// assert_eq!(primary.content(), "## Goals\n\nSign in.\n\n## Risks\n\nLockouts.\n");
+/// ```
+pub fn merge_specs(primary: &mut Spec, secondary: &Spec) {
+    let content = merged_content(primary.content(), secondary.content());
+    if content != primary.content() {
+        primary.set_content(content);
+    }
+
+    let primary_id = primary.id().clone();
+    let metadata = primary.metadata_mut();
+    for tag in secondary.tags() {
+        let duplicate = metadata
+            .tags()
+            .iter()
+            .any(|existing| existing.to_lowercase() == tag.to_lowercase());
+        if !duplicate {
+            metadata.add_tag(tag.clone());
+        }
+    }
+
+    for dependency in secondary.dependencies() {
+        if dependency.spec_id != primary_id && !metadata.dependencies().contains(dependency) {
+            metadata.add_dependency(dependency.clone());
+        }
+    }
+
+    let secondary_id = secondary.id().as_str();
+    append_custom(metadata, MERGED_FROM_FIELD, ", ", secondary_id);
+
+    if secondary.category() != metadata.category() {
+        let note = format!(
+            "{secondary_id} had category {}, kept {}",
+            secondary.category(),
+            metadata.category()
+        );
+        append_custom(metadata, MERGE_NOTE_FIELD, "; ", &note);
+    }
+}
+
+/// Appends `value` to the custom field `key`, separated by `separator`.
+fn append_custom(metadata: &mut SpecMetadata, key: &str, separator: &str, value: &str) {
+    let combined = match metadata.get_custom(key) {
+        Some(existing) if !existing.is_empty() => format!("{existing}{separator}{value}"),
+        _ => value.to_string(),
+    };
+    metadata.set_custom(key, combined);
+}
+
+/// Returns `primary` with the sections of `secondary` it lacks appended.
+fn merged_content(primary: &str, secondary: &str) -> String {
+    let headings: Vec<String> = sections(primary)
+        .iter()
+        .filter_map(|(heading, _)| heading.map(normalize_heading))
+        .collect();
+
+    let additions: Vec<&str> = sections(secondary)
+        .into_iter()
+        .filter(|(heading, text)| match heading {
+            Some(heading) => !headings.contains(&normalize_heading(heading)),
+            None => !text.trim().is_empty() && !primary.contains(text.trim()),
+        })
+        .map(|(_, text)| text.trim())
+        .collect();
+
+    if additions.is_empty() {
+        return primary.to_string();
+    }
+
+    let mut merged = primary.trim_end().to_string();
+    for addition in additions {
+        if !merged.is_empty() {
+            merged.push_str("\n\n");
+        }
+        merged.push_str(addition);
+    }
+    merged.push('\n');
+    merged
+}
+
+/// Splits Markdown into sections, each starting at a heading line outside
+/// fenced code blocks. Text before the first heading has no heading.
+fn sections(content: &str) -> Vec<(Option<&str>, &str)> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    let mut heading = None;
+    let mut in_fence = false;
+    let mut offset = 0;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        } else if !in_fence && is_heading(trimmed) {
+            if offset > start || heading.is_some() {
+                sections.push((heading, &content[start..offset]));
+            }
+            start = offset;
+            heading = Some(trimmed);
+        }
+        offset += line.len();
+    }
+    if offset > start || heading.is_some() {
+        sections.push((heading, &content[start..]));
+    }
+    sections
+}
+
+/// Returns true for an ATX heading line such as `## Goals`.
+fn is_heading(line: &str) -> bool {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    (1..=6).contains(&hashes) && line[hashes..].starts_with(' ')
+}
+
+/// Normalizes a heading line for comparison.
+fn normalize_heading(heading: &str) -> String {
+    heading.trim_start_matches('#').trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{Category, Dependency, SpecBuilder, SpecId};
+
+    fn spec(slug: &str, content: &str) -> Spec {
+        SpecBuilder::new()
+            .title(slug)
+            .content(content)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_merge_unions_tags_and_dependencies() {
+        let shared = SpecId::new(1_000, "shared");
+        let mut primary = spec("primary", "");
+        primary.metadata_mut().add_tag("payments");
+        primary
+            .metadata_mut()
+            .add_dependency(Dependency::blocked_by(shared.clone()));
+
+        let mut secondary = spec("secondary", "");
+        secondary.metadata_mut().add_tag("Payments");
+        secondary.metadata_mut().add_tag("api");
+        secondary
+            .metadata_mut()
+            .add_dependency(Dependency::blocked_by(shared.clone()));
+        secondary
+            .metadata_mut()
+            .add_dependency(Dependency::related_to(SpecId::new(2_000, "other")));
+        secondary
+            .metadata_mut()
+            .add_dependency(Dependency::related_to(primary.id().clone()));
+
+        merge_specs(&mut primary, &secondary);
+
+        assert_eq!(primary.tags(), ["payments", "api"]);
+        assert_eq!(
+            primary.dependencies(),
+            [
+                Dependency::blocked_by(shared),
+                Dependency::related_to(SpecId::new(2_000, "other")),
+            ]
+        );
+        assert_eq!(
+            primary.metadata().get_custom(MERGED_FROM_FIELD),
+            Some(secondary.id().as_str())
+        );
+    }
+
+    #[test]
+    fn test_merge_appends_only_unique_sections() {
+        let mut primary = spec("primary", "Intro.\n\n## Goals\n\nShip it.\n");
+        let secondary = spec(
+            "secondary",
+            "Intro.\n\n## goals\n\nShip it soon.\n\n## Risks\n\n```\n# not a heading\n```\n",
+        );
+
+        merge_specs(&mut primary, &secondary);
+
+        assert_eq!(
+            primary.content(),
+            "Intro.\n\n## Goals\n\nShip it.\n\n## Risks\n\n```\n# not a heading\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_merge_identical_content_is_unchanged() {
+        let mut primary = spec("primary", "## Goals\n\nShip it.");
+        let secondary = spec("secondary", "## Goals\n\nShip it.");
+
+        merge_specs(&mut primary, &secondary);
+
+        assert_eq!(primary.content(), "## Goals\n\nShip it.");
+    }
+
+    #[test]
+    fn test_merge_category_conflict_keeps_primary_and_notes() {
+        let mut primary = spec("primary", "");
+        let mut secondary = spec("secondary", "");
+        secondary.metadata_mut().set_category(Category::BugFix);
+
+        merge_specs(&mut primary, &secondary);
+
+        assert_eq!(primary.category(), Category::Feature);
+        let note = primary.metadata().get_custom(MERGE_NOTE_FIELD).unwrap();
+        assert!(note.contains(secondary.id().as_str()), "{note}");
+        assert!(
+            note.contains("bugfix") && note.contains("feature"),
+            "{note}"
+        );
+    }
+
+    #[test]
+    fn test_merge_same_category_adds_no_note() {
+        let mut primary = spec("primary", "");
+        let secondary = spec("secondary", "");
+
+        merge_specs(&mut primary, &secondary);
+
+        assert_eq!(primary.metadata().get_custom(MERGE_NOTE_FIELD), None);
+    }
+}
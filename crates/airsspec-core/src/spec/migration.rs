@@ -0,0 +1,110 @@
+//! One-shot migrations for specs written in older formats.
+
+use super::category::Category;
+use super::types::Spec;
+
+/// Moves a legacy category label from the content into the metadata.
+///
+/// Older specs recorded their category as the first content line (e.g.
+/// `# BugFix` or `Category: Bug Fix`) and left the metadata at the default
+/// [`Category::Feature`]. When the metadata still holds the default and the
+/// first line names another category, the metadata category is set from it.
+/// The content is left as it is.
+///
+/// Returns `true` if the category changed.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{Category, SpecBuilder, migrate_spec_category_from_content};
+///
+/// let mut spec = SpecBuilder::new()
+///     .title("Login crash")
+///     .content("# BugFix\n\nThe app crashes on login.")
+///     .build()
+///     .unwrap();
+///
+/// assert!(migrate_spec_category_from_content(&mut spec));
+/// assert_eq!(spec.category(), Category::BugFix);
+/// ```
+pub fn migrate_spec_category_from_content(spec: &mut Spec) -> bool {
+    if spec.category() != Category::default() {
+        return false;
+    }
+    let Some(category) = spec.content().lines().next().and_then(category_label) else {
+        return false;
+    };
+    if category == spec.category() {
+        return false;
+    }
+    spec.metadata_mut().set_category(category);
+    true
+}
+
+/// Parses a line such as `# Bug Fix` or `Category: bugfix` as a category.
+///
+/// Matching ignores case, a leading Markdown heading marker, an optional
+/// `Category:` prefix, and spaces, hyphens and underscores within the label.
+fn category_label(line: &str) -> Option<Category> {
+    let label = line.trim().trim_start_matches('#').trim();
+    let label = label
+        .split_once(':')
+        .filter(|(prefix, _)| prefix.trim().eq_ignore_ascii_case("category"))
+        .map_or(label, |(_, rest)| rest);
+    let normalized = label
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .collect::<String>()
+        .to_lowercase();
+    Category::ALL
+        .into_iter()
+        .find(|category| category.to_string() == normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::SpecBuilder;
+
+    fn spec_with_content(content: &str) -> Spec {
+        SpecBuilder::new()
+            .title("Legacy")
+            .content(content)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_bugfix_heading_promotes_category() {
+        let mut spec = spec_with_content("# BugFix\n\n## Problem\n");
+
+        assert!(migrate_spec_category_from_content(&mut spec));
+        assert_eq!(spec.category(), Category::BugFix);
+        assert_eq!(spec.content(), "# BugFix\n\n## Problem\n");
+    }
+
+    #[test]
+    fn test_label_variants_are_recognized() {
+        for line in ["Category: Bug Fix", "## bug-fix", "bug_fix"] {
+            assert_eq!(category_label(line), Some(Category::BugFix), "{line}");
+        }
+        assert_eq!(category_label("# Login crash"), None);
+    }
+
+    #[test]
+    fn test_content_without_hint_is_unchanged() {
+        let mut spec = spec_with_content("# Login crash\n\nDetails.");
+
+        assert!(!migrate_spec_category_from_content(&mut spec));
+        assert_eq!(spec.category(), Category::Feature);
+    }
+
+    #[test]
+    fn test_explicit_metadata_category_wins() {
+        let mut spec = spec_with_content("# BugFix");
+        spec.metadata_mut().set_category(Category::Refactor);
+
+        assert!(!migrate_spec_category_from_content(&mut spec));
+        assert_eq!(spec.category(), Category::Refactor);
+    }
+}
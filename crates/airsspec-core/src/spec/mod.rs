@@ -7,16 +7,35 @@
 //! - [`Spec`] - The main specification type
 //! - [`SpecMetadata`] - Metadata for a specification
 //! - [`SpecId`] - Unique identifier for specifications
+//! - [`IdGranularity`] - Seconds or milliseconds in new spec IDs
+//! - [`IdPart`] - Which part of a spec ID failed strict parsing
 //! - [`SpecBuilder`] - Builder pattern for creating specs
 //! - [`Category`] - Specification categorization
+//! - [`Approval`] - A sign-off recorded on a spec
 //! - [`Dependency`] - Spec-to-spec relationships
 //! - [`DependencyKind`] - Type of dependency relationship
 //! - [`SpecError`] - Domain-specific errors
 //! - [`SpecStorage`] - Trait for spec persistence
+//! - [`builtin_template`] - Built-in content template per category
+//! - [`specs_in_range`] - Filter specs by ID timestamp range
+//! - [`find_duplicate_content`] - Find specs with identical content
+//! - [`group_specs_by_state`] - Bucket specs by lifecycle state
+//! - [`compute_effective_status`] - Lifecycle state with blocking derived from dependencies
+//! - [`partition_by_readiness`] - Split open specs into ready and blocked ([`SpecReadiness`])
+//! - [`build_tag_index`] - Map each normalized tag to the specs carrying it
+//! - [`dependency_levels`] - Layer specs by their longest blocking chain
+//! - [`merge_specs`] - Fold a duplicate spec into the one that is kept
+//! - [`migrate_spec_category_from_content`] - Promote a legacy content category label
 //!
 //! ## Validation
 //!
 //! - [`validate_spec`] - Validate a specification
+//! - [`validate_spec_with_config`] - Validate with custom thresholds
+//! - [`validate_dependency_graph`] - Detect blocking cycles across specs
+//! - [`validate_spec_in_lifecycle`] - Validate with lifecycle-dependent checks
+//! - [`validate_spec_in_lifecycle_with_config`] - Lifecycle checks with custom thresholds
+//! - [`SpecValidationConfig`] - Thresholds such as the content size limit
+//! - [`KeywordLintConfig`] - Phrases every spec's content must mention
 //! - [`ValidationReport`] - Report of validation issues
 //! - [`ValidationIssue`] - A single validation issue
 //! - [`ValidationSeverity`] - Severity level (info, warning, error)
@@ -60,21 +79,38 @@
 //! };
 //! ```
 
+mod approval;
 mod builder;
 mod category;
 mod dependency;
 mod error;
 mod id;
+mod merge;
+mod migration;
+mod query;
 mod storage;
+mod template;
 mod types;
 mod validatable;
 mod validator;
 
+pub use approval::Approval;
 pub use builder::SpecBuilder;
 pub use category::Category;
 pub use dependency::{Dependency, DependencyKind};
 pub use error::SpecError;
-pub use id::SpecId;
+pub use id::{IdGranularity, IdPart, SpecId};
+pub use merge::{MERGE_NOTE_FIELD, MERGED_FROM_FIELD, merge_specs};
+pub use migration::migrate_spec_category_from_content;
+pub use query::{
+    SpecReadiness, build_tag_index, compute_effective_status, dependency_levels,
+    find_duplicate_content, group_specs_by_state, partition_by_readiness, specs_in_range,
+};
 pub use storage::{SpecStorage, SpecStorageExt};
+pub use template::builtin_template;
 pub use types::{Spec, SpecMetadata};
-pub use validator::{ValidationIssue, ValidationReport, ValidationSeverity, validate_spec};
+pub use validator::{
+    KeywordLintConfig, SpecValidationConfig, ValidationIssue, ValidationReport, ValidationSeverity,
+    validate_dependency_graph, validate_spec, validate_spec_in_lifecycle,
+    validate_spec_in_lifecycle_with_config, validate_spec_with_config,
+};
@@ -0,0 +1,734 @@
+//! Queries over collections of specs.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use crate::shared::LifecycleState;
+use crate::validation::{BlockingGraph, ValidatableSpec};
+
+use super::dependency::DependencyKind;
+use super::error::SpecError;
+use super::id::SpecId;
+use super::types::Spec;
+
+/// Returns the specs whose ID timestamp falls within `start_ts..=end_ts`.
+///
+/// Both bounds are inclusive Unix timestamps (seconds). An inverted range
+/// (`start_ts > end_ts`) matches nothing. Input order is preserved.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{Spec, SpecId, SpecMetadata, specs_in_range};
+///
+/// let specs = vec![
+///     Spec::new(SpecId::new(100, "old"), SpecMetadata::new("Old", ""), ""),
+///     Spec::new(SpecId::new(200, "new"), SpecMetadata::new("New", ""), ""),
+/// ];
+///
+/// let recent = specs_in_range(&specs, 150, 250);
+/// assert_eq!(recent.len(), 1);
+/// assert_eq!(recent[0].id().slug(), "new");
+/// ```
+#[must_use]
+pub fn specs_in_range(specs: &[Spec], start_ts: i64, end_ts: i64) -> Vec<&Spec> {
+    if start_ts > end_ts {
+        return Vec::new();
+    }
+    specs
+        .iter()
+        .filter(|spec| (start_ts..=end_ts).contains(&spec.id().timestamp()))
+        .collect()
+}
+
+/// Buckets specs by lifecycle state, e.g. for a kanban-style board.
+///
+/// Columns iterate in [`LifecycleState`] order (draft first); only states
+/// with at least one spec appear. Specs with no entry in `states` are
+/// placed in [`LifecycleState::Draft`]. Within a column, input order is
+/// preserved.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use airsspec_core::shared::LifecycleState;
+/// use airsspec_core::spec::{Spec, SpecId, SpecMetadata, group_specs_by_state};
+///
+/// let specs = vec![
+///     Spec::new(SpecId::new(100, "a"), SpecMetadata::new("A", ""), ""),
+///     Spec::new(SpecId::new(200, "b"), SpecMetadata::new("B", ""), ""),
+/// ];
+/// let states = HashMap::from([(SpecId::new(200, "b"), LifecycleState::Active)]);
+///
+/// let board = group_specs_by_state(&specs, &states);
+/// assert_eq!(board[&LifecycleState::Draft].len(), 1);
+/// assert_eq!(board[&LifecycleState::Active].len(), 1);
+/// ```
+#[must_use]
+pub fn group_specs_by_state<'a, H: BuildHasher>(
+    specs: &'a [Spec],
+    states: &HashMap<SpecId, LifecycleState, H>,
+) -> BTreeMap<LifecycleState, Vec<&'a Spec>> {
+    let mut board: BTreeMap<LifecycleState, Vec<&Spec>> = BTreeMap::new();
+    for spec in specs {
+        let state = states.get(spec.id()).copied().unwrap_or_default();
+        board.entry(state).or_default().push(spec);
+    }
+    board
+}
+
+/// Returns the state a spec should be shown in, deriving blocking from its
+/// dependencies.
+///
+/// A spec that is still open (draft, active or blocked) is
+/// [`Blocked`](LifecycleState::Blocked) while any of its
+/// [`BlockedBy`](DependencyKind::BlockedBy) dependencies is not yet
+/// [`Done`](LifecycleState::Done) or [`Archived`](LifecycleState::Archived).
+/// Otherwise, and always for done, cancelled or archived specs, the result
+/// is the spec's own state. Specs with no entry in `states` are treated as
+/// [`Draft`](LifecycleState::Draft), so an unknown blocker still blocks.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use airsspec_core::shared::LifecycleState;
+/// use airsspec_core::spec::{Dependency, Spec, SpecId, SpecMetadata, compute_effective_status};
+///
+/// let auth = SpecId::new(100, "auth");
+/// let mut metadata = SpecMetadata::new("Profile", "");
+/// metadata.add_dependency(Dependency::blocked_by(auth.clone()));
+/// let profile = Spec::new(SpecId::new(200, "profile"), metadata, "");
+///
+/// let mut states = HashMap::from([
+///     (auth.clone(), LifecycleState::Active),
+///     (profile.id().clone(), LifecycleState::Active),
+/// ]);
+/// assert_eq!(compute_effective_status(&profile, &states), LifecycleState::Blocked);
+///
+/// states.insert(auth, LifecycleState::Done);
+/// assert_eq!(compute_effective_status(&profile, &states), LifecycleState::Active);
+/// ```
+#[must_use]
+pub fn compute_effective_status<H: BuildHasher>(
+    spec: &Spec,
+    states: &HashMap<SpecId, LifecycleState, H>,
+) -> LifecycleState {
+    let state_of = |id: &SpecId| states.get(id).copied().unwrap_or_default();
+    let own = state_of(spec.id());
+    if !matches!(
+        own,
+        LifecycleState::Draft | LifecycleState::Active | LifecycleState::Blocked
+    ) {
+        return own;
+    }
+
+    let blocked = spec.dependencies().iter().any(|dep| {
+        dep.kind == DependencyKind::BlockedBy
+            && !matches!(
+                state_of(&dep.spec_id),
+                LifecycleState::Done | LifecycleState::Archived
+            )
+    });
+    if blocked {
+        LifecycleState::Blocked
+    } else {
+        own
+    }
+}
+
+/// Open specs partitioned by whether their blockers are complete, as
+/// returned by [`partition_by_readiness`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecReadiness<'a> {
+    /// Specs that can be started: every blocker is done or archived.
+    pub ready: Vec<&'a Spec>,
+
+    /// Specs still waiting on at least one incomplete blocker.
+    pub blocked: Vec<&'a Spec>,
+}
+
+impl SpecReadiness<'_> {
+    /// Number of specs ready to start.
+    #[must_use]
+    pub fn ready_count(&self) -> usize {
+        self.ready.len()
+    }
+
+    /// Number of specs still blocked.
+    #[must_use]
+    pub fn blocked_count(&self) -> usize {
+        self.blocked.len()
+    }
+}
+
+/// Splits the open specs into those ready to start and those blocked, e.g.
+/// to see what can be picked up in the next sprint.
+///
+/// Each open (draft, active or blocked) spec goes into
+/// [`blocked`](SpecReadiness::blocked) if [`compute_effective_status`] says
+/// it is [`Blocked`](LifecycleState::Blocked), and into
+/// [`ready`](SpecReadiness::ready) otherwise. Done, cancelled and archived
+/// specs appear in neither. Input order is preserved within each group.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use airsspec_core::shared::LifecycleState;
+/// use airsspec_core::spec::{Dependency, Spec, SpecId, SpecMetadata, partition_by_readiness};
+///
+/// let auth = SpecId::new(100, "auth");
+/// let mut metadata = SpecMetadata::new("Profile", "");
+/// metadata.add_dependency(Dependency::blocked_by(auth.clone()));
+/// let specs = vec![
+///     Spec::new(auth.clone(), SpecMetadata::new("Auth", ""), ""),
+///     Spec::new(SpecId::new(200, "profile"), metadata, ""),
+/// ];
+/// let states = HashMap::from([(auth, LifecycleState::Active)]);
+///
+/// let readiness = partition_by_readiness(&specs, &states);
+/// assert_eq!(readiness.ready_count(), 1);
+/// assert_eq!(readiness.blocked[0].id().slug(), "profile");
+/// ```
+#[must_use]
+pub fn partition_by_readiness<'a, H: BuildHasher>(
+    specs: &'a [Spec],
+    states: &HashMap<SpecId, LifecycleState, H>,
+) -> SpecReadiness<'a> {
+    let mut readiness = SpecReadiness::default();
+    for spec in specs {
+        match compute_effective_status(spec, states) {
+            LifecycleState::Blocked => readiness.blocked.push(spec),
+            LifecycleState::Draft | LifecycleState::Active => readiness.ready.push(spec),
+            _ => {}
+        }
+    }
+    readiness
+}
+
+/// Maps each tag to the specs carrying it, e.g. for tag navigation.
+///
+/// Tags are normalized by trimming whitespace and lowercasing, so `API` and
+/// ` api ` are the same tag; blank tags are skipped. Tags iterate in sorted
+/// order, and each tag's specs keep input order, listed once even if a spec
+/// carries the tag in several spellings.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{Spec, SpecId, SpecMetadata, build_tag_index};
+///
+/// let mut metadata = SpecMetadata::new("Checkout", "");
+/// metadata.add_tag("Payments");
+/// let specs = vec![Spec::new(SpecId::new(100, "checkout"), metadata, "")];
+///
+/// let index = build_tag_index(&specs);
+/// assert_eq!(index["payments"], vec![SpecId::new(100, "checkout")]);
+/// ```
+#[must_use]
+pub fn build_tag_index(specs: &[Spec]) -> BTreeMap<String, Vec<SpecId>> {
+    let mut index: BTreeMap<String, Vec<SpecId>> = BTreeMap::new();
+    for spec in specs {
+        for tag in spec.tags() {
+            let tag = tag.trim().to_lowercase();
+            if tag.is_empty() {
+                continue;
+            }
+            let ids = index.entry(tag).or_default();
+            if ids.last() != Some(spec.id()) {
+                ids.push(spec.id().clone());
+            }
+        }
+    }
+    index
+}
+
+/// Finds specs whose content is identical after normalization.
+///
+/// Content is normalized by converting line endings to `\n`, trimming
+/// trailing whitespace from each line, and dropping leading and trailing
+/// blank lines. Each duplicate is reported once, paired with the first spec
+/// (in input order) that has the same content: `(original, duplicate)`.
+/// Specs with empty content are never reported.
+///
+/// Callers can surface the pairs as warnings, e.g. when importing specs.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{Spec, SpecId, SpecMetadata, find_duplicate_content};
+///
+/// let specs = vec![
+///     Spec::new(SpecId::new(100, "a"), SpecMetadata::new("A", ""), "# Same\n"),
+///     Spec::new(SpecId::new(200, "b"), SpecMetadata::new("B", ""), "# Same"),
+/// ];
+///
+/// let duplicates = find_duplicate_content(&specs);
+/// assert_eq!(duplicates, vec![(SpecId::new(100, "a"), SpecId::new(200, "b"))]);
+/// ```
+#[must_use]
+pub fn find_duplicate_content(specs: &[Spec]) -> Vec<(SpecId, SpecId)> {
+    let mut seen: HashMap<u64, Vec<(String, &SpecId)>> = HashMap::new();
+    let mut duplicates = Vec::new();
+
+    for spec in specs {
+        let normalized = normalize_content(spec.content());
+        if normalized.is_empty() {
+            continue;
+        }
+
+        let mut hasher = DefaultHasher::new();
+        normalized.hash(&mut hasher);
+        let bucket = seen.entry(hasher.finish()).or_default();
+
+        // Compare within the bucket so a hash collision is never reported.
+        match bucket.iter().find(|(content, _)| *content == normalized) {
+            Some((_, original)) => duplicates.push(((*original).clone(), spec.id().clone())),
+            None => bucket.push((normalized, spec.id())),
+        }
+    }
+
+    duplicates
+}
+
+/// Assigns each spec a level for layered display: the length of the longest
+/// chain of `BlockedBy` dependencies beneath it.
+///
+/// Specs that are not blocked by anything are at level 0; a spec blocked by
+/// others sits one level above the highest of them. Other dependency kinds,
+/// and dependencies on specs not in `specs`, are ignored.
+///
+/// # Errors
+///
+/// Returns [`SpecError::CircularDependency`] naming the cycle if the
+/// `BlockedBy` dependencies form one.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{Dependency, Spec, SpecId, SpecMetadata, dependency_levels};
+///
+/// let base = SpecId::new(100, "base");
+/// let mut metadata = SpecMetadata::new("Top", "");
+/// metadata.add_dependency(Dependency::blocked_by(base.clone()));
+/// let specs = vec![
+///     Spec::new(base.clone(), SpecMetadata::new("Base", ""), ""),
+///     Spec::new(SpecId::new(200, "top"), metadata, ""),
+/// ];
+///
+/// let levels = dependency_levels(&specs).unwrap();
+/// assert_eq!(levels[&base], 0);
+/// assert_eq!(levels[&SpecId::new(200, "top")], 1);
+/// ```
+pub fn dependency_levels(specs: &[Spec]) -> Result<BTreeMap<SpecId, usize>, SpecError> {
+    let graph = BlockingGraph::new(specs);
+    if let Some(cycle) = graph.cycles().first() {
+        return Err(SpecError::CircularDependency(cycle.join(" -> ")));
+    }
+
+    let mut levels = HashMap::new();
+    for spec in specs {
+        level_of(spec.id_str(), &graph, &mut levels);
+    }
+
+    Ok(specs
+        .iter()
+        .map(|spec| (spec.id().clone(), levels[spec.id_str()]))
+        .collect())
+}
+
+/// Computes (and memoizes) the level of `id` in an acyclic `graph`.
+fn level_of<'a>(
+    id: &'a str,
+    graph: &BlockingGraph<'a>,
+    levels: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if let Some(&level) = levels.get(id) {
+        return level;
+    }
+    let level = graph
+        .blockers(id)
+        .iter()
+        .map(|&blocker| level_of(blocker, graph, levels) + 1)
+        .max()
+        .unwrap_or(0);
+    levels.insert(id, level);
+    level
+}
+
+/// Normalizes spec content for duplicate detection.
+fn normalize_content(content: &str) -> String {
+    let lines: Vec<&str> = content.lines().map(str::trim_end).collect();
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end].join("\n"),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{Dependency, SpecMetadata};
+
+    fn spec_at(timestamp: i64, slug: &str) -> Spec {
+        Spec::new(
+            SpecId::new(timestamp, slug),
+            SpecMetadata::new(slug, "Range query"),
+            "",
+        )
+    }
+
+    fn specs() -> Vec<Spec> {
+        vec![
+            spec_at(1_000, "first"),
+            spec_at(2_000, "second"),
+            spec_at(3_000, "third"),
+            spec_at(4_000, "fourth"),
+        ]
+    }
+
+    fn slugs<'a>(specs: &[&'a Spec]) -> Vec<&'a str> {
+        specs.iter().map(|spec| spec.id().slug()).collect()
+    }
+
+    #[test]
+    fn test_range_captures_subset_inclusively() {
+        let specs = specs();
+        let found = specs_in_range(&specs, 2_000, 3_000);
+        assert_eq!(slugs(&found), vec!["second", "third"]);
+    }
+
+    #[test]
+    fn test_range_with_no_matches_is_empty() {
+        let specs = specs();
+        assert!(specs_in_range(&specs, 2_001, 2_999).is_empty());
+    }
+
+    #[test]
+    fn test_single_point_range() {
+        let specs = specs();
+        let found = specs_in_range(&specs, 4_000, 4_000);
+        assert_eq!(slugs(&found), vec!["fourth"]);
+    }
+
+    #[test]
+    fn test_inverted_range_is_empty() {
+        let specs = specs();
+        assert!(specs_in_range(&specs, 3_000, 1_000).is_empty());
+    }
+
+    fn spec_with(timestamp: i64, slug: &str, content: &str) -> Spec {
+        Spec::new(
+            SpecId::new(timestamp, slug),
+            SpecMetadata::new(slug, "Duplicate check"),
+            content,
+        )
+    }
+
+    #[test]
+    fn test_shared_content_is_reported() {
+        let specs = vec![
+            spec_with(1_000, "original", "# Auth\n\nUse OAuth2.\n"),
+            spec_with(2_000, "other", "# Billing"),
+            spec_with(3_000, "imported", "\r\n# Auth  \r\n\r\nUse OAuth2.\r\n\r\n"),
+        ];
+
+        let duplicates = find_duplicate_content(&specs);
+
+        assert_eq!(
+            duplicates,
+            vec![(
+                SpecId::new(1_000, "original"),
+                SpecId::new(3_000, "imported")
+            )]
+        );
+    }
+
+    #[test]
+    fn test_distinct_content_is_empty() {
+        let specs = vec![
+            spec_with(1_000, "auth", "# Auth"),
+            spec_with(2_000, "billing", "# Billing"),
+            spec_with(3_000, "blank-a", ""),
+            spec_with(4_000, "blank-b", "  \n"),
+        ];
+
+        assert!(find_duplicate_content(&specs).is_empty());
+    }
+
+    #[test]
+    fn test_each_duplicate_pairs_with_first_occurrence() {
+        let specs = vec![
+            spec_with(1_000, "a", "# Same"),
+            spec_with(2_000, "b", "# Same"),
+            spec_with(3_000, "c", "# Same"),
+        ];
+
+        let duplicates = find_duplicate_content(&specs);
+
+        assert_eq!(
+            duplicates,
+            vec![
+                (SpecId::new(1_000, "a"), SpecId::new(2_000, "b")),
+                (SpecId::new(1_000, "a"), SpecId::new(3_000, "c")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_specs_by_state_buckets_in_column_order() {
+        let specs = specs();
+        let states = HashMap::from([
+            (SpecId::new(1_000, "first"), LifecycleState::Done),
+            (SpecId::new(2_000, "second"), LifecycleState::Active),
+            (SpecId::new(3_000, "third"), LifecycleState::Done),
+            (SpecId::new(4_000, "fourth"), LifecycleState::Blocked),
+        ]);
+
+        let board = group_specs_by_state(&specs, &states);
+
+        let columns: Vec<LifecycleState> = board.keys().copied().collect();
+        assert_eq!(
+            columns,
+            vec![
+                LifecycleState::Active,
+                LifecycleState::Done,
+                LifecycleState::Blocked
+            ]
+        );
+        assert_eq!(slugs(&board[&LifecycleState::Done]), vec!["first", "third"]);
+        assert_eq!(slugs(&board[&LifecycleState::Active]), vec!["second"]);
+        assert_eq!(slugs(&board[&LifecycleState::Blocked]), vec!["fourth"]);
+    }
+
+    #[test]
+    fn test_group_specs_by_state_defaults_to_draft() {
+        let specs = specs();
+        let states = HashMap::from([
+            (SpecId::new(2_000, "second"), LifecycleState::Active),
+            (SpecId::new(9_000, "unknown"), LifecycleState::Done),
+        ]);
+
+        let board = group_specs_by_state(&specs, &states);
+
+        assert_eq!(
+            slugs(&board[&LifecycleState::Draft]),
+            vec!["first", "third", "fourth"]
+        );
+        assert!(!board.contains_key(&LifecycleState::Done));
+    }
+
+    fn blocked_spec(timestamp: i64, slug: &str, blockers: &[&SpecId]) -> Spec {
+        let mut metadata = SpecMetadata::new(slug, "Level check");
+        for &blocker in blockers {
+            metadata.add_dependency(Dependency::blocked_by(blocker.clone()));
+        }
+        Spec::new(SpecId::new(timestamp, slug), metadata, "")
+    }
+
+    #[test]
+    fn test_effective_status_blocked_by_incomplete_blocker() {
+        let auth = SpecId::new(1_000, "auth");
+        let profile = blocked_spec(2_000, "profile", &[&auth]);
+        let states = HashMap::from([
+            (auth, LifecycleState::Active),
+            (profile.id().clone(), LifecycleState::Active),
+        ]);
+
+        assert_eq!(
+            compute_effective_status(&profile, &states),
+            LifecycleState::Blocked
+        );
+    }
+
+    #[test]
+    fn test_effective_status_keeps_own_state_when_blockers_done() {
+        let auth = SpecId::new(1_000, "auth");
+        let billing = SpecId::new(1_500, "billing");
+        let profile = blocked_spec(2_000, "profile", &[&auth, &billing]);
+        let states = HashMap::from([
+            (auth, LifecycleState::Done),
+            (billing, LifecycleState::Archived),
+            (profile.id().clone(), LifecycleState::Active),
+        ]);
+
+        assert_eq!(
+            compute_effective_status(&profile, &states),
+            LifecycleState::Active
+        );
+    }
+
+    #[test]
+    fn test_effective_status_ignores_soft_and_closed() {
+        let auth = SpecId::new(1_000, "auth");
+        let mut metadata = SpecMetadata::new("profile", "");
+        metadata.add_dependency(Dependency::related_to(auth.clone()));
+        let related = Spec::new(SpecId::new(2_000, "profile"), metadata, "");
+        let done = blocked_spec(3_000, "done", &[&auth]);
+        let states = HashMap::from([(done.id().clone(), LifecycleState::Done)]);
+
+        assert_eq!(
+            compute_effective_status(&related, &states),
+            LifecycleState::Draft
+        );
+        assert_eq!(
+            compute_effective_status(&done, &states),
+            LifecycleState::Done
+        );
+    }
+
+    #[test]
+    fn test_levels_increase_along_chain() {
+        let a = SpecId::new(1, "a");
+        let b = SpecId::new(2, "b");
+        let c = SpecId::new(3, "c");
+        let specs = vec![
+            blocked_spec(3, "c", &[&b]),
+            blocked_spec(2, "b", &[&a]),
+            blocked_spec(1, "a", &[]),
+        ];
+
+        let levels = dependency_levels(&specs).unwrap();
+
+        assert_eq!(levels, BTreeMap::from([(a, 0), (b, 1), (c, 2)]));
+    }
+
+    #[test]
+    fn test_diamond_takes_longest_chain() {
+        let root = SpecId::new(1, "root");
+        let left = SpecId::new(2, "left");
+        let mid = SpecId::new(3, "mid");
+        let right = SpecId::new(4, "right");
+        let top = SpecId::new(5, "top");
+        let mut top_spec = blocked_spec(5, "top", &[&left, &right]);
+        top_spec
+            .metadata_mut()
+            .add_dependency(Dependency::related_to(root.clone()));
+        let specs = vec![
+            blocked_spec(1, "root", &[]),
+            blocked_spec(2, "left", &[&root]),
+            blocked_spec(3, "mid", &[&root]),
+            blocked_spec(4, "right", &[&mid]),
+            top_spec,
+        ];
+
+        let levels = dependency_levels(&specs).unwrap();
+
+        assert_eq!(levels[&left], 1);
+        assert_eq!(levels[&right], 2);
+        assert_eq!(levels[&top], 3);
+    }
+
+    #[test]
+    fn test_cycle_is_reported() {
+        let a = SpecId::new(1, "a");
+        let b = SpecId::new(2, "b");
+        let specs = vec![
+            blocked_spec(1, "a", &[&b]),
+            blocked_spec(2, "b", &[&a]),
+            blocked_spec(3, "c", &[]),
+        ];
+
+        let result = dependency_levels(&specs);
+
+        assert_eq!(
+            result,
+            Err(SpecError::CircularDependency(
+                "1-a -> 2-b -> 1-a".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_unknown_blocker_is_ignored() {
+        let specs = vec![blocked_spec(1, "a", &[&SpecId::new(9, "missing")])];
+
+        let levels = dependency_levels(&specs).unwrap();
+
+        assert_eq!(levels[&SpecId::new(1, "a")], 0);
+    }
+
+    #[test]
+    fn test_readiness_partitions_open_specs() {
+        // auth (done) <- profile <- settings; billing has no blockers.
+        let auth = SpecId::new(1_000, "auth");
+        let profile = blocked_spec(2_000, "profile", &[&auth]);
+        let settings = blocked_spec(3_000, "settings", &[profile.id()]);
+        let billing = blocked_spec(4_000, "billing", &[]);
+        let specs = vec![
+            blocked_spec(1_000, "auth", &[]),
+            profile.clone(),
+            settings.clone(),
+            billing,
+        ];
+        let states = HashMap::from([
+            (auth, LifecycleState::Done),
+            (profile.id().clone(), LifecycleState::Active),
+            (settings.id().clone(), LifecycleState::Draft),
+        ]);
+
+        let readiness = partition_by_readiness(&specs, &states);
+
+        assert_eq!(slugs(&readiness.ready), ["profile", "billing"]);
+        assert_eq!(slugs(&readiness.blocked), ["settings"]);
+        assert_eq!(readiness.ready_count(), 2);
+        assert_eq!(readiness.blocked_count(), 1);
+    }
+
+    #[test]
+    fn test_readiness_excludes_closed_specs() {
+        let specs = vec![
+            blocked_spec(1_000, "shipped", &[]),
+            blocked_spec(2_000, "dropped", &[]),
+        ];
+        let states = HashMap::from([
+            (SpecId::new(1_000, "shipped"), LifecycleState::Done),
+            (SpecId::new(2_000, "dropped"), LifecycleState::Cancelled),
+        ]);
+
+        assert_eq!(
+            partition_by_readiness(&specs, &states),
+            SpecReadiness::default()
+        );
+    }
+
+    fn tagged_spec(timestamp: i64, slug: &str, tags: &[&str]) -> Spec {
+        let mut metadata = SpecMetadata::new(slug, "Tag index");
+        for &tag in tags {
+            metadata.add_tag(tag);
+        }
+        Spec::new(SpecId::new(timestamp, slug), metadata, "")
+    }
+
+    #[test]
+    fn test_tag_index_groups_specs_sharing_tags() {
+        let specs = vec![
+            tagged_spec(1_000, "checkout", &["Payments", "api"]),
+            tagged_spec(2_000, "refunds", &["payments ", "PAYMENTS"]),
+            tagged_spec(3_000, "search", &["API", " "]),
+        ];
+
+        let index = build_tag_index(&specs);
+
+        assert_eq!(index.keys().collect::<Vec<_>>(), ["api", "payments"]);
+        assert_eq!(
+            index["api"],
+            [SpecId::new(1_000, "checkout"), SpecId::new(3_000, "search")]
+        );
+        assert_eq!(
+            index["payments"],
+            [
+                SpecId::new(1_000, "checkout"),
+                SpecId::new(2_000, "refunds")
+            ]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! Built-in spec content templates.
+//!
+//! Each [`Category`] has a Markdown skeleton used to prefill the content of
+//! new specs. Workspaces can override these with their own files; loading
+//! those lives in `airsspec-mcp` (per ADR-002, no I/O here).
+
+use super::category::Category;
+
+/// Returns the built-in content template for `category`.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{Category, builtin_template};
+///
+/// assert!(builtin_template(Category::BugFix).contains("## Reproduction"));
+/// ```
+#[must_use]
+pub fn builtin_template(category: Category) -> &'static str {
+    match category {
+        Category::Feature => {
+            "## Overview\n\n## Goals\n\n## Non-Goals\n\n## Design\n\n## Acceptance Criteria\n"
+        }
+        Category::Enhancement => {
+            "## Current Behavior\n\n## Proposed Change\n\n## Impact\n\n## Acceptance Criteria\n"
+        }
+        Category::BugFix => {
+            "## Problem\n\n## Reproduction\n\n## Expected Behavior\n\n## Root Cause\n\n## Fix\n"
+        }
+        Category::Refactor => {
+            "## Motivation\n\n## Current Structure\n\n## Target Structure\n\n## Risks\n"
+        }
+        Category::Documentation => "## Audience\n\n## Scope\n\n## Outline\n",
+        Category::Infrastructure => "## Motivation\n\n## Changes\n\n## Rollout\n\n## Rollback\n",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_category_has_a_template() {
+        for category in Category::ALL {
+            let template = builtin_template(category);
+            assert!(
+                template.starts_with("## "),
+                "{category} template should start with a section heading"
+            );
+        }
+    }
+
+    #[test]
+    fn test_templates_differ_by_category() {
+        assert_ne!(
+            builtin_template(Category::Feature),
+            builtin_template(Category::BugFix)
+        );
+    }
+}
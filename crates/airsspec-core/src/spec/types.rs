@@ -2,18 +2,85 @@
 //!
 //! This module defines the main specification types used throughout the system.
 
+use std::collections::{BTreeMap, HashMap};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::shared::LifecycleState;
+use crate::utils::checksum;
+use crate::utils::clock::{Clock, SystemClock};
+
+use super::approval::Approval;
 use super::category::Category;
 use super::dependency::Dependency;
 use super::id::SpecId;
 
+/// Markers that flag unfinished work in spec content.
+const UNRESOLVED_MARKERS: &[Marker] = &[
+    Marker::exact("TODO"),
+    Marker::exact("FIXME"),
+    Marker::exact("???"),
+];
+
+/// Filler text left behind by drafting, reported by
+/// [`Spec::contains_placeholders`].
+const PLACEHOLDER_MARKERS: &[Marker] = &[
+    Marker::ignoring_case("lorem ipsum"),
+    Marker::exact("TBD"),
+    Marker::exact("XXX"),
+];
+
+/// A word or phrase searched for in spec content by [`find_markers`].
+#[derive(Debug, Clone, Copy)]
+struct Marker {
+    /// The text to find; lowercase ASCII if `ignore_case` is set.
+    text: &'static str,
+    /// Whether ASCII case is ignored when matching.
+    ignore_case: bool,
+}
+
+impl Marker {
+    /// A marker matched case-sensitively.
+    const fn exact(text: &'static str) -> Self {
+        Self {
+            text,
+            ignore_case: false,
+        }
+    }
+
+    /// A marker matched in any ASCII case; `text` must be lowercase.
+    const fn ignoring_case(text: &'static str) -> Self {
+        Self {
+            text,
+            ignore_case: true,
+        }
+    }
+
+    /// Returns whether the marker occurs in `line` as a whole word.
+    fn is_in(self, line: &str) -> bool {
+        if self.ignore_case {
+            contains_word(&line.to_ascii_lowercase(), self.text)
+        } else {
+            contains_word(line, self.text)
+        }
+    }
+}
+
+/// Line opening a table of contents inserted by [`Spec::insert_toc`].
+const TOC_START: &str = "<!-- airsspec:toc -->";
+
+/// Line closing a table of contents inserted by [`Spec::insert_toc`].
+const TOC_END: &str = "<!-- /airsspec:toc -->";
+
 /// Metadata associated with a specification.
 ///
 /// Contains descriptive and organizational information about a spec,
 /// including timestamps for tracking creation and modification.
 ///
+/// Fields serialize in declaration order, which is the key order of spec
+/// files on disk; keep it stable so diffs stay clean.
+///
 /// # Examples
 ///
 /// ```
@@ -35,9 +102,25 @@ pub struct SpecMetadata {
     /// Category classifying the type of work.
     category: Category,
 
+    /// Person or team accountable for the spec.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+
+    /// Sign-offs given on the spec, in the order they were added.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    approvals: Vec<Approval>,
+
+    /// Labels for navigating related specs (e.g., `payments`, `api`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+
     /// Dependencies on other specifications.
     dependencies: Vec<Dependency>,
 
+    /// Free-form key/value fields (e.g., ticket, owner, channel).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    custom: BTreeMap<String, String>,
+
     /// Timestamp when the spec was created.
     created_at: DateTime<Utc>,
 
@@ -66,12 +149,38 @@ impl SpecMetadata {
     /// ```
     #[must_use]
     pub fn new(title: impl Into<String>, description: impl Into<String>) -> Self {
-        let now = Utc::now();
+        Self::new_with_clock(title, description, &SystemClock)
+    }
+
+    /// Creates new metadata, reading the creation time from `clock`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecMetadata;
+    /// use airsspec_core::utils::clock::FixedClock;
+    /// use chrono::DateTime;
+    ///
+    /// let at = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+    /// let metadata = SpecMetadata::new_with_clock("Title", "Desc", &FixedClock::new(at));
+    /// assert_eq!(metadata.created_at(), at);
+    /// ```
+    #[must_use]
+    pub fn new_with_clock(
+        title: impl Into<String>,
+        description: impl Into<String>,
+        clock: &impl Clock,
+    ) -> Self {
+        let now = clock.now();
         Self {
             title: title.into(),
             description: description.into(),
             category: Category::default(),
+            owner: None,
+            approvals: Vec::new(),
+            tags: Vec::new(),
             dependencies: Vec::new(),
+            custom: BTreeMap::new(),
             created_at: now,
             updated_at: now,
         }
@@ -95,12 +204,59 @@ impl SpecMetadata {
         self.category
     }
 
+    /// Returns the owner of the specification, if assigned.
+    #[must_use]
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Returns the sign-offs given on the specification.
+    #[must_use]
+    pub fn approvals(&self) -> &[Approval] {
+        &self.approvals
+    }
+
+    /// Returns the tags of this specification.
+    #[must_use]
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Returns true if the specification carries `tag`.
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
     /// Returns the dependencies of this specification.
     #[must_use]
     pub fn dependencies(&self) -> &[Dependency] {
         &self.dependencies
     }
 
+    /// Returns the value of a custom field, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::SpecMetadata;
+    ///
+    /// let mut metadata = SpecMetadata::new("Payments", "Stripe integration");
+    /// metadata.set_custom("jira", "PAY-42");
+    /// assert_eq!(metadata.get_custom("jira"), Some("PAY-42"));
+    /// assert_eq!(metadata.get_custom("owner"), None);
+    /// ```
+    #[must_use]
+    pub fn get_custom(&self, key: &str) -> Option<&str> {
+        self.custom.get(key).map(String::as_str)
+    }
+
+    /// Returns all custom fields, ordered by key.
+    #[must_use]
+    pub fn custom(&self) -> &BTreeMap<String, String> {
+        &self.custom
+    }
+
     /// Returns when the specification was created.
     #[must_use]
     pub fn created_at(&self) -> DateTime<Utc> {
@@ -116,36 +272,80 @@ impl SpecMetadata {
     /// Sets the title of the specification.
     pub fn set_title(&mut self, title: impl Into<String>) {
         self.title = title.into();
-        self.updated_at = Utc::now();
+        self.touch();
     }
 
     /// Sets the description of the specification.
     pub fn set_description(&mut self, description: impl Into<String>) {
         self.description = description.into();
-        self.updated_at = Utc::now();
+        self.touch();
     }
 
     /// Sets the category of the specification.
     pub fn set_category(&mut self, category: Category) {
         self.category = category;
-        self.updated_at = Utc::now();
+        self.touch();
+    }
+
+    /// Sets the owner of the specification.
+    pub fn set_owner(&mut self, owner: impl Into<String>) {
+        self.owner = Some(owner.into());
+        self.touch();
+    }
+
+    /// Removes the owner of the specification.
+    pub fn clear_owner(&mut self) {
+        self.owner = None;
+        self.touch();
+    }
+
+    /// Records a sign-off on this specification.
+    pub fn add_approval(&mut self, approval: Approval) {
+        self.approvals.push(approval);
+        self.touch();
+    }
+
+    /// Adds a tag, unless the specification already carries it.
+    pub fn add_tag(&mut self, tag: impl Into<String>) {
+        let tag = tag.into();
+        if !self.has_tag(&tag) {
+            self.tags.push(tag);
+            self.touch();
+        }
+    }
+
+    /// Sets the tags of this specification, replacing any existing ones.
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags = tags;
+        self.touch();
     }
 
     /// Adds a dependency to this specification.
     pub fn add_dependency(&mut self, dependency: Dependency) {
         self.dependencies.push(dependency);
-        self.updated_at = Utc::now();
+        self.touch();
     }
 
     /// Sets the dependencies of this specification, replacing any existing ones.
     pub fn set_dependencies(&mut self, dependencies: Vec<Dependency>) {
         self.dependencies = dependencies;
-        self.updated_at = Utc::now();
+        self.touch();
+    }
+
+    /// Sets a custom field, replacing any existing value for the key.
+    pub fn set_custom(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.custom.insert(key.into(), value.into());
+        self.touch();
     }
 
     /// Updates the `updated_at` timestamp to the current time.
     pub fn touch(&mut self) {
-        self.updated_at = Utc::now();
+        self.touch_with_clock(&SystemClock);
+    }
+
+    /// Updates the `updated_at` timestamp to the time reported by `clock`.
+    pub fn touch_with_clock(&mut self, clock: &impl Clock) {
+        self.updated_at = clock.now();
     }
 }
 
@@ -155,6 +355,9 @@ impl SpecMetadata {
 /// It contains a unique identifier, metadata describing the work, and the actual
 /// content (typically Markdown documentation).
 ///
+/// Fields serialize in declaration order with `content` last, so edits to a
+/// long body never shift the metadata keys around in a diff.
+///
 /// # Examples
 ///
 /// ```
@@ -236,6 +439,29 @@ impl Spec {
         self.metadata.touch();
     }
 
+    /// Returns a checksum of the content as 16 lowercase hex digits.
+    ///
+    /// The hash (64-bit FNV-1a) is stable across runs and platforms, so it
+    /// can be stored and compared later to detect edits made outside the
+    /// server. It is not a cryptographic hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+    ///
+    /// let mut spec = Spec::new(SpecId::new(1, "a"), SpecMetadata::new("A", ""), "v1");
+    /// let before = spec.content_hash();
+    /// assert_eq!(before.len(), 16);
+    ///
+    /// spec.set_content("v2");
+    /// assert_ne!(spec.content_hash(), before);
+    /// ```
+    #[must_use]
+    pub fn content_hash(&self) -> String {
+        checksum::fnv1a_hex(self.content.as_bytes())
+    }
+
     /// Returns the title from metadata (convenience accessor).
     #[must_use]
     pub fn title(&self) -> &str {
@@ -254,11 +480,479 @@ impl Spec {
         self.metadata.category()
     }
 
+    /// Returns the owner from metadata (convenience accessor).
+    #[must_use]
+    pub fn owner(&self) -> Option<&str> {
+        self.metadata.owner()
+    }
+
+    /// Returns the tags from metadata (convenience accessor).
+    #[must_use]
+    pub fn tags(&self) -> &[String] {
+        self.metadata.tags()
+    }
+
     /// Returns the dependencies from metadata (convenience accessor).
     #[must_use]
     pub fn dependencies(&self) -> &[Dependency] {
         self.metadata.dependencies()
     }
+
+    /// Returns `true` if an open spec has not been updated for more than
+    /// `threshold_secs` seconds before `now` (a Unix timestamp).
+    ///
+    /// Only [`Draft`](LifecycleState::Draft) and
+    /// [`Active`](LifecycleState::Active) specs can be stale; finished,
+    /// blocked, cancelled and archived specs never are. Specs do not store
+    /// their lifecycle state, so the caller supplies it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::shared::LifecycleState;
+    /// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+    /// use airsspec_core::utils::clock::FixedClock;
+    /// use chrono::DateTime;
+    ///
+    /// let at = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+    /// let metadata = SpecMetadata::new_with_clock("Old", "", &FixedClock::new(at));
+    /// let spec = Spec::new(SpecId::new(1_737_734_400, "old"), metadata, "");
+    /// let month_later = 1_737_734_400 + 30 * 24 * 3600;
+    ///
+    /// assert!(spec.is_stale(LifecycleState::Active, month_later, 14 * 24 * 3600));
+    /// assert!(!spec.is_stale(LifecycleState::Done, month_later, 14 * 24 * 3600));
+    /// ```
+    #[must_use]
+    pub fn is_stale(&self, lifecycle: LifecycleState, now: i64, threshold_secs: i64) -> bool {
+        matches!(lifecycle, LifecycleState::Draft | LifecycleState::Active)
+            && now.saturating_sub(self.metadata.updated_at().timestamp()) > threshold_secs
+    }
+
+    /// Returns content lines that contain an unresolved marker.
+    ///
+    /// Scans for `TODO`, `FIXME`, and `???`, matched case-sensitively as
+    /// whole words (so `TODOs` or `todo` do not count). Each entry pairs the
+    /// 1-based line number with the trimmed line text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+    ///
+    /// let spec = Spec::new(
+    ///     SpecId::new(1_737_734_400, "markers"),
+    ///     SpecMetadata::new("Markers", "Marker scan"),
+    ///     "# Markers\n\nTODO: decide on storage",
+    /// );
+    /// assert_eq!(
+    ///     spec.unresolved_markers(),
+    ///     vec![(3, "TODO: decide on storage".to_string())]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn unresolved_markers(&self) -> Vec<(usize, String)> {
+        let mut lines: Vec<(usize, String)> = Vec::new();
+        for (line, _, text) in find_markers(&self.content, UNRESOLVED_MARKERS) {
+            if lines.last().is_none_or(|(last, _)| *last != line) {
+                lines.push((line, text.to_string()));
+            }
+        }
+        lines
+    }
+
+    /// Returns the placeholder markers that appear in the content.
+    ///
+    /// Scans for `lorem ipsum`, `TBD`, and `XXX` with the same rules as
+    /// [`unresolved_markers`](Self::unresolved_markers): whole words only,
+    /// and case-sensitive except for `lorem ipsum`, so `Lorem Ipsum` counts
+    /// but `tbd` or `XXXL` do not. Each marker found is returned once, as
+    /// written here and in that order; see
+    /// [`placeholder_lines`](Self::placeholder_lines) for where they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+    ///
+    /// let spec = Spec::new(
+    ///     SpecId::new(1_737_734_400, "draft"),
+    ///     SpecMetadata::new("Draft", "Unpolished"),
+    ///     "# Draft\n\nRollout plan: TBD",
+    /// );
+    /// assert_eq!(spec.contains_placeholders(), vec!["TBD".to_string()]);
+    /// ```
+    #[must_use]
+    pub fn contains_placeholders(&self) -> Vec<String> {
+        let found = find_markers(&self.content, PLACEHOLDER_MARKERS);
+        PLACEHOLDER_MARKERS
+            .iter()
+            .filter(|marker| found.iter().any(|(_, m, _)| *m == marker.text))
+            .map(|marker| marker.text.to_string())
+            .collect()
+    }
+
+    /// Returns each placeholder occurrence as the 1-based line number and
+    /// the marker, in line order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+    ///
+    /// let spec = Spec::new(
+    ///     SpecId::new(1_737_734_400, "draft"),
+    ///     SpecMetadata::new("Draft", "Unpolished"),
+    ///     "# Draft\n\nRollout plan: TBD",
+    /// );
+    /// assert_eq!(spec.placeholder_lines(), vec![(3, "TBD".to_string())]);
+    /// ```
+    #[must_use]
+    pub fn placeholder_lines(&self) -> Vec<(usize, String)> {
+        find_markers(&self.content, PLACEHOLDER_MARKERS)
+            .into_iter()
+            .map(|(line, marker, _)| (line, marker.to_string()))
+            .collect()
+    }
+
+    /// Normalizes heading levels in imported content.
+    ///
+    /// Removes a leading H1 that repeats the title (the title already lives
+    /// in metadata), along with the blank lines after it, and demotes any
+    /// other H1 to H2 so that sections start at H2. Deeper headings and
+    /// lines inside fenced code blocks are left alone.
+    ///
+    /// Returns the number of adjustments made. The content (and
+    /// `updated_at`) is only changed when that number is non-zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+    ///
+    /// let mut spec = Spec::new(
+    ///     SpecId::new(1_737_734_400, "auth"),
+    ///     SpecMetadata::new("Auth", "Auth flow"),
+    ///     "# Auth\n\n# Goals\n\nLog in.",
+    /// );
+    /// assert_eq!(spec.normalize_headings(), 2);
+    /// assert_eq!(spec.content(), "## Goals\n\nLog in.");
+    /// ```
+    pub fn normalize_headings(&mut self) -> usize {
+        let mut lines: Vec<&str> = self.content.lines().collect();
+        let mut adjustments = 0;
+
+        if let Some(first) = lines.iter().position(|line| !line.trim().is_empty())
+            && h1_text(lines[first]).is_some_and(|text| text.eq_ignore_ascii_case(self.title()))
+        {
+            let end = lines[first + 1..]
+                .iter()
+                .position(|line| !line.trim().is_empty())
+                .map_or(lines.len(), |offset| first + 1 + offset);
+            lines.drain(first..end);
+            adjustments += 1;
+        }
+
+        let mut in_code_block = false;
+        let mut normalized: Vec<String> = Vec::with_capacity(lines.len());
+        for line in lines {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            if !in_code_block && h1_text(line).is_some() {
+                normalized.push(format!("#{line}"));
+                adjustments += 1;
+            } else {
+                normalized.push(line.to_string());
+            }
+        }
+
+        if adjustments > 0 {
+            let mut content = normalized.join("\n");
+            if self.content.ends_with('\n') && !content.is_empty() {
+                content.push('\n');
+            }
+            self.set_content(content);
+        }
+        adjustments
+    }
+
+    /// Builds a table of contents from the `##` and `###` headings.
+    ///
+    /// Returns a Markdown list of anchor links, with `###` entries nested
+    /// under the preceding `##`. Anchors follow GitHub's scheme (lowercase,
+    /// punctuation dropped, spaces to hyphens, `-1`, `-2`, ... for repeats).
+    /// Headings inside fenced code blocks are ignored. Returns an empty
+    /// string if there are no such headings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+    ///
+    /// let spec = Spec::new(
+    ///     SpecId::new(1_737_734_400, "auth"),
+    ///     SpecMetadata::new("Auth", "Auth flow"),
+    ///     "## Goals\n\n### Login Flow\n",
+    /// );
+    /// assert_eq!(
+    ///     spec.generate_toc(),
+    ///     "- [Goals](#goals)\n  - [Login Flow](#login-flow)"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn generate_toc(&self) -> String {
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        let mut entries = Vec::new();
+        let mut in_code_block = false;
+
+        for line in self.content.lines() {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            if in_code_block {
+                continue;
+            }
+            let Some((level, text)) = heading(line) else {
+                continue;
+            };
+            // Anchors count every heading level, so track them all.
+            let base = heading_anchor(text);
+            let count = seen.entry(base.clone()).or_insert(0);
+            let anchor = if *count == 0 {
+                base
+            } else {
+                format!("{base}-{count}")
+            };
+            *count += 1;
+
+            match level {
+                2 => entries.push(format!("- [{text}](#{anchor})")),
+                3 => entries.push(format!("  - [{text}](#{anchor})")),
+                _ => {}
+            }
+        }
+
+        entries.join("\n")
+    }
+
+    /// Inserts the [`generate_toc`](Self::generate_toc) output after the
+    /// first heading, between `<!-- airsspec:toc -->` marker comments.
+    ///
+    /// If the content already has a marked table of contents it is replaced
+    /// in place, so running this again after editing headings refreshes the
+    /// list instead of adding a second one. Content without any heading gets
+    /// the table at the top. The content (and `updated_at`) is only changed
+    /// when the result differs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+    ///
+    /// let mut spec = Spec::new(
+    ///     SpecId::new(1_737_734_400, "auth"),
+    ///     SpecMetadata::new("Auth", "Auth flow"),
+    ///     "# Auth\n\n## Goals\n",
+    /// );
+    /// spec.insert_toc();
+    /// spec.insert_toc();
+    /// assert_eq!(spec.content().matches("- [Goals](#goals)").count(), 1);
+    /// ```
+    pub fn insert_toc(&mut self) {
+        let toc = self.generate_toc();
+        let block = if toc.is_empty() {
+            format!("{TOC_START}\n{TOC_END}")
+        } else {
+            format!("{TOC_START}\n{toc}\n{TOC_END}")
+        };
+
+        let lines: Vec<&str> = self.content.lines().collect();
+        let existing = lines
+            .iter()
+            .position(|line| line.trim() == TOC_START)
+            .and_then(|start| {
+                lines[start..]
+                    .iter()
+                    .position(|line| line.trim() == TOC_END)
+                    .map(|offset| (start, start + offset))
+            });
+
+        let mut updated: Vec<&str> = Vec::with_capacity(lines.len() + 4);
+        if let Some((start, end)) = existing {
+            updated.extend_from_slice(&lines[..start]);
+            updated.push(&block);
+            updated.extend_from_slice(&lines[end + 1..]);
+        } else {
+            let mut in_code_block = false;
+            let first_heading = lines.iter().position(|line| {
+                if line.trim_start().starts_with("```") {
+                    in_code_block = !in_code_block;
+                }
+                !in_code_block && heading(line).is_some()
+            });
+            let at = first_heading.map_or(0, |index| index + 1);
+
+            updated.extend_from_slice(&lines[..at]);
+            if at > 0 {
+                updated.push("");
+            }
+            updated.push(&block);
+            if lines.get(at).is_some_and(|line| !line.trim().is_empty()) {
+                updated.push("");
+            }
+            updated.extend_from_slice(&lines[at..]);
+        }
+
+        let mut content = updated.join("\n");
+        if self.content.ends_with('\n') {
+            content.push('\n');
+        }
+        if content != self.content {
+            self.set_content(content);
+        }
+    }
+
+    /// Moves the `##` sections named in `order` to the front, in that order.
+    ///
+    /// Headings match case-insensitively. Sections not named in `order`
+    /// follow in their original relative order, and text before the first
+    /// `##` heading stays on top. A section runs until the next `##`
+    /// heading outside a fenced code block, so its `###` subsections move
+    /// with it. Sections are separated by one blank line afterwards.
+    ///
+    /// Returns the number of sections whose position changed. The content
+    /// (and `updated_at`) is only changed when that number is non-zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+    ///
+    /// let mut spec = Spec::new(
+    ///     SpecId::new(1_737_734_400, "auth"),
+    ///     SpecMetadata::new("Auth", "Auth flow"),
+    ///     "## Goals\n\nLog in.\n\n## Overview\n\nAuth.\n",
+    /// );
+    /// assert_eq!(spec.reorder_sections(&["Overview", "Goals"]), 2);
+    /// assert_eq!(spec.content(), "## Overview\n\nAuth.\n\n## Goals\n\nLog in.\n");
+    /// ```
+    pub fn reorder_sections(&mut self, order: &[&str]) -> usize {
+        let mut preamble: Vec<&str> = Vec::new();
+        let mut sections: Vec<(&str, Vec<&str>)> = Vec::new();
+        let mut in_code_block = false;
+
+        for line in self.content.lines() {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            match heading(line) {
+                Some((2, text)) if !in_code_block => sections.push((text, vec![line])),
+                _ => match sections.last_mut() {
+                    Some((_, lines)) => lines.push(line),
+                    None => preamble.push(line),
+                },
+            }
+        }
+
+        let rank = |title: &str| {
+            order
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(title))
+                .unwrap_or(order.len())
+        };
+        let mut indexed: Vec<(usize, (&str, Vec<&str>))> =
+            sections.into_iter().enumerate().collect();
+        // Stable, so equal ranks keep their original relative order.
+        indexed.sort_by_key(|(_, (title, _))| rank(title));
+
+        let moved = indexed
+            .iter()
+            .enumerate()
+            .filter(|(position, (original, _))| position != original)
+            .count();
+        if moved == 0 {
+            return 0;
+        }
+
+        let mut blocks: Vec<String> = Vec::with_capacity(indexed.len() + 1);
+        let preamble = preamble.join("\n");
+        if !preamble.trim().is_empty() {
+            blocks.push(preamble.trim_end().to_string());
+        }
+        for (_, (_, lines)) in indexed {
+            blocks.push(lines.join("\n").trim_end().to_string());
+        }
+
+        let mut content = blocks.join("\n\n");
+        if self.content.ends_with('\n') {
+            content.push('\n');
+        }
+        self.set_content(content);
+        moved
+    }
+}
+
+/// Returns the level and text of a Markdown ATX heading (`## Text`).
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.bytes().take_while(|&b| b == b'#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &line[level..];
+    (rest.is_empty() || rest.starts_with(' ')).then(|| (level, rest.trim()))
+}
+
+/// Converts heading text to a GitHub-style anchor.
+fn heading_anchor(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Returns the heading text if `line` is a Markdown H1 (`# Text`).
+fn h1_text(line: &str) -> Option<&str> {
+    line.strip_prefix("# ")
+        .map(str::trim)
+        .or_else(|| (line == "#").then_some(""))
+}
+
+/// Finds every occurrence of `markers` in `content`, line by line.
+///
+/// Returns the 1-based line number, the marker text, and the trimmed line
+/// for each marker on each line, in line order and then marker order.
+/// Matching follows each [`Marker`]'s case rule, and only whole words
+/// count: a marker that starts or ends with a letter, digit, or `_` must
+/// not touch another one.
+fn find_markers<'c>(content: &'c str, markers: &[Marker]) -> Vec<(usize, &'static str, &'c str)> {
+    let mut found = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        for &marker in markers {
+            if marker.is_in(line) {
+                found.push((idx + 1, marker.text, line.trim()));
+            }
+        }
+    }
+    found
+}
+
+/// Returns whether `word` occurs in `line` with no letter, digit, or `_`
+/// directly before or after it.
+fn contains_word(line: &str, word: &str) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let starts_word = word.chars().next().is_some_and(is_word_char);
+    let ends_word = word.chars().next_back().is_some_and(is_word_char);
+    line.match_indices(word).any(|(start, _)| {
+        let joined_before =
+            starts_word && line[..start].chars().next_back().is_some_and(is_word_char);
+        let joined_after = ends_word
+            && line[start + word.len()..]
+                .chars()
+                .next()
+                .is_some_and(is_word_char);
+        !joined_before && !joined_after
+    })
 }
 
 #[cfg(test)]
@@ -420,10 +1114,408 @@ mod tests {
         assert_eq!(spec.content(), "Dynamic content");
     }
 
+    #[test]
+    fn test_unresolved_markers_found() {
+        let id = SpecId::new(1_737_734_400, "markers");
+        let metadata = SpecMetadata::new("Markers", "Marker scan");
+        let content = "# Title\n\n- TODO: pick a database\nResolved line\n  FIXME handle retries\n";
+        let spec = Spec::new(id, metadata, content);
+
+        assert_eq!(
+            spec.unresolved_markers(),
+            vec![
+                (3, "- TODO: pick a database".to_string()),
+                (5, "FIXME handle retries".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_markers_question_marks() {
+        let id = SpecId::new(1_737_734_400, "questions");
+        let metadata = SpecMetadata::new("Questions", "Marker scan");
+        let spec = Spec::new(id, metadata, "Owner: ???");
+
+        assert_eq!(
+            spec.unresolved_markers(),
+            vec![(1, "Owner: ???".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_markers_clean_content() {
+        let id = SpecId::new(1_737_734_400, "clean");
+        let metadata = SpecMetadata::new("Clean", "Marker scan");
+        let spec = Spec::new(id, metadata, "# Clean\n\nNothing left to decide.");
+
+        assert!(spec.unresolved_markers().is_empty());
+    }
+
+    #[test]
+    fn test_contains_placeholders_flags_tbd() {
+        let id = SpecId::new(1_737_734_400, "draft");
+        let metadata = SpecMetadata::new("Draft", "Placeholder scan");
+        let spec = Spec::new(
+            id,
+            metadata,
+            "# Draft\n\nRetry policy: TBD\nLorem ipsum dolor",
+        );
+
+        assert_eq!(
+            spec.contains_placeholders(),
+            vec!["lorem ipsum".to_string(), "TBD".to_string()]
+        );
+        assert_eq!(
+            spec.placeholder_lines(),
+            vec![(3, "TBD".to_string()), (4, "lorem ipsum".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_lorem_ipsum_matches_in_any_case_once() {
+        let id = SpecId::new(1_737_734_400, "draft");
+        let metadata = SpecMetadata::new("Draft", "Placeholder scan");
+        let spec = Spec::new(
+            id,
+            metadata,
+            "LOREM IPSUM dolor\nLorem Ipsum sit\nlorem ipsum amet\nLoremipsum",
+        );
+
+        assert_eq!(
+            spec.contains_placeholders(),
+            vec!["lorem ipsum".to_string()]
+        );
+        assert_eq!(
+            spec.placeholder_lines(),
+            vec![
+                (1, "lorem ipsum".to_string()),
+                (2, "lorem ipsum".to_string()),
+                (3, "lorem ipsum".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contains_placeholders_matches_whole_words_case_sensitively() {
+        let id = SpecId::new(1_737_734_400, "words");
+        let metadata = SpecMetadata::new("Words", "Placeholder scan");
+        let spec = Spec::new(
+            id,
+            metadata,
+            "Sizes: XXXL and XXS\nStatus: tbd\nSee TBDs below\nOwner: (TBD)",
+        );
+
+        assert_eq!(spec.contains_placeholders(), vec!["TBD".to_string()]);
+        assert_eq!(spec.placeholder_lines(), vec![(4, "TBD".to_string())]);
+    }
+
+    #[test]
+    fn test_unresolved_markers_match_whole_words() {
+        let id = SpecId::new(1_737_734_400, "words");
+        let metadata = SpecMetadata::new("Words", "Marker scan");
+        let spec = Spec::new(
+            id,
+            metadata,
+            "TODOS list\ntodo: lowercase\nTODO/FIXME both\nWhat now???",
+        );
+
+        assert_eq!(
+            spec.unresolved_markers(),
+            vec![
+                (3, "TODO/FIXME both".to_string()),
+                (4, "What now???".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contains_placeholders_clean_content() {
+        let id = SpecId::new(1_737_734_400, "clean");
+        let metadata = SpecMetadata::new("Clean", "Placeholder scan");
+        let spec = Spec::new(id, metadata, "# Clean\n\nRetries use exponential backoff.");
+
+        assert!(spec.contains_placeholders().is_empty());
+    }
+
+    #[test]
+    fn test_normalize_headings_drops_redundant_title() {
+        let id = SpecId::new(1_737_734_400, "auth");
+        let metadata = SpecMetadata::new("User Auth", "Auth flow");
+        let mut spec = Spec::new(id, metadata, "\n# user auth\n\n## Goals\n\nLog in.\n");
+
+        assert_eq!(spec.normalize_headings(), 1);
+        assert_eq!(spec.content(), "\n## Goals\n\nLog in.\n");
+    }
+
+    #[test]
+    fn test_normalize_headings_mixed_levels() {
+        let id = SpecId::new(1_737_734_400, "mixed");
+        let metadata = SpecMetadata::new("Mixed", "Heading levels");
+        let content = "# Overview\n\nText.\n\n## Scope\n\n### Details\n\n# Risks\n\n```sh\n# not a heading\n```";
+        let mut spec = Spec::new(id, metadata, content);
+
+        assert_eq!(spec.normalize_headings(), 2);
+        assert_eq!(
+            spec.content(),
+            "## Overview\n\nText.\n\n## Scope\n\n### Details\n\n## Risks\n\n```sh\n# not a heading\n```"
+        );
+    }
+
+    #[test]
+    fn test_normalize_headings_already_normal() {
+        let id = SpecId::new(1_737_734_400, "normal");
+        let metadata = SpecMetadata::new("Normal", "Heading levels");
+        let mut spec = Spec::new(id, metadata, "## Goals\n\n#hashtag is not a heading");
+        let updated_at = spec.metadata().updated_at();
+
+        assert_eq!(spec.normalize_headings(), 0);
+        assert_eq!(spec.content(), "## Goals\n\n#hashtag is not a heading");
+        assert_eq!(spec.metadata().updated_at(), updated_at);
+    }
+
+    #[test]
+    fn test_content_hash_is_stable_fnv1a() {
+        let id = SpecId::new(1_737_734_400, "hash");
+        let empty = Spec::new(id.clone(), SpecMetadata::new("Hash", ""), "");
+        let a = Spec::new(id, SpecMetadata::new("Other title", ""), "a");
+
+        assert_eq!(empty.content_hash(), "cbf29ce484222325");
+        assert_eq!(a.content_hash(), "af63dc4c8601ec8c");
+    }
+
     // This test verifies the SpecError import is working (used in builder)
     #[test]
     fn test_spec_error_available() {
         let err = SpecError::MissingField("title".to_string());
         assert!(err.to_string().contains("title"));
     }
+
+    #[test]
+    fn test_custom_fields_set_and_get() {
+        let mut metadata = SpecMetadata::new("Title", "Description");
+        assert!(metadata.custom().is_empty());
+
+        metadata.set_custom("owner", "alice");
+        metadata.set_custom("jira", "PROJ-1");
+        metadata.set_custom("owner", "bob");
+
+        assert_eq!(metadata.get_custom("owner"), Some("bob"));
+        assert_eq!(metadata.get_custom("jira"), Some("PROJ-1"));
+        assert_eq!(metadata.get_custom("missing"), None);
+        assert_eq!(
+            metadata.custom().keys().collect::<Vec<_>>(),
+            vec!["jira", "owner"]
+        );
+    }
+
+    #[test]
+    fn test_custom_fields_serde_roundtrip() {
+        let mut metadata = SpecMetadata::new("Title", "Description");
+        metadata.set_custom("slack", "#payments");
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        let parsed: SpecMetadata = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.get_custom("slack"), Some("#payments"));
+    }
+
+    #[test]
+    fn test_approvals_serde_roundtrip() {
+        let at = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+        let mut metadata = SpecMetadata::new("Title", "Description");
+        metadata.add_approval(Approval::new("alice", at));
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        let parsed: SpecMetadata = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.approvals(), [Approval::new("alice", at)]);
+    }
+
+    #[test]
+    fn test_approvals_default_when_absent() {
+        let metadata = SpecMetadata::new("Title", "Description");
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert!(!json.contains("approvals"));
+
+        let parsed: SpecMetadata = serde_json::from_str(&json).unwrap();
+        assert!(parsed.approvals().is_empty());
+    }
+
+    #[test]
+    fn test_metadata_tags() {
+        let mut metadata = SpecMetadata::new("Title", "Description");
+        metadata.add_tag("payments");
+        metadata.add_tag("payments");
+        metadata.add_tag("api");
+
+        assert_eq!(metadata.tags(), ["payments", "api"]);
+        assert!(metadata.has_tag("api"));
+        assert!(!metadata.has_tag("search"));
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        let parsed: SpecMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.tags(), metadata.tags());
+    }
+
+    #[test]
+    fn test_custom_fields_default_when_absent() {
+        let metadata = SpecMetadata::new("Title", "Description");
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert!(!json.contains("custom"));
+
+        let parsed: SpecMetadata = serde_json::from_str(&json).unwrap();
+        assert!(parsed.custom().is_empty());
+    }
+
+    #[test]
+    fn test_metadata_with_fixed_clock() {
+        use crate::utils::clock::FixedClock;
+
+        let created = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+        let later = DateTime::from_timestamp(1_737_738_000, 0).unwrap();
+
+        let mut metadata =
+            SpecMetadata::new_with_clock("Title", "Description", &FixedClock::new(created));
+        assert_eq!(metadata.created_at(), created);
+        assert_eq!(metadata.updated_at(), created);
+
+        metadata.touch_with_clock(&FixedClock::new(later));
+        assert_eq!(metadata.created_at(), created);
+        assert_eq!(metadata.updated_at(), later);
+    }
+
+    fn sections_spec(content: &str) -> Spec {
+        Spec::new(
+            SpecId::new(1_737_734_400, "sections"),
+            SpecMetadata::new("Sections", "Sections test"),
+            content,
+        )
+    }
+
+    #[test]
+    fn test_reorder_sections_into_canonical_order() {
+        let mut spec = sections_spec(
+            "Intro.\n\n## Details\n\n### API\n\n```md\n## Not a section\n```\n\n## non-goals\n\nNo SSO.\n\n## Overview\n\nAuth.\n\n## Goals\n\nLog in.\n",
+        );
+
+        let moved = spec.reorder_sections(&["Overview", "Goals", "Non-Goals", "Details"]);
+
+        assert_eq!(moved, 4);
+        assert_eq!(
+            spec.content(),
+            "Intro.\n\n## Overview\n\nAuth.\n\n## Goals\n\nLog in.\n\n## non-goals\n\nNo SSO.\n\n## Details\n\n### API\n\n```md\n## Not a section\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_reorder_sections_keeps_unlisted_sections_last_in_order() {
+        let mut spec = sections_spec("## Risks\n\nR.\n\n## Goals\n\nG.\n\n## Notes\n\nN.");
+
+        let moved = spec.reorder_sections(&["Overview", "Goals"]);
+
+        assert_eq!(moved, 2);
+        assert_eq!(
+            spec.content(),
+            "## Goals\n\nG.\n\n## Risks\n\nR.\n\n## Notes\n\nN."
+        );
+    }
+
+    #[test]
+    fn test_reorder_sections_already_ordered_is_unchanged() {
+        let content = "# Auth\n## Overview\nA.\n## Goals\nG.\n";
+        let mut spec = sections_spec(content);
+        let updated_at = spec.metadata().updated_at();
+
+        assert_eq!(spec.reorder_sections(&["Overview", "Goals"]), 0);
+        assert_eq!(spec.content(), content);
+        assert_eq!(spec.metadata().updated_at(), updated_at);
+    }
+
+    fn toc_spec(content: &str) -> Spec {
+        Spec::new(
+            SpecId::new(1_737_734_400, "toc"),
+            SpecMetadata::new("Toc", "Table of contents"),
+            content,
+        )
+    }
+
+    #[test]
+    fn test_generate_toc_nests_headings() {
+        let spec = toc_spec(
+            "# Auth\n\n## Goals\n\n### Login & Logout\n\n### API\n\n## Risks\n\n#### Deep\n\n```md\n## Not a heading\n```\n\n## Goals\n",
+        );
+
+        assert_eq!(
+            spec.generate_toc(),
+            "- [Goals](#goals)\n  - [Login & Logout](#login--logout)\n  - [API](#api)\n- [Risks](#risks)\n- [Goals](#goals-1)"
+        );
+    }
+
+    #[test]
+    fn test_generate_toc_without_sections_is_empty() {
+        assert_eq!(toc_spec("# Auth\n\nJust text.").generate_toc(), "");
+    }
+
+    #[test]
+    fn test_insert_toc_after_first_heading() {
+        let mut spec = toc_spec("# Auth\n\n## Goals\n\nLog in.\n");
+
+        spec.insert_toc();
+
+        assert_eq!(
+            spec.content(),
+            "# Auth\n\n<!-- airsspec:toc -->\n- [Goals](#goals)\n<!-- /airsspec:toc -->\n\n## Goals\n\nLog in.\n"
+        );
+    }
+
+    #[test]
+    fn test_insert_toc_is_idempotent_and_refreshes() {
+        let mut spec = toc_spec("# Auth\n\n## Goals\n\nLog in.\n");
+        spec.insert_toc();
+        let once = spec.content().to_string();
+
+        spec.insert_toc();
+        assert_eq!(spec.content(), once);
+
+        spec.set_content(format!("{once}\n## Risks\n"));
+        spec.insert_toc();
+        assert_eq!(spec.content().matches(TOC_START).count(), 1);
+        assert!(
+            spec.content()
+                .contains("- [Goals](#goals)\n- [Risks](#risks)\n<!-- /airsspec:toc -->")
+        );
+    }
+
+    fn spec_updated_at(timestamp: i64) -> Spec {
+        use crate::utils::clock::FixedClock;
+
+        let at = DateTime::from_timestamp(timestamp, 0).unwrap();
+        let metadata = SpecMetadata::new_with_clock("Stale", "Check", &FixedClock::new(at));
+        Spec::new(SpecId::new(timestamp, "stale"), metadata, "")
+    }
+
+    #[test]
+    fn test_recently_updated_active_spec_is_not_stale() {
+        let spec = spec_updated_at(1_000_000);
+
+        assert!(!spec.is_stale(LifecycleState::Active, 1_000_500, 3_600));
+        assert!(!spec.is_stale(LifecycleState::Active, 1_003_600, 3_600));
+    }
+
+    #[test]
+    fn test_old_open_spec_is_stale() {
+        let spec = spec_updated_at(1_000_000);
+
+        assert!(spec.is_stale(LifecycleState::Active, 1_003_601, 3_600));
+        assert!(spec.is_stale(LifecycleState::Draft, 1_003_601, 3_600));
+    }
+
+    #[test]
+    fn test_old_closed_spec_is_never_stale() {
+        let spec = spec_updated_at(1_000_000);
+
+        for lifecycle in [LifecycleState::Done, LifecycleState::Archived] {
+            assert!(!spec.is_stale(lifecycle, 9_000_000, 3_600));
+        }
+    }
 }
@@ -6,6 +6,7 @@
 
 use crate::validation::{ValidatableSpec, ValidationReport};
 
+use super::dependency::DependencyKind;
 use super::types::Spec;
 use super::validator::validate_spec;
 
@@ -21,6 +22,14 @@ impl ValidatableSpec for Spec {
             .collect()
     }
 
+    fn blocker_ids(&self) -> Vec<&str> {
+        self.dependencies()
+            .iter()
+            .filter(|d| d.kind == DependencyKind::BlockedBy)
+            .map(|d| d.spec_id.as_str())
+            .collect()
+    }
+
     fn validate_content(&self) -> ValidationReport {
         validate_spec(self)
     }
@@ -62,6 +71,7 @@ mod tests {
         assert_eq!(deps.len(), 2);
         assert_eq!(deps[0], "1000000-dep-a");
         assert_eq!(deps[1], "1000001-dep-b");
+        assert_eq!(spec.blocker_ids(), ["1000000-dep-a"]);
     }
 
     #[test]
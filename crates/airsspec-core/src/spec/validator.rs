@@ -5,19 +5,186 @@
 
 use std::collections::HashSet;
 
+use crate::shared::LifecycleState;
+use crate::validation::BlockingGraph;
+
 use super::types::Spec;
 
 // Re-export validation types for backward compatibility
 // Also makes them available for use within this module
 pub use crate::validation::{ValidationIssue, ValidationReport, ValidationSeverity};
 
+/// Phrases every spec's content must mention, e.g. "acceptance criteria"
+/// or "rollback plan".
+///
+/// Matching is case-insensitive. An empty list (the default) disables the
+/// check.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::KeywordLintConfig;
+///
+/// let lint = KeywordLintConfig {
+///     required_phrases: vec!["Acceptance criteria".to_string()],
+/// };
+/// assert_eq!(lint.required_phrases.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeywordLintConfig {
+    /// Phrases that must appear somewhere in the spec content.
+    pub required_phrases: Vec<String>,
+}
+
+/// Tunable thresholds for [`validate_spec_with_config`].
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::SpecValidationConfig;
+///
+/// let config = SpecValidationConfig::default().with_max_content_words(500);
+/// assert_eq!(config.max_content_words(), 500);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecValidationConfig {
+    max_content_words: usize,
+    max_dependencies: usize,
+    max_dependencies_hard: usize,
+    keyword_lint: KeywordLintConfig,
+    require_approval: bool,
+    check_placeholders: bool,
+}
+
+impl SpecValidationConfig {
+    /// Default content size limit, in words. Generous on purpose: it should
+    /// only fire for specs that clearly cover several pieces of work.
+    pub const DEFAULT_MAX_CONTENT_WORDS: usize = 10_000;
+
+    /// Default dependency count above which a warning suggests splitting
+    /// the spec.
+    pub const DEFAULT_MAX_DEPENDENCIES: usize = 20;
+
+    /// Default dependency count above which the spec is an error.
+    pub const DEFAULT_MAX_DEPENDENCIES_HARD: usize = 50;
+
+    /// Sets the content size, in words, above which a warning is raised.
+    #[must_use]
+    pub fn with_max_content_words(mut self, max_content_words: usize) -> Self {
+        self.max_content_words = max_content_words;
+        self
+    }
+
+    /// Sets the dependency count above which a warning is raised.
+    #[must_use]
+    pub fn with_max_dependencies(mut self, max_dependencies: usize) -> Self {
+        self.max_dependencies = max_dependencies;
+        self
+    }
+
+    /// Sets the dependency count above which an error is raised instead.
+    #[must_use]
+    pub fn with_max_dependencies_hard(mut self, max_dependencies_hard: usize) -> Self {
+        self.max_dependencies_hard = max_dependencies_hard;
+        self
+    }
+
+    /// Sets the phrases the content must mention.
+    #[must_use]
+    pub fn with_keyword_lint(mut self, keyword_lint: KeywordLintConfig) -> Self {
+        self.keyword_lint = keyword_lint;
+        self
+    }
+
+    /// Sets whether an active spec must have at least one approval (see
+    /// [`validate_spec_in_lifecycle_with_config`]). Off by default.
+    #[must_use]
+    pub fn with_require_approval(mut self, require_approval: bool) -> Self {
+        self.require_approval = require_approval;
+        self
+    }
+
+    /// Sets whether leftover placeholders such as `TBD` are reported (see
+    /// [`Spec::contains_placeholders`]). On by default, which means plain
+    /// [`validate_spec`] reports them too; pass `false` to restore the
+    /// behavior from before the check existed.
+    #[must_use]
+    pub fn with_check_placeholders(mut self, check_placeholders: bool) -> Self {
+        self.check_placeholders = check_placeholders;
+        self
+    }
+
+    /// Returns the content size limit, in words.
+    #[must_use]
+    pub fn max_content_words(&self) -> usize {
+        self.max_content_words
+    }
+
+    /// Returns the dependency count above which a warning is raised.
+    #[must_use]
+    pub fn max_dependencies(&self) -> usize {
+        self.max_dependencies
+    }
+
+    /// Returns the dependency count above which an error is raised.
+    #[must_use]
+    pub fn max_dependencies_hard(&self) -> usize {
+        self.max_dependencies_hard
+    }
+
+    /// Returns the required-phrase configuration.
+    #[must_use]
+    pub fn keyword_lint(&self) -> &KeywordLintConfig {
+        &self.keyword_lint
+    }
+
+    /// Returns whether an active spec must have at least one approval.
+    #[must_use]
+    pub fn require_approval(&self) -> bool {
+        self.require_approval
+    }
+
+    /// Returns whether leftover placeholders are reported.
+    #[must_use]
+    pub fn check_placeholders(&self) -> bool {
+        self.check_placeholders
+    }
+}
+
+impl Default for SpecValidationConfig {
+    fn default() -> Self {
+        Self {
+            max_content_words: Self::DEFAULT_MAX_CONTENT_WORDS,
+            max_dependencies: Self::DEFAULT_MAX_DEPENDENCIES,
+            max_dependencies_hard: Self::DEFAULT_MAX_DEPENDENCIES_HARD,
+            keyword_lint: KeywordLintConfig::default(),
+            require_approval: false,
+            check_placeholders: true,
+        }
+    }
+}
+
 /// Validates a specification and returns a report of any issues.
 ///
+/// Uses the default [`SpecValidationConfig`]; see
+/// [`validate_spec_with_config`] to tune thresholds.
+///
 /// Currently validates:
 /// - Title is not empty
 /// - Title length is reasonable (< 200 chars)
 /// - Description is recommended (warning if empty)
 /// - Content is recommended (warning if empty)
+/// - Content size is reasonable (warning above the word limit)
+/// - Custom field keys are not blank (warning)
+/// - No placeholders such as `TBD` are left in the content (warning, one
+///   per occurrence; see [`Spec::placeholder_lines`]). This check is on by
+///   default, so specs that used to validate cleanly can now get warnings;
+///   disable it with
+///   [`with_check_placeholders(false)`](SpecValidationConfig::with_check_placeholders)
+/// - No two dependencies on the same spec have conflicting kinds (error;
+///   see [`DependencyKind::conflicts_with`](super::DependencyKind::conflicts_with))
+/// - The number of dependencies is manageable (warning above the soft
+///   limit, error above the hard cap)
 ///
 /// # Arguments
 ///
@@ -43,6 +210,42 @@ pub use crate::validation::{ValidationIssue, ValidationReport, ValidationSeverit
 /// ```
 #[must_use]
 pub fn validate_spec(spec: &Spec) -> ValidationReport {
+    validate_spec_with_config(spec, &SpecValidationConfig::default())
+}
+
+/// Validates a specification using the thresholds in `config`.
+///
+/// Runs the same checks as [`validate_spec`], plus a warning for each
+/// required phrase in [`KeywordLintConfig`] the content does not mention.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{SpecBuilder, SpecValidationConfig, validate_spec_with_config};
+///
+/// let spec = SpecBuilder::new()
+///     .title("Big Feature")
+///     .description("Does many things")
+///     .content("one two three four")
+///     .build()
+///     .unwrap();
+///
+/// let config = SpecValidationConfig::default().with_max_content_words(3);
+/// let report = validate_spec_with_config(&spec, &config);
+/// assert_eq!(report.warnings().len(), 1);
+/// ```
+#[must_use]
+pub fn validate_spec_with_config(spec: &Spec, config: &SpecValidationConfig) -> ValidationReport {
+    validate_spec_fields(spec, config, None)
+}
+
+/// Runs the checks shared by every entry point. Placeholders are errors
+/// when `lifecycle` is [`Done`](LifecycleState::Done), warnings otherwise.
+fn validate_spec_fields(
+    spec: &Spec,
+    config: &SpecValidationConfig,
+    lifecycle: Option<LifecycleState>,
+) -> ValidationReport {
     let mut report = ValidationReport::new();
 
     // Validate title
@@ -52,11 +255,130 @@ pub fn validate_spec(spec: &Spec) -> ValidationReport {
     validate_description(spec, &mut report);
 
     // Validate content
-    validate_content(spec, &mut report);
+    validate_content(spec, config, &mut report);
+    validate_required_phrases(spec, config.keyword_lint(), &mut report);
+    if config.check_placeholders() {
+        validate_placeholders(spec, lifecycle, &mut report);
+    }
 
     // Validate dependencies
     validate_dependencies(spec, &mut report);
+    validate_dependency_count(spec, config, &mut report);
+
+    // Validate custom fields
+    validate_custom_fields(spec, &mut report);
+
+    report
+}
+
+/// Validates a specification that is in the given lifecycle state.
+///
+/// Runs the checks of [`validate_spec`], plus a warning when an
+/// [`Active`](LifecycleState::Active) spec has no owner and a warning for
+/// each line of a [`Done`](LifecycleState::Done) spec that still has an
+/// unresolved marker (see [`Spec::unresolved_markers`]). Specs do not store
+/// their lifecycle state, so the caller supplies it (e.g. from the spec's
+/// [`WorkflowState`](crate::state::WorkflowState)).
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::shared::LifecycleState;
+/// use airsspec_core::spec::{SpecBuilder, validate_spec_in_lifecycle};
+///
+/// let spec = SpecBuilder::new()
+///     .title("My Feature")
+///     .description("Does things")
+///     .content("# My Feature")
+///     .build()
+///     .unwrap();
+///
+/// assert!(validate_spec_in_lifecycle(&spec, LifecycleState::Draft).warnings().is_empty());
+/// assert_eq!(validate_spec_in_lifecycle(&spec, LifecycleState::Active).warnings().len(), 1);
+/// ```
+#[must_use]
+pub fn validate_spec_in_lifecycle(spec: &Spec, lifecycle: LifecycleState) -> ValidationReport {
+    validate_spec_in_lifecycle_with_config(spec, lifecycle, &SpecValidationConfig::default())
+}
+
+/// Validates a specification in the given lifecycle state using `config`.
+///
+/// Runs the checks of [`validate_spec_with_config`] and
+/// [`validate_spec_in_lifecycle`]; leftover placeholders are errors rather
+/// than warnings once the spec is [`Done`](LifecycleState::Done). When
+/// [`require_approval`](SpecValidationConfig::require_approval) is set, an
+/// [`Active`](LifecycleState::Active) spec with no
+/// [`approvals`](super::SpecMetadata::approvals) is also an error.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::shared::LifecycleState;
+/// use airsspec_core::spec::{
+///     SpecBuilder, SpecValidationConfig, validate_spec_in_lifecycle_with_config,
+/// };
+///
+/// let spec = SpecBuilder::new()
+///     .title("My Feature")
+///     .description("Does things")
+///     .content("# My Feature")
+///     .owner("alice")
+///     .build()
+///     .unwrap();
+/// let config = SpecValidationConfig::default().with_require_approval(true);
+///
+/// let report = validate_spec_in_lifecycle_with_config(&spec, LifecycleState::Active, &config);
+/// assert_eq!(report.errors().len(), 1);
+/// ```
+#[must_use]
+pub fn validate_spec_in_lifecycle_with_config(
+    spec: &Spec,
+    lifecycle: LifecycleState,
+    config: &SpecValidationConfig,
+) -> ValidationReport {
+    let mut report = validate_spec_fields(spec, config, Some(lifecycle));
+    validate_owner(spec, lifecycle, &mut report);
+    validate_unresolved_markers(spec, lifecycle, &mut report);
+    if config.require_approval() {
+        validate_approvals(spec, lifecycle, &mut report);
+    }
+    report
+}
 
+/// Validates the `BlockedBy` dependencies across `specs`, reporting an
+/// error for each cycle.
+///
+/// [`validate_spec`] only sees one spec at a time, so it can catch a spec
+/// blocked by itself but not `a -> b -> a`. Each cycle's message lists its
+/// members, starting from the lowest ID, e.g.
+/// `Circular dependency: 1-a -> 2-b -> 1-a`. `RelatedTo` dependencies,
+/// self-references, and dependencies on specs outside `specs` are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{Dependency, Spec, SpecId, SpecMetadata, validate_dependency_graph};
+///
+/// let a = SpecId::new(1, "a");
+/// let b = SpecId::new(2, "b");
+/// let mut a_meta = SpecMetadata::new("A", "");
+/// a_meta.add_dependency(Dependency::blocked_by(b.clone()));
+/// let mut b_meta = SpecMetadata::new("B", "");
+/// b_meta.add_dependency(Dependency::blocked_by(a.clone()));
+///
+/// let report = validate_dependency_graph(&[
+///     Spec::new(a, a_meta, ""),
+///     Spec::new(b, b_meta, ""),
+/// ]);
+/// assert_eq!(report.errors()[0].message(), "Circular dependency: 1-a -> 2-b -> 1-a");
+/// ```
+#[must_use]
+pub fn validate_dependency_graph(specs: &[Spec]) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    for cycle in BlockingGraph::new(specs).cycles() {
+        let message = format!("Circular dependency: {}", cycle.join(" -> "));
+        report.add_issue(ValidationIssue::error(message).with_field("metadata.dependencies"));
+    }
     report
 }
 
@@ -106,7 +428,7 @@ fn validate_description(spec: &Spec, report: &mut ValidationReport) {
 }
 
 /// Validates the spec content.
-fn validate_content(spec: &Spec, report: &mut ValidationReport) {
+fn validate_content(spec: &Spec, config: &SpecValidationConfig, report: &mut ValidationReport) {
     let content = spec.content();
 
     if content.is_empty() {
@@ -114,6 +436,111 @@ fn validate_content(spec: &Spec, report: &mut ValidationReport) {
             ValidationIssue::warning("Content is empty, consider adding documentation")
                 .with_field("content"),
         );
+        return;
+    }
+
+    let words = content.split_whitespace().count();
+    if words > config.max_content_words() {
+        report.add_issue(
+            ValidationIssue::warning(format!(
+                "Content is very long ({words} words, limit {}), consider splitting into smaller specs",
+                config.max_content_words()
+            ))
+            .with_field("content"),
+        );
+    }
+}
+
+/// Warns for each required phrase missing from the spec content.
+fn validate_required_phrases(spec: &Spec, lint: &KeywordLintConfig, report: &mut ValidationReport) {
+    if lint.required_phrases.is_empty() {
+        return;
+    }
+
+    let content = spec.content().to_lowercase();
+    for phrase in &lint.required_phrases {
+        let phrase = phrase.trim();
+        if !phrase.is_empty() && !content.contains(&phrase.to_lowercase()) {
+            report.add_issue(
+                ValidationIssue::warning(format!(
+                    "Content does not mention required phrase '{phrase}'"
+                ))
+                .with_field("content"),
+            );
+        }
+    }
+}
+
+/// Warns when an active spec has nobody accountable for it.
+fn validate_owner(spec: &Spec, lifecycle: LifecycleState, report: &mut ValidationReport) {
+    let unowned = spec.owner().is_none_or(|owner| owner.trim().is_empty());
+    if lifecycle == LifecycleState::Active && unowned {
+        report.add_issue(
+            ValidationIssue::warning("Active spec has no owner, consider assigning one")
+                .with_field("metadata.owner"),
+        );
+    }
+}
+
+/// Validates that no placeholder text is left in the content.
+fn validate_placeholders(
+    spec: &Spec,
+    lifecycle: Option<LifecycleState>,
+    report: &mut ValidationReport,
+) {
+    let severity = if lifecycle == Some(LifecycleState::Done) {
+        ValidationSeverity::Error
+    } else {
+        ValidationSeverity::Warning
+    };
+    for (line, marker) in spec.placeholder_lines() {
+        report.add_issue(
+            ValidationIssue::new(
+                severity,
+                format!("Content contains placeholder \"{marker}\" on line {line}"),
+            )
+            .with_field("content"),
+        );
+    }
+}
+
+/// Warns for each line of a done spec that still has a `TODO`-style marker.
+fn validate_unresolved_markers(
+    spec: &Spec,
+    lifecycle: LifecycleState,
+    report: &mut ValidationReport,
+) {
+    if lifecycle != LifecycleState::Done {
+        return;
+    }
+    for (line, text) in spec.unresolved_markers() {
+        report.add_issue(
+            ValidationIssue::warning(format!(
+                "Done spec has an unresolved marker on line {line}: {text}"
+            ))
+            .with_field("content"),
+        );
+    }
+}
+
+/// Validates that an active spec has been signed off.
+fn validate_approvals(spec: &Spec, lifecycle: LifecycleState, report: &mut ValidationReport) {
+    if lifecycle == LifecycleState::Active && spec.metadata().approvals().is_empty() {
+        report.add_issue(
+            ValidationIssue::error("Active spec has no approvals").with_field("metadata.approvals"),
+        );
+    }
+}
+
+/// Validates custom metadata fields.
+fn validate_custom_fields(spec: &Spec, report: &mut ValidationReport) {
+    for key in spec.metadata().custom().keys() {
+        if key.trim().is_empty() {
+            report.add_issue(
+                ValidationIssue::warning("Custom field has an empty key")
+                    .with_field("metadata.custom"),
+            );
+        }
     }
 }
 
@@ -133,6 +560,22 @@ fn validate_dependencies(spec: &Spec, report: &mut ValidationReport) {
         }
     }
 
+    // Check for contradictory kinds on the same target
+    for (idx, dep) in dependencies.iter().enumerate() {
+        if let Some(earlier) = dependencies[..idx]
+            .iter()
+            .find(|other| other.spec_id == dep.spec_id && other.kind.conflicts_with(dep.kind))
+        {
+            report.add_issue(
+                ValidationIssue::error(format!(
+                    "Dependency on {} cannot be both {} and {}",
+                    dep.spec_id, earlier.kind, dep.kind
+                ))
+                .with_field(format!("metadata.dependencies[{idx}]")),
+            );
+        }
+    }
+
     // Check for self-reference
     let self_id = spec.id().as_str();
     if dependencies.iter().any(|d| d.spec_id.as_str() == self_id) {
@@ -143,10 +586,36 @@ fn validate_dependencies(spec: &Spec, report: &mut ValidationReport) {
     }
 }
 
+/// Warns when a spec has many dependencies and errors above the hard cap.
+fn validate_dependency_count(
+    spec: &Spec,
+    config: &SpecValidationConfig,
+    report: &mut ValidationReport,
+) {
+    let count = spec.dependencies().len();
+    if count > config.max_dependencies_hard() {
+        report.add_issue(
+            ValidationIssue::error(format!(
+                "Too many dependencies ({count}, maximum {}), split the spec into smaller specs",
+                config.max_dependencies_hard()
+            ))
+            .with_field("metadata.dependencies"),
+        );
+    } else if count > config.max_dependencies() {
+        report.add_issue(
+            ValidationIssue::warning(format!(
+                "Many dependencies ({count}, limit {}), consider splitting into smaller specs",
+                config.max_dependencies()
+            ))
+            .with_field("metadata.dependencies"),
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::spec::{Dependency, SpecBuilder, SpecId, SpecMetadata};
+    use crate::spec::{Approval, Dependency, DependencyKind, SpecBuilder, SpecId, SpecMetadata};
 
     #[test]
     fn test_validation_severity_display() {
@@ -273,6 +742,76 @@ mod tests {
         assert!(report.warnings()[0].message().contains("Content is empty"));
     }
 
+    #[test]
+    fn test_validate_spec_content_over_limit_warns() {
+        let spec = Spec::new(
+            SpecId::new(1_737_734_400, "big"),
+            SpecMetadata::new("Big", "A large spec"),
+            "word ".repeat(11),
+        );
+        let config = SpecValidationConfig::default().with_max_content_words(10);
+
+        let report = validate_spec_with_config(&spec, &config);
+
+        assert!(report.is_valid());
+        assert_eq!(report.warnings().len(), 1);
+        let warning = report.warnings()[0];
+        assert_eq!(warning.field(), Some("content"));
+        assert!(warning.message().contains("11 words"));
+        assert!(warning.message().contains("splitting"));
+    }
+
+    #[test]
+    fn test_validate_spec_content_under_limit_is_clean() {
+        let spec = Spec::new(
+            SpecId::new(1_737_734_400, "small"),
+            SpecMetadata::new("Small", "A small spec"),
+            "word ".repeat(10),
+        );
+        let config = SpecValidationConfig::default().with_max_content_words(10);
+
+        assert!(validate_spec_with_config(&spec, &config).is_empty());
+        assert!(validate_spec(&spec).is_empty());
+    }
+
+    fn lint(phrases: &[&str]) -> SpecValidationConfig {
+        SpecValidationConfig::default().with_keyword_lint(KeywordLintConfig {
+            required_phrases: phrases.iter().map(ToString::to_string).collect(),
+        })
+    }
+
+    #[test]
+    fn test_validate_spec_missing_required_phrase_warns() {
+        let spec = Spec::new(
+            SpecId::new(1_737_734_400, "lint"),
+            SpecMetadata::new("Lint", "Keyword lint"),
+            "## Acceptance Criteria\n\n- Users can log in",
+        );
+
+        let report =
+            validate_spec_with_config(&spec, &lint(&["acceptance criteria", "Rollback plan"]));
+
+        assert!(report.is_valid());
+        assert_eq!(report.warnings().len(), 1);
+        let warning = report.warnings()[0];
+        assert_eq!(warning.field(), Some("content"));
+        assert!(warning.message().contains("'Rollback plan'"));
+    }
+
+    #[test]
+    fn test_validate_spec_all_required_phrases_is_clean() {
+        let spec = Spec::new(
+            SpecId::new(1_737_734_400, "lint"),
+            SpecMetadata::new("Lint", "Keyword lint"),
+            "ACCEPTANCE CRITERIA: works.\nRollback Plan: revert the deploy.",
+        );
+
+        let config = lint(&["acceptance criteria", "rollback plan"]);
+
+        assert!(validate_spec_with_config(&spec, &config).is_empty());
+        assert!(validate_spec_with_config(&spec, &lint(&[])).is_empty());
+    }
+
     #[test]
     fn test_validate_spec_long_title() {
         let long_title = "A".repeat(MAX_TITLE_LENGTH + 50);
@@ -347,6 +886,337 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_spec_conflicting_dependency_kinds() {
+        let dep_id = SpecId::new(1_737_734_400, "dep-spec");
+        let id = SpecId::new(1_737_734_401, "main-spec");
+        let mut metadata = SpecMetadata::new("Conflicting Deps", "Description");
+        metadata.add_dependency(Dependency::new(dep_id.clone(), DependencyKind::ChildOf));
+        metadata.add_dependency(Dependency::new(dep_id, DependencyKind::ParentOf));
+        let spec = Spec::new(id, metadata, "Content");
+
+        let report = validate_spec(&spec);
+
+        assert!(!report.is_valid());
+        assert_eq!(report.errors().len(), 1);
+        assert_eq!(
+            report.errors()[0].message(),
+            "Dependency on 1737734400-dep-spec cannot be both child_of and parent_of"
+        );
+        assert_eq!(report.errors()[0].field(), Some("metadata.dependencies[1]"));
+    }
+
+    /// Builds a spec blocked by `count` distinct specs.
+    fn spec_with_dependencies(count: i64) -> Spec {
+        let mut metadata = SpecMetadata::new("Many Deps", "Description");
+        for n in 0..count {
+            metadata.add_dependency(Dependency::blocked_by(SpecId::new(n + 1, "dep")));
+        }
+        Spec::new(SpecId::new(1_737_734_400, "main"), metadata, "Content")
+    }
+
+    #[test]
+    fn test_validate_spec_dependency_count_within_limit() {
+        let config = SpecValidationConfig::default()
+            .with_max_dependencies(3)
+            .with_max_dependencies_hard(5);
+
+        let report = validate_spec_with_config(&spec_with_dependencies(3), &config);
+
+        assert!(report.is_valid());
+        assert!(report.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_validate_spec_dependency_count_over_warning_threshold() {
+        let config = SpecValidationConfig::default()
+            .with_max_dependencies(3)
+            .with_max_dependencies_hard(5);
+
+        let report = validate_spec_with_config(&spec_with_dependencies(4), &config);
+
+        assert!(report.is_valid());
+        assert_eq!(report.warnings().len(), 1);
+        assert_eq!(
+            report.warnings()[0].message(),
+            "Many dependencies (4, limit 3), consider splitting into smaller specs"
+        );
+        assert_eq!(report.warnings()[0].field(), Some("metadata.dependencies"));
+    }
+
+    #[test]
+    fn test_validate_spec_dependency_count_over_hard_cap() {
+        let config = SpecValidationConfig::default()
+            .with_max_dependencies(3)
+            .with_max_dependencies_hard(5);
+
+        let report = validate_spec_with_config(&spec_with_dependencies(6), &config);
+
+        assert!(report.warnings().is_empty());
+        assert_eq!(report.errors().len(), 1);
+        assert_eq!(
+            report.errors()[0].message(),
+            "Too many dependencies (6, maximum 5), split the spec into smaller specs"
+        );
+    }
+
+    #[test]
+    fn test_validate_spec_default_dependency_limits_are_generous() {
+        let report = validate_spec(&spec_with_dependencies(20));
+        assert!(report.warnings().is_empty());
+
+        let report = validate_spec(&spec_with_dependencies(21));
+        assert_eq!(report.warnings().len(), 1);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn test_validate_spec_compatible_dependency_kinds() {
+        let dep_id = SpecId::new(1_737_734_400, "dep-spec");
+        let id = SpecId::new(1_737_734_401, "main-spec");
+        let mut metadata = SpecMetadata::new("Compatible Deps", "Description");
+        metadata.add_dependency(Dependency::new(dep_id.clone(), DependencyKind::ChildOf));
+        metadata.add_dependency(Dependency::related_to(dep_id));
+        let spec = Spec::new(id, metadata, "Content");
+
+        let report = validate_spec(&spec);
+
+        assert!(report.errors().is_empty());
+    }
+
+    #[test]
+    fn test_validate_spec_empty_custom_key_warns() {
+        let id = SpecId::new(1_737_734_400, "custom-keys");
+        let mut metadata = SpecMetadata::new("Custom Keys", "Description");
+        metadata.set_custom("owner", "alice");
+        metadata.set_custom("  ", "orphan value");
+        let spec = Spec::new(id, metadata, "Content");
+
+        let report = validate_spec(&spec);
+        assert!(report.is_valid()); // Warning only
+        assert_eq!(report.warning_count(), 1);
+        assert_eq!(report.warnings()[0].field(), Some("metadata.custom"));
+    }
+
+    fn complete_spec() -> Spec {
+        SpecBuilder::new()
+            .title("Owned Feature")
+            .description("A feature")
+            .content("# Owned Feature")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_validate_spec_active_without_owner_warns() {
+        let report = validate_spec_in_lifecycle(&complete_spec(), LifecycleState::Active);
+
+        assert!(report.is_valid());
+        assert_eq!(report.warnings().len(), 1);
+        assert_eq!(report.warnings()[0].field(), Some("metadata.owner"));
+    }
+
+    #[test]
+    fn test_validate_spec_active_with_owner_is_clean() {
+        let mut spec = complete_spec();
+        spec.metadata_mut().set_owner("platform-team");
+
+        let report = validate_spec_in_lifecycle(&spec, LifecycleState::Active);
+
+        assert!(report.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_validate_spec_draft_without_owner_is_clean() {
+        let report = validate_spec_in_lifecycle(&complete_spec(), LifecycleState::Draft);
+
+        assert!(report.warnings().is_empty());
+    }
+
+    fn approval_config() -> SpecValidationConfig {
+        SpecValidationConfig::default().with_require_approval(true)
+    }
+
+    #[test]
+    fn test_validate_spec_active_without_approval_errors() {
+        let mut spec = complete_spec();
+        spec.metadata_mut().set_owner("platform-team");
+
+        let report = validate_spec_in_lifecycle_with_config(
+            &spec,
+            LifecycleState::Active,
+            &approval_config(),
+        );
+
+        assert!(!report.is_valid());
+        assert_eq!(report.errors().len(), 1);
+        assert_eq!(report.errors()[0].field(), Some("metadata.approvals"));
+    }
+
+    #[test]
+    fn test_validate_spec_active_with_approval_is_clean() {
+        let mut spec = complete_spec();
+        spec.metadata_mut().set_owner("platform-team");
+        let approved_at = spec.metadata().created_at();
+        spec.metadata_mut()
+            .add_approval(Approval::new("alice", approved_at));
+
+        let report = validate_spec_in_lifecycle_with_config(
+            &spec,
+            LifecycleState::Active,
+            &approval_config(),
+        );
+
+        assert!(report.is_empty(), "got: {:?}", report.issues());
+    }
+
+    #[test]
+    fn test_validate_spec_approval_not_required_by_default() {
+        let mut spec = complete_spec();
+        spec.metadata_mut().set_owner("platform-team");
+
+        let report = validate_spec_in_lifecycle(&spec, LifecycleState::Active);
+
+        assert!(report.is_empty(), "got: {:?}", report.issues());
+    }
+
+    #[test]
+    fn test_validate_spec_draft_without_approval_is_clean() {
+        let report = validate_spec_in_lifecycle_with_config(
+            &complete_spec(),
+            LifecycleState::Draft,
+            &approval_config(),
+        );
+
+        assert!(report.is_valid());
+    }
+
+    fn placeholder_spec() -> Spec {
+        let mut spec = complete_spec();
+        spec.set_content("# Feature\n\nRollout: TBD");
+        spec
+    }
+
+    #[test]
+    fn test_validate_spec_placeholder_warns() {
+        let report = validate_spec(&placeholder_spec());
+
+        assert!(report.is_valid());
+        assert_eq!(report.warnings().len(), 1);
+        assert!(report.warnings()[0].message().contains("\"TBD\""));
+    }
+
+    #[test]
+    fn test_validate_spec_placeholder_errors_when_done() {
+        let report = validate_spec_in_lifecycle(&placeholder_spec(), LifecycleState::Done);
+
+        assert_eq!(report.errors().len(), 1);
+        assert_eq!(report.errors()[0].field(), Some("content"));
+    }
+
+    #[test]
+    fn test_validate_spec_unresolved_markers_warn_when_done() {
+        let mut spec = complete_spec();
+        spec.set_content("# Feature\n\nTODO: pick a queue\nFIXME retry policy");
+
+        let report = validate_spec_in_lifecycle(&spec, LifecycleState::Done);
+
+        assert!(report.is_valid());
+        let messages: Vec<&str> = report
+            .warnings()
+            .iter()
+            .map(|issue| issue.message())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "Done spec has an unresolved marker on line 3: TODO: pick a queue",
+                "Done spec has an unresolved marker on line 4: FIXME retry policy",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_spec_unresolved_markers_ignored_before_done() {
+        let mut spec = complete_spec();
+        spec.set_content("# Feature\n\nTODO: pick a queue");
+
+        let report = validate_spec_in_lifecycle(&spec, LifecycleState::Draft);
+
+        assert!(report.is_empty(), "got: {:?}", report.issues());
+    }
+
+    #[test]
+    fn test_validate_spec_placeholder_check_can_be_disabled() {
+        let config = SpecValidationConfig::default().with_check_placeholders(false);
+
+        let report = validate_spec_with_config(&placeholder_spec(), &config);
+
+        assert!(report.is_empty(), "got: {:?}", report.issues());
+    }
+
+    /// Builds a spec blocked by each of `blockers`.
+    fn spec_blocked_by(id: &SpecId, blockers: &[&SpecId]) -> Spec {
+        let mut metadata = SpecMetadata::new("Graph", "Dependency graph");
+        for &blocker in blockers {
+            metadata.add_dependency(Dependency::blocked_by(blocker.clone()));
+        }
+        Spec::new(id.clone(), metadata, "")
+    }
+
+    #[test]
+    fn test_dependency_graph_two_cycle() {
+        let a = SpecId::new(1, "a");
+        let b = SpecId::new(2, "b");
+        let specs = [spec_blocked_by(&b, &[&a]), spec_blocked_by(&a, &[&b])];
+
+        let report = validate_dependency_graph(&specs);
+
+        let messages: Vec<&str> = report.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, ["Circular dependency: 1-a -> 2-b -> 1-a"]);
+        assert_eq!(report.errors()[0].field(), Some("metadata.dependencies"));
+    }
+
+    #[test]
+    fn test_dependency_graph_three_cycle() {
+        let a = SpecId::new(1, "a");
+        let b = SpecId::new(2, "b");
+        let c = SpecId::new(3, "c");
+        let d = SpecId::new(4, "d");
+        let specs = [
+            spec_blocked_by(&a, &[&c]),
+            spec_blocked_by(&b, &[&a]),
+            spec_blocked_by(&c, &[&b]),
+            spec_blocked_by(&d, &[&a]),
+        ];
+
+        let report = validate_dependency_graph(&specs);
+
+        let messages: Vec<&str> = report.errors().iter().map(|e| e.message()).collect();
+        assert_eq!(messages, ["Circular dependency: 1-a -> 3-c -> 2-b -> 1-a"]);
+    }
+
+    #[test]
+    fn test_dependency_graph_dag_passes() {
+        let a = SpecId::new(1, "a");
+        let b = SpecId::new(2, "b");
+        let c = SpecId::new(3, "c");
+        let missing = SpecId::new(9, "missing");
+        let mut related = spec_blocked_by(&a, &[]);
+        related
+            .metadata_mut()
+            .add_dependency(Dependency::related_to(c.clone()));
+        let specs = [
+            related,
+            spec_blocked_by(&b, &[&a, &missing]),
+            spec_blocked_by(&c, &[&a, &b, &c]),
+        ];
+
+        let report = validate_dependency_graph(&specs);
+
+        assert!(report.is_empty(), "{:?}", report.issues());
+    }
+
     #[test]
     fn test_validation_report_errors_and_warnings_accessors() {
         let mut report = ValidationReport::new();
@@ -68,6 +68,62 @@ impl StateMachine {
         }
     }
 
+    /// Returns the shortest sequence of states leading from `from` to `to`.
+    ///
+    /// The path includes both endpoints, so each adjacent pair is a valid
+    /// transition and `path.len() - 1` is the number of steps remaining.
+    /// A state's path to itself is just that state. Returns `None` when
+    /// `to` cannot be reached, e.g. from a terminal state like `Archived`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::state::StateMachine;
+    /// use airsspec_core::shared::LifecycleState;
+    ///
+    /// let machine = StateMachine::new();
+    /// let path = machine
+    ///     .transition_path(LifecycleState::Draft, LifecycleState::Done)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     path,
+    ///     [LifecycleState::Draft, LifecycleState::Active, LifecycleState::Done]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn transition_path(
+        &self,
+        from: LifecycleState,
+        to: LifecycleState,
+    ) -> Option<Vec<LifecycleState>> {
+        // Breadth-first search; each entry records the index of the entry it
+        // was reached from, so the path can be rebuilt backwards.
+        let mut visited: Vec<(LifecycleState, Option<usize>)> = vec![(from, None)];
+        let mut next = 0;
+
+        while next < visited.len() {
+            let (state, _) = visited[next];
+            if state == to {
+                let mut path = Vec::new();
+                let mut at = Some(next);
+                while let Some(index) = at {
+                    path.push(visited[index].0);
+                    at = visited[index].1;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for target in self.valid_transitions(state) {
+                if !visited.iter().any(|(seen, _)| *seen == target) {
+                    visited.push((target, Some(next)));
+                }
+            }
+            next += 1;
+        }
+
+        None
+    }
+
     /// Attempts a state transition, returning the new state or an error.
     ///
     /// # Errors
@@ -174,6 +230,57 @@ mod tests {
         assert!(!machine.can_transition(LifecycleState::Archived, LifecycleState::Archived));
     }
 
+    #[test]
+    fn test_transition_path_draft_to_archived() {
+        let machine = StateMachine::new();
+        let path = machine.transition_path(LifecycleState::Draft, LifecycleState::Archived);
+        assert_eq!(
+            path,
+            Some(vec![
+                LifecycleState::Draft,
+                LifecycleState::Active,
+                LifecycleState::Done,
+                LifecycleState::Archived,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_transition_path_blocked_to_done() {
+        let machine = StateMachine::new();
+        let path = machine.transition_path(LifecycleState::Blocked, LifecycleState::Done);
+        assert_eq!(
+            path,
+            Some(vec![
+                LifecycleState::Blocked,
+                LifecycleState::Active,
+                LifecycleState::Done,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_transition_path_unreachable() {
+        let machine = StateMachine::new();
+        assert_eq!(
+            machine.transition_path(LifecycleState::Done, LifecycleState::Active),
+            None
+        );
+        assert_eq!(
+            machine.transition_path(LifecycleState::Archived, LifecycleState::Draft),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transition_path_to_same_state() {
+        let machine = StateMachine::new();
+        assert_eq!(
+            machine.transition_path(LifecycleState::Active, LifecycleState::Active),
+            Some(vec![LifecycleState::Active])
+        );
+    }
+
     #[test]
     fn test_valid_transitions_list() {
         let machine = StateMachine::new();
@@ -4,7 +4,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::shared::{LifecycleState, Phase};
-use crate::spec::SpecId;
+use crate::spec::{Category, SpecId};
+use crate::utils::clock::{Clock, SystemClock};
 
 use super::progress::BuildProgress;
 
@@ -44,12 +45,52 @@ impl WorkflowState {
     /// Creates initial workflow state for a new spec.
     #[must_use]
     pub fn new(spec_id: SpecId) -> Self {
+        Self::new_with_clock(spec_id, &SystemClock)
+    }
+
+    /// Creates initial workflow state, reading the timestamp from `clock`.
+    #[must_use]
+    pub fn new_with_clock(spec_id: SpecId, clock: &impl Clock) -> Self {
         Self {
             spec_id,
             lifecycle: LifecycleState::default(),
             phase: Phase::default(),
             build_progress: BuildProgress::default(),
-            updated_at: Utc::now(),
+            updated_at: clock.now(),
+        }
+    }
+
+    /// Creates initial workflow state for a new spec of `category`, starting
+    /// in [`Category::suggested_starting_phase`] instead of the first phase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::shared::Phase;
+    /// use airsspec_core::spec::{Category, SpecId};
+    /// use airsspec_core::state::WorkflowState;
+    ///
+    /// let spec_id = SpecId::new(1737734400, "login-crash");
+    /// let state = WorkflowState::for_category(spec_id, Category::BugFix);
+    ///
+    /// assert_eq!(state.phase(), Phase::Plan);
+    /// ```
+    #[must_use]
+    pub fn for_category(spec_id: SpecId, category: Category) -> Self {
+        Self::for_category_with_clock(spec_id, category, &SystemClock)
+    }
+
+    /// Creates initial workflow state for a new spec of `category`, reading
+    /// the timestamp from `clock`.
+    #[must_use]
+    pub fn for_category_with_clock(
+        spec_id: SpecId,
+        category: Category,
+        clock: &impl Clock,
+    ) -> Self {
+        Self {
+            phase: category.suggested_starting_phase(),
+            ..Self::new_with_clock(spec_id, clock)
         }
     }
 
@@ -85,29 +126,52 @@ impl WorkflowState {
 
     /// Sets the lifecycle state and updates the timestamp.
     pub fn set_lifecycle(&mut self, lifecycle: LifecycleState) {
+        self.set_lifecycle_with_clock(lifecycle, &SystemClock);
+    }
+
+    /// Sets the lifecycle state, reading the new timestamp from `clock`.
+    pub fn set_lifecycle_with_clock(&mut self, lifecycle: LifecycleState, clock: &impl Clock) {
         self.lifecycle = lifecycle;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
 
     /// Sets the workflow phase and updates the timestamp.
     pub fn set_phase(&mut self, phase: Phase) {
+        self.set_phase_with_clock(phase, &SystemClock);
+    }
+
+    /// Sets the workflow phase, reading the new timestamp from `clock`.
+    pub fn set_phase_with_clock(&mut self, phase: Phase, clock: &impl Clock) {
         self.phase = phase;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
 
     /// Sets the build progress and updates the timestamp.
     pub fn set_build_progress(&mut self, progress: BuildProgress) {
+        self.set_build_progress_with_clock(progress, &SystemClock);
+    }
+
+    /// Sets the build progress, reading the new timestamp from `clock`.
+    pub fn set_build_progress_with_clock(&mut self, progress: BuildProgress, clock: &impl Clock) {
         self.build_progress = progress;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
 
     /// Advances to the next phase if possible.
     ///
     /// Returns `true` if advanced, `false` if already at the Build phase.
     pub fn advance_phase(&mut self) -> bool {
+        self.advance_phase_with_clock(&SystemClock)
+    }
+
+    /// Advances to the next phase, reading the new timestamp from `clock`.
+    ///
+    /// Returns `true` if advanced, `false` if already at the Build phase.
+    /// The timestamp is left unchanged when the phase does not advance.
+    pub fn advance_phase_with_clock(&mut self, clock: &impl Clock) -> bool {
         if let Some(next) = self.phase.next() {
             self.phase = next;
-            self.updated_at = Utc::now();
+            self.updated_at = clock.now();
             true
         } else {
             false
@@ -134,6 +198,14 @@ mod tests {
         assert_eq!(state.build_progress().total_steps(), 0);
     }
 
+    #[test]
+    fn test_for_category() {
+        let state = WorkflowState::for_category(test_spec_id(), Category::Documentation);
+
+        assert_eq!(state.lifecycle(), LifecycleState::Draft);
+        assert_eq!(state.phase(), Phase::Build);
+    }
+
     #[test]
     fn test_set_lifecycle() {
         let spec_id = test_spec_id();
@@ -213,4 +285,53 @@ mod tests {
             state.build_progress().total_steps()
         );
     }
+
+    #[test]
+    fn test_transitions_with_fixed_clock() {
+        use crate::utils::clock::FixedClock;
+
+        let created = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+        let later = DateTime::from_timestamp(1_737_738_000, 0).unwrap();
+
+        let mut state = WorkflowState::new_with_clock(test_spec_id(), &FixedClock::new(created));
+        assert_eq!(state.updated_at(), created);
+
+        state.set_lifecycle_with_clock(LifecycleState::Active, &FixedClock::new(later));
+        assert_eq!(state.lifecycle(), LifecycleState::Active);
+        assert_eq!(state.updated_at(), later);
+    }
+
+    #[test]
+    fn test_advance_phase_with_fixed_clock() {
+        use crate::utils::clock::FixedClock;
+
+        let created = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+        let later = DateTime::from_timestamp(1_737_738_000, 0).unwrap();
+        let mut state = WorkflowState::new_with_clock(test_spec_id(), &FixedClock::new(created));
+        state.set_phase_with_clock(Phase::Build, &FixedClock::new(created));
+
+        // No further phase: timestamp is left alone
+        assert!(!state.advance_phase_with_clock(&FixedClock::new(later)));
+        assert_eq!(state.updated_at(), created);
+
+        state.set_phase_with_clock(Phase::Spec, &FixedClock::new(created));
+        assert!(state.advance_phase_with_clock(&FixedClock::new(later)));
+        assert_eq!(state.phase(), Phase::Plan);
+        assert_eq!(state.updated_at(), later);
+    }
+
+    #[test]
+    fn test_for_category_with_fixed_clock() {
+        use crate::utils::clock::FixedClock;
+
+        let created = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+        let state = WorkflowState::for_category_with_clock(
+            test_spec_id(),
+            Category::BugFix,
+            &FixedClock::new(created),
+        );
+
+        assert_eq!(state.phase(), Phase::Plan);
+        assert_eq!(state.updated_at(), created);
+    }
 }
@@ -0,0 +1,26 @@
+//! Stable, non-cryptographic checksums.
+//!
+//! Checksums are 64-bit FNV-1a hashes rendered as 16 lowercase hex digits.
+//! They are stable across runs and platforms, so they can be stored and
+//! compared later to detect edits.
+
+/// Returns the FNV-1a checksum of `bytes` as 16 lowercase hex digits.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::utils::checksum;
+///
+/// assert_eq!(checksum::fnv1a_hex(b""), "cbf29ce484222325");
+/// assert_ne!(checksum::fnv1a_hex(b"a"), checksum::fnv1a_hex(b"b"));
+/// ```
+#[must_use]
+pub fn fnv1a_hex(bytes: &[u8]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let hash = bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    });
+    format!("{hash:016x}")
+}
@@ -0,0 +1,85 @@
+//! Clock abstraction for timestamp generation.
+//!
+//! Domain types stamp creation and update times. Reading the time through
+//! the [`Clock`] trait instead of calling `Utc::now()` directly lets tests
+//! inject a [`FixedClock`] and assert on exact timestamps.
+//!
+//! Per project guidelines, callers take `&impl Clock` (static dispatch)
+//! rather than a `dyn Clock`.
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current time.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::utils::clock::{Clock, FixedClock};
+/// use chrono::DateTime;
+///
+/// let at = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+/// let clock = FixedClock::new(at);
+/// assert_eq!(clock.now(), at);
+/// ```
+pub trait Clock {
+    /// Returns the current time according to this clock.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The real wall clock, backed by `Utc::now()`.
+///
+/// This is the clock used by every convenience method that does not take
+/// an explicit clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock frozen at a single instant.
+///
+/// Intended for tests that need deterministic timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    at: DateTime<Utc>,
+}
+
+impl FixedClock {
+    /// Creates a clock that always reports `at`.
+    #[must_use]
+    pub fn new(at: DateTime<Utc>) -> Self {
+        Self { at }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock_is_frozen() {
+        let at = DateTime::from_timestamp(1_737_734_400, 0).unwrap();
+        let clock = FixedClock::new(at);
+
+        assert_eq!(clock.now(), at);
+        assert_eq!(clock.now(), at);
+    }
+
+    #[test]
+    fn test_system_clock_tracks_wall_time() {
+        let before = Utc::now();
+        let now = SystemClock.now();
+        let after = Utc::now();
+
+        assert!(before <= now && now <= after);
+    }
+}
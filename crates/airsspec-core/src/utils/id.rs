@@ -2,10 +2,9 @@
 //!
 //! Provides helpers for generating spec IDs following ADR-003 format.
 
-use chrono::Utc;
-
 use crate::spec::SpecId;
 
+use super::clock::{Clock, SystemClock};
 use super::slug;
 
 /// Default slug used when the title produces an empty slug.
@@ -40,9 +39,25 @@ fn ensure_valid_slug(slug: String) -> String {
 /// ```
 #[must_use]
 pub fn generate_spec_id(title: &str) -> SpecId {
-    let timestamp = Utc::now().timestamp();
-    let slug_str = ensure_valid_slug(slug::generate_default(title));
-    SpecId::new(timestamp, &slug_str)
+    generate_spec_id_with_clock(title, &SystemClock)
+}
+
+/// Generates a [`SpecId`] from a title using the time reported by `clock`.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::utils::clock::FixedClock;
+/// use airsspec_core::utils::id;
+/// use chrono::DateTime;
+///
+/// let clock = FixedClock::new(DateTime::from_timestamp(1_737_734_400, 0).unwrap());
+/// let spec_id = id::generate_spec_id_with_clock("Test Feature", &clock);
+/// assert_eq!(spec_id.as_str(), "1737734400-test-feature");
+/// ```
+#[must_use]
+pub fn generate_spec_id_with_clock(title: &str, clock: &impl Clock) -> SpecId {
+    generate_spec_id_with_timestamp(clock.now().timestamp(), title)
 }
 
 /// Generates a [`SpecId`] with a specific timestamp (useful for testing).
@@ -69,6 +84,8 @@ pub fn generate_spec_id_with_timestamp(timestamp: i64, title: &str) -> SpecId {
 
 #[cfg(test)]
 mod tests {
+    use chrono::Utc;
+
     use super::*;
 
     #[test]
@@ -146,4 +163,13 @@ mod tests {
         assert_eq!(id1.slug(), id2.slug());
         assert_ne!(id1.timestamp(), id2.timestamp());
     }
+
+    #[test]
+    fn test_generate_spec_id_with_fixed_clock() {
+        use crate::utils::clock::FixedClock;
+
+        let clock = FixedClock::new(chrono::DateTime::from_timestamp(1_737_734_400, 0).unwrap());
+        let id = generate_spec_id_with_clock("User Authentication", &clock);
+        assert_eq!(id.as_str(), "1737734400-user-authentication");
+    }
 }
@@ -6,6 +6,8 @@
 //!
 //! - [`slug`] - URL-safe slug generation (ADR-003 compliant)
 //! - [`id`] - Spec ID generation helpers
+//! - [`clock`] - Injectable time source (`Clock`, `SystemClock`, `FixedClock`)
+//! - [`checksum`] - Stable checksums for detecting edits
 //!
 //! ## Example
 //!
@@ -21,5 +23,7 @@
 //! assert!(!spec_id.slug().is_empty());
 //! ```
 
+pub mod checksum;
+pub mod clock;
 pub mod id;
 pub mod slug;
@@ -2,12 +2,23 @@
 //!
 //! Per ADR-003, slugs must be:
 //! - Lowercase
-//! - Alphanumeric + hyphens only
+//! - Alphanumeric + separators (see [`SEPARATORS`]) only
 //! - Max 50 characters
-//! - No leading/trailing hyphens
-//! - No consecutive hyphens
+//! - No leading/trailing separators
+//! - No consecutive separators
+//!
+//! [`generate`] joins words with hyphens. [`generate_with`] uses another
+//! separator, such as `_`, for teams that prefer it in IDs;
+//! [`SpecId::parse_strict`] accepts slugs made with either.
+
+use crate::spec::{SpecError, SpecId};
+
+/// Word separators a slug may use, in generated slugs and in spec IDs
+/// checked by [`SpecId::parse_strict`]. [`generate`] uses the first, `-`.
+pub const SEPARATORS: &[char] = &['-', '_'];
 
-use crate::spec::SpecId;
+/// Separator used by [`generate`].
+const DEFAULT_SEPARATOR: char = '-';
 
 /// Generates a URL-safe slug from a title.
 ///
@@ -37,6 +48,46 @@ use crate::spec::SpecId;
 /// ```
 #[must_use]
 pub fn generate(title: &str, max_length: usize) -> String {
+    slugify(title, max_length, DEFAULT_SEPARATOR)
+}
+
+/// Generates a slug like [`generate`], joining words with `separator`.
+///
+/// Spaces, hyphens, and underscores in `title` all become `separator`,
+/// runs of it collapse into one, and it never leads or trails the slug.
+///
+/// # Arguments
+///
+/// * `title` - The input string to slugify
+/// * `max_length` - Maximum length of the resulting slug
+/// * `separator` - Word separator, one of [`SEPARATORS`]
+///
+/// # Errors
+///
+/// Returns [`SpecError::InvalidFormat`] if `separator` is not in
+/// [`SEPARATORS`].
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::utils::slug;
+///
+/// assert_eq!(slug::generate_with("My Feature Title", 50, '_').unwrap(), "my_feature_title");
+/// assert!(slug::generate_with("My Feature Title", 50, '.').is_err());
+/// ```
+pub fn generate_with(title: &str, max_length: usize, separator: char) -> Result<String, SpecError> {
+    if !SEPARATORS.contains(&separator) {
+        let allowed: Vec<String> = SEPARATORS.iter().map(ToString::to_string).collect();
+        return Err(SpecError::InvalidFormat(format!(
+            "invalid slug separator '{separator}', expected one of: {}",
+            allowed.join(", ")
+        )));
+    }
+    Ok(slugify(title, max_length, separator))
+}
+
+/// Slugifies `title` with `separator`, which must be in [`SEPARATORS`].
+fn slugify(title: &str, max_length: usize, separator: char) -> String {
     // Step 1: Convert to lowercase and map characters
     let slug: String = title
         .to_lowercase()
@@ -45,7 +96,7 @@ pub fn generate(title: &str, max_length: usize) -> String {
             if c.is_ascii_alphanumeric() {
                 c
             } else if c.is_whitespace() || c == '-' || c == '_' {
-                '-'
+                separator
             } else {
                 // Mark for removal
                 '\0'
@@ -54,33 +105,33 @@ pub fn generate(title: &str, max_length: usize) -> String {
         .filter(|&c| c != '\0')
         .collect();
 
-    // Step 2: Collapse consecutive hyphens and trim leading hyphens
+    // Step 2: Collapse consecutive separators and trim leading separators
     let mut result = String::with_capacity(slug.len());
-    let mut prev_hyphen = true; // Start as true to trim leading hyphens
+    let mut prev_separator = true; // Start as true to trim leading separators
 
     for c in slug.chars() {
-        if c == '-' {
-            if !prev_hyphen {
+        if c == separator {
+            if !prev_separator {
                 result.push(c);
-                prev_hyphen = true;
+                prev_separator = true;
             }
-            // Skip consecutive hyphens
+            // Skip consecutive separators
         } else {
             result.push(c);
-            prev_hyphen = false;
+            prev_separator = false;
         }
     }
 
-    // Step 3: Trim trailing hyphen
-    while result.ends_with('-') {
+    // Step 3: Trim trailing separator
+    while result.ends_with(separator) {
         result.pop();
     }
 
     // Step 4: Truncate to max_length, avoiding mid-word breaks
     if result.len() > max_length {
         let truncated = &result[..max_length];
-        // Don't end on a hyphen
-        truncated.trim_end_matches('-').to_string()
+        // Don't end on a separator
+        truncated.trim_end_matches(separator).to_string()
     } else {
         result
     }
@@ -226,4 +277,46 @@ mod tests {
     fn test_brackets() {
         assert_eq!(generate("fix [urgent]", 50), "fix-urgent");
     }
+
+    #[test]
+    fn test_generate_with_underscore() {
+        assert_eq!(
+            generate_with("My Feature Title", 50, '_').unwrap(),
+            "my_feature_title"
+        );
+        assert_eq!(
+            generate_with("kebab-case title", 50, '_').unwrap(),
+            "kebab_case_title"
+        );
+    }
+
+    #[test]
+    fn test_generate_with_underscore_collapses_and_trims() {
+        assert_eq!(generate_with("__a - _b   c__", 50, '_').unwrap(), "a_b_c");
+        assert_eq!(generate_with("  title  ", 50, '_').unwrap(), "title");
+    }
+
+    #[test]
+    fn test_generate_with_underscore_truncation() {
+        let title = "a".repeat(49) + " b";
+        let slug = generate_with(&title, 50, '_').unwrap();
+        assert_eq!(slug, "a".repeat(49));
+    }
+
+    #[test]
+    fn test_generate_with_hyphen_matches_generate() {
+        let title = "Fix Bug #123!  now";
+        assert_eq!(generate_with(title, 50, '-').unwrap(), generate(title, 50));
+    }
+
+    #[test]
+    fn test_generate_with_rejects_invalid_separator() {
+        for separator in ['.', ' ', 'a', '/'] {
+            let result = generate_with("title", 50, separator);
+            assert!(
+                matches!(result, Err(SpecError::InvalidFormat(ref msg)) if msg.contains("expected one of: -, _")),
+                "{separator:?}: {result:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,204 @@
+//! Blocking graph shared by the checks for cycles among blocking
+//! dependencies.
+//!
+//! [`DependencyValidator`](super::DependencyValidator) does not use it: it
+//! reports cycles across every dependency kind.
+//!
+//! Only blocking dependencies (`BlockedBy` for [`Spec`](crate::spec::Spec))
+//! form edges: they order work, so a cycle among them can never be
+//! finished. Other kinds such as `RelatedTo` are informational and never
+//! make a cycle. Self-references and dependencies on specs outside the
+//! graph are left out; callers report those separately.
+
+use std::collections::{BTreeMap, HashSet};
+
+use super::traits::ValidatableSpec;
+
+/// Which specs block which, built from [`ValidatableSpec::blocker_ids`].
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::spec::{Dependency, Spec, SpecId, SpecMetadata};
+/// use airsspec_core::validation::BlockingGraph;
+///
+/// let a = SpecId::new(1, "a");
+/// let b = SpecId::new(2, "b");
+/// let mut a_meta = SpecMetadata::new("A", "");
+/// a_meta.add_dependency(Dependency::blocked_by(b.clone()));
+/// let mut b_meta = SpecMetadata::new("B", "");
+/// b_meta.add_dependency(Dependency::blocked_by(a.clone()));
+/// let specs = [Spec::new(a, a_meta, ""), Spec::new(b, b_meta, "")];
+///
+/// let graph = BlockingGraph::new(&specs);
+/// assert!(graph.reaches("1-a", "2-b"));
+/// assert_eq!(graph.cycles(), vec![vec!["1-a", "2-b", "1-a"]]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockingGraph<'a> {
+    blockers: BTreeMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> BlockingGraph<'a> {
+    /// Builds the graph of `specs`.
+    #[must_use]
+    pub fn new<S: ValidatableSpec + 'a>(specs: impl IntoIterator<Item = &'a S>) -> Self {
+        let specs: Vec<&'a S> = specs.into_iter().collect();
+        let known: HashSet<&str> = specs.iter().map(|spec| spec.id_str()).collect();
+
+        let mut blockers: BTreeMap<&'a str, Vec<&'a str>> = BTreeMap::new();
+        for spec in specs {
+            let id = spec.id_str();
+            let edges = blockers.entry(id).or_default();
+            for blocker in spec.blocker_ids() {
+                if blocker != id && known.contains(blocker) && !edges.contains(&blocker) {
+                    edges.push(blocker);
+                }
+            }
+        }
+        Self { blockers }
+    }
+
+    /// Returns whether `id` is a spec in the graph.
+    #[must_use]
+    pub fn contains(&self, id: &str) -> bool {
+        self.blockers.contains_key(id)
+    }
+
+    /// Returns the specs blocking `id`, in dependency order. Unknown IDs
+    /// have none.
+    #[must_use]
+    pub fn blockers(&self, id: &str) -> &[&'a str] {
+        self.blockers.get(id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns whether `goal` can be reached from `start` by following
+    /// blocking edges. A spec always reaches itself.
+    #[must_use]
+    pub fn reaches(&self, start: &str, goal: &str) -> bool {
+        let mut stack = vec![start];
+        let mut seen = HashSet::new();
+        while let Some(id) = stack.pop() {
+            if id == goal {
+                return true;
+            }
+            if seen.insert(id) {
+                stack.extend(self.blockers(id));
+            }
+        }
+        false
+    }
+
+    /// Returns every cycle, each as its IDs rotated to start at the lowest
+    /// one and with that ID repeated at the end, e.g. `["1-a", "2-b",
+    /// "1-a"]`.
+    ///
+    /// Cycles are found by a depth-first search from each spec in ID order;
+    /// the same cycle is only returned once.
+    #[must_use]
+    pub fn cycles(&self) -> Vec<Vec<&'a str>> {
+        let mut finished = HashSet::new();
+        let mut cycles = Vec::new();
+        for &root in self.blockers.keys() {
+            let mut path = Vec::new();
+            self.find_cycles(root, &mut path, &mut finished, &mut cycles);
+        }
+        cycles
+    }
+
+    /// Depth-first search from `id`, recording each cycle closed by an
+    /// edge back to a spec on `path`.
+    fn find_cycles(
+        &self,
+        id: &'a str,
+        path: &mut Vec<&'a str>,
+        finished: &mut HashSet<&'a str>,
+        cycles: &mut Vec<Vec<&'a str>>,
+    ) {
+        if finished.contains(id) {
+            return;
+        }
+        if let Some(start) = path.iter().position(|&visiting| visiting == id) {
+            let members = &path[start..];
+            let lowest = (0..members.len())
+                .min_by_key(|&index| members[index])
+                .unwrap_or_default();
+            let mut cycle: Vec<&str> = members[lowest..]
+                .iter()
+                .chain(&members[..lowest])
+                .copied()
+                .collect();
+            cycle.push(members[lowest]);
+            if !cycles.contains(&cycle) {
+                cycles.push(cycle);
+            }
+            return;
+        }
+
+        path.push(id);
+        for &blocker in self.blockers(id) {
+            self.find_cycles(blocker, path, finished, cycles);
+        }
+        path.pop();
+        finished.insert(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{Dependency, Spec, SpecId, SpecMetadata};
+
+    fn spec(timestamp: i64, slug: &str, dependencies: Vec<Dependency>) -> Spec {
+        let mut metadata = SpecMetadata::new(slug, "Graph");
+        for dependency in dependencies {
+            metadata.add_dependency(dependency);
+        }
+        Spec::new(SpecId::new(timestamp, slug), metadata, "")
+    }
+
+    #[test]
+    fn test_only_blocked_by_edges_to_known_specs_count() {
+        let a = SpecId::new(1, "a");
+        let b = SpecId::new(2, "b");
+        let specs = [
+            spec(
+                1,
+                "a",
+                vec![
+                    Dependency::blocked_by(b.clone()),
+                    Dependency::blocked_by(b.clone()),
+                    Dependency::blocked_by(a.clone()),
+                    Dependency::blocked_by(SpecId::new(9, "missing")),
+                ],
+            ),
+            spec(2, "b", vec![Dependency::related_to(a)]),
+        ];
+
+        let graph = BlockingGraph::new(&specs);
+
+        assert_eq!(graph.blockers("1-a"), ["2-b"]);
+        assert!(graph.blockers("2-b").is_empty());
+        assert!(graph.cycles().is_empty());
+        assert!(graph.reaches("1-a", "2-b"));
+        assert!(!graph.reaches("2-b", "1-a"));
+        assert!(!graph.contains("9-missing"));
+    }
+
+    #[test]
+    fn test_each_cycle_reported_once_from_lowest_id() {
+        let a = SpecId::new(1, "a");
+        let b = SpecId::new(2, "b");
+        let c = SpecId::new(3, "c");
+        let specs = [
+            spec(3, "c", vec![Dependency::blocked_by(b.clone())]),
+            spec(2, "b", vec![Dependency::blocked_by(a)]),
+            spec(1, "a", vec![Dependency::blocked_by(c)]),
+            spec(4, "d", vec![Dependency::blocked_by(b)]),
+        ];
+
+        let graph = BlockingGraph::new(&specs);
+
+        assert_eq!(graph.cycles(), vec![vec!["1-a", "3-c", "2-b", "1-a"]]);
+    }
+}
@@ -13,6 +13,7 @@
 //! - [`ValidationReport`] - Collection of issues with merge support
 //! - [`Validator`] - Generic trait for implementing validators
 //! - [`ValidationContext`] - Generic context for workspace-level validation
+//! - [`BlockingGraph`] - Blocking dependency graph shared by cycle checks
 //! - [`ValidatableSpec`] / [`ValidatablePlan`] - Trait abstractions for DIP
 //!
 //! ## Dependency Inversion Principle
@@ -56,6 +57,7 @@
 //! These functions use the validation framework from this module.
 
 mod context;
+mod graph;
 mod issue;
 mod report;
 mod severity;
@@ -64,6 +66,7 @@ mod validator;
 pub(crate) mod validators;
 
 pub use context::{ValidationContext, ValidationContextBuilder};
+pub use graph::BlockingGraph;
 pub use issue::ValidationIssue;
 pub use report::ValidationReport;
 pub use severity::ValidationSeverity;
@@ -72,6 +75,6 @@ pub use validator::{Validator, ValidatorExt};
 
 // Workspace validator re-exports for convenience
 pub use validators::{
-    DependencyValidator, DirectoryStructureValidator, SpecContentValidator,
+    DependencyValidator, DirectoryStructureValidator, PlanReferenceValidator, SpecContentValidator,
     StateTransitionValidator,
 };
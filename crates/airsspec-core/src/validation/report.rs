@@ -1,5 +1,7 @@
 //! Validation report for collecting issues.
 
+use std::collections::HashSet;
+
 use super::issue::ValidationIssue;
 use super::severity::ValidationSeverity;
 
@@ -147,6 +149,31 @@ impl ValidationReport {
             self.merge(other);
         }
     }
+
+    /// Removes issues identical to an earlier one (same severity, field,
+    /// and message), keeping the first occurrence of each in place.
+    ///
+    /// Useful after merging reports from validators that can flag the
+    /// same problem.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use airsspec_core::validation::ValidationReport;
+    ///
+    /// let mut report = ValidationReport::new();
+    /// report.add_error("Broken link");
+    /// report.add_warning("Broken link");
+    /// report.add_error("Broken link");
+    ///
+    /// report.dedup();
+    /// assert_eq!(report.error_count(), 1);
+    /// assert_eq!(report.warning_count(), 1);
+    /// ```
+    pub fn dedup(&mut self) {
+        let mut seen = HashSet::new();
+        self.issues.retain(|issue| seen.insert(issue.clone()));
+    }
 }
 
 #[cfg(test)]
@@ -198,6 +225,45 @@ mod tests {
         assert_eq!(report1.warning_count(), 2);
     }
 
+    #[test]
+    fn test_dedup_collapses_duplicates_across_merged_reports() {
+        let mut report = ValidationReport::new();
+        report.add_issue(ValidationIssue::error("Missing title").with_field("metadata.title"));
+        report.add_warning("Empty content");
+
+        let mut other = ValidationReport::new();
+        other.add_warning("Empty content");
+        other.add_info("Checked 2 specs");
+        other.add_issue(ValidationIssue::error("Missing title").with_field("metadata.title"));
+
+        report.merge(other);
+        report.dedup();
+
+        let issues: Vec<String> = report.issues().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            issues,
+            [
+                "[error] metadata.title: Missing title",
+                "[warning] Empty content",
+                "[info] Checked 2 specs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dedup_keeps_distinct_issues() {
+        let mut report = ValidationReport::new();
+        report.add_error("Same message");
+        report.add_warning("Same message");
+        report.add_issue(ValidationIssue::error("Same message").with_field("a"));
+        report.add_issue(ValidationIssue::error("Same message").with_field("b"));
+        let before = report.clone();
+
+        report.dedup();
+
+        assert_eq!(report, before);
+    }
+
     #[test]
     fn test_merge_all() {
         let mut main_report = ValidationReport::new();
@@ -58,6 +58,15 @@ pub trait ValidatableSpec: Send + Sync {
     /// self-references, and circular dependencies.
     fn dependency_ids(&self) -> Vec<&str>;
 
+    /// Returns the IDs of specs that block this one.
+    ///
+    /// Only these edges form the [`BlockingGraph`](super::BlockingGraph)
+    /// used for cycle detection. Defaults to all of
+    /// [`dependency_ids`](Self::dependency_ids).
+    fn blocker_ids(&self) -> Vec<&str> {
+        self.dependency_ids()
+    }
+
     /// Validates this spec's content and returns a validation report.
     ///
     /// Used by `SpecContentValidator` to run per-spec validation without
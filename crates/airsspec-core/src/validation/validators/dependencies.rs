@@ -20,7 +20,9 @@ use crate::validation::validator::Validator;
 /// Checks for:
 /// - **Broken references**: A spec depends on a spec ID that doesn't exist
 /// - **Self-references**: A spec depends on itself
-/// - **Circular dependencies**: Cycles in the dependency graph (A -> B -> A)
+/// - **Circular dependencies**: Cycles in the dependency graph (A -> B -> A),
+///   following every dependency kind rather than only the blocking edges of
+///   [`BlockingGraph`](crate::validation::BlockingGraph)
 ///
 /// # Examples
 ///
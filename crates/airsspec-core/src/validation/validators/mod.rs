@@ -20,13 +20,16 @@
 //! - [`SpecContentValidator`] -- Validates all specs using the `ValidatableSpec` trait
 //! - [`DependencyValidator`] -- Cross-spec dependency validation (broken refs, cycles)
 //! - [`StateTransitionValidator`] -- Validates specs have required artifacts for their state
+//! - [`PlanReferenceValidator`] -- Checks every plan belongs to an existing spec
 
 mod content;
 mod dependencies;
+mod references;
 mod state;
 mod structure;
 
 pub use content::SpecContentValidator;
 pub use dependencies::DependencyValidator;
+pub use references::PlanReferenceValidator;
 pub use state::StateTransitionValidator;
 pub use structure::DirectoryStructureValidator;
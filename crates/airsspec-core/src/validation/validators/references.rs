@@ -0,0 +1,168 @@
+//! Plan reference validator.
+//!
+//! Validates that every plan in the workspace belongs to an existing
+//! specification. A plan whose spec is missing is an orphan: it can never
+//! be reached through the spec workflow and usually indicates that the
+//! spec file was deleted or renamed without its plan.
+//!
+//! Uses [`ValidatableSpec`] and [`ValidatablePlan`] trait abstractions
+//! for DIP compliance.
+
+use std::collections::HashSet;
+
+use crate::validation::context::ValidationContext;
+use crate::validation::issue::ValidationIssue;
+use crate::validation::report::ValidationReport;
+use crate::validation::traits::{ValidatablePlan, ValidatableSpec};
+use crate::validation::validator::Validator;
+
+/// Validates that plans reference existing specifications.
+///
+/// Checks:
+/// - Plans whose spec ID does not match any loaded spec (error)
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use airsspec_core::validation::{
+///     Validator, ValidationContextBuilder, PlanReferenceValidator,
+///     ValidatableSpec, ValidatablePlan, ValidationReport,
+/// };
+///
+/// struct MockSpec { id: String }
+/// impl ValidatableSpec for MockSpec {
+///     fn id_str(&self) -> &str { &self.id }
+///     fn dependency_ids(&self) -> Vec<&str> { vec![] }
+///     fn validate_content(&self) -> ValidationReport { ValidationReport::new() }
+/// }
+///
+/// struct MockPlan { spec_id: String }
+/// impl ValidatablePlan for MockPlan {
+///     fn spec_id_str(&self) -> &str { &self.spec_id }
+///     fn step_count(&self) -> usize { 1 }
+///     fn is_completed(&self) -> bool { false }
+/// }
+///
+/// let context = ValidationContextBuilder::new()
+///     .workspace_path(PathBuf::from("/project"))
+///     .specs(Vec::<MockSpec>::new())
+///     .plans(vec![MockPlan { spec_id: "1000000-gone".into() }])
+///     .build();
+///
+/// let report = PlanReferenceValidator.validate(&context);
+/// assert!(!report.is_valid());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PlanReferenceValidator;
+
+impl<S, P> Validator<ValidationContext<S, P>> for PlanReferenceValidator
+where
+    S: ValidatableSpec,
+    P: ValidatablePlan,
+{
+    fn name(&self) -> &'static str {
+        "plan-references"
+    }
+
+    fn validate(&self, context: &ValidationContext<S, P>) -> ValidationReport {
+        let mut report = ValidationReport::new();
+        let known_ids: HashSet<&str> = context
+            .specs()
+            .iter()
+            .map(ValidatableSpec::id_str)
+            .collect();
+
+        for plan in context.plans() {
+            let spec_id = plan.spec_id_str();
+            if !known_ids.contains(spec_id) {
+                report.add_issue(
+                    ValidationIssue::error(format!(
+                        "Plan references non-existent spec '{spec_id}'"
+                    ))
+                    .with_field(format!("[{spec_id}] plan")),
+                );
+            }
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::plan::{Plan, PlanStep};
+    use crate::spec::{Spec, SpecId, SpecMetadata};
+    use crate::validation::ValidationContextBuilder;
+
+    fn make_spec(timestamp: i64, slug: &str) -> Spec {
+        Spec::new(
+            SpecId::new(timestamp, slug),
+            SpecMetadata::new(slug, "Description"),
+            "Content",
+        )
+    }
+
+    fn make_plan(timestamp: i64, slug: &str) -> Plan {
+        Plan::new(
+            SpecId::new(timestamp, slug),
+            "Test approach",
+            vec![PlanStep::new(0, "Step 1", "Do something")],
+        )
+    }
+
+    fn make_context(specs: Vec<Spec>, plans: Vec<Plan>) -> ValidationContext<Spec, Plan> {
+        ValidationContextBuilder::new()
+            .workspace_path(PathBuf::from("/project"))
+            .specs(specs)
+            .plans(plans)
+            .build()
+    }
+
+    #[test]
+    fn test_empty_context_is_valid() {
+        let context = make_context(vec![], vec![]);
+        let report = PlanReferenceValidator.validate(&context);
+
+        assert!(report.is_valid());
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn test_plan_with_existing_spec_passes() {
+        let context = make_context(
+            vec![make_spec(1_000_000, "planned")],
+            vec![make_plan(1_000_000, "planned")],
+        );
+        let report = PlanReferenceValidator.validate(&context);
+
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn test_orphaned_plan_reports_error() {
+        let context = make_context(
+            vec![make_spec(1_000_000, "present")],
+            vec![make_plan(2_000_000, "missing")],
+        );
+        let report = PlanReferenceValidator.validate(&context);
+
+        assert!(!report.is_valid());
+        assert_eq!(report.error_count(), 1);
+        let error = &report.errors()[0];
+        assert!(error.message().contains("2000000-missing"));
+        assert_eq!(error.field(), Some("[2000000-missing] plan"));
+    }
+
+    #[test]
+    fn test_validator_name() {
+        let validator = PlanReferenceValidator;
+        assert_eq!(
+            Validator::<ValidationContext<Spec, Plan>>::name(&validator),
+            "plan-references"
+        );
+    }
+}
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::spec::Category;
+use crate::spec::{Category, IdGranularity};
 
 /// Project configuration stored in `.airsspec/config.toml`.
 ///
@@ -58,6 +58,17 @@ impl ProjectConfig {
     pub fn set_default_category(&mut self, category: Category) {
         self.defaults.category = category;
     }
+
+    /// Returns the timestamp resolution for new spec IDs.
+    #[must_use]
+    pub fn id_granularity(&self) -> IdGranularity {
+        self.defaults.id_granularity
+    }
+
+    /// Sets the timestamp resolution for new spec IDs.
+    pub fn set_id_granularity(&mut self, id_granularity: IdGranularity) {
+        self.defaults.id_granularity = id_granularity;
+    }
 }
 
 /// Basic project information.
@@ -86,6 +97,8 @@ impl ProjectInfo {
 pub struct SpecDefaults {
     #[serde(default)]
     category: Category,
+    #[serde(default)]
+    id_granularity: IdGranularity,
 }
 
 impl SpecDefaults {
@@ -94,6 +107,12 @@ impl SpecDefaults {
     pub fn category(&self) -> Category {
         self.category
     }
+
+    /// Returns the timestamp resolution for new spec IDs.
+    #[must_use]
+    pub fn id_granularity(&self) -> IdGranularity {
+        self.id_granularity
+    }
 }
 
 #[cfg(test)]
@@ -182,4 +201,22 @@ mod tests {
         let parsed: ProjectConfig = toml::from_str(&toml_str).expect("should parse TOML");
         assert_eq!(parsed.default_category(), Category::Enhancement);
     }
+
+    #[test]
+    fn test_config_id_granularity() {
+        let mut config = ProjectConfig::new("Test", "Test");
+        assert_eq!(config.id_granularity(), IdGranularity::Seconds);
+
+        config.set_id_granularity(IdGranularity::Millis);
+        let toml_str = toml::to_string(&config).expect("should serialize to TOML");
+        let parsed: ProjectConfig = toml::from_str(&toml_str).expect("should parse TOML");
+        assert_eq!(parsed.id_granularity(), IdGranularity::Millis);
+    }
+
+    #[test]
+    fn test_config_without_id_granularity_defaults_to_seconds() {
+        let toml_str = "[project]\nname = \"Test\"\ndescription = \"Test\"\n";
+        let parsed: ProjectConfig = toml::from_str(toml_str).expect("should parse TOML");
+        assert_eq!(parsed.id_granularity(), IdGranularity::Seconds);
+    }
 }
@@ -19,6 +19,16 @@ pub enum WorkspaceError {
     #[error("invalid workspace configuration: {0}")]
     InvalidConfig(String),
 
+    /// Another running process holds the workspace lock.
+    #[error("workspace is locked by another airsspec process (pid {0})")]
+    Locked(u32),
+
+    /// The workspace lock file exists but does not name its owner.
+    #[error(
+        "workspace lock {0} is held by an unknown process; remove it if no airsspec process is running"
+    )]
+    LockedByUnknown(PathBuf),
+
     /// I/O error (stored as string since `io::Error` doesn't impl Clone/Eq).
     #[error("I/O error: {0}")]
     Io(String),
@@ -58,6 +68,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_display_locked() {
+        let err = WorkspaceError::Locked(4242);
+        assert_eq!(
+            err.to_string(),
+            "workspace is locked by another airsspec process (pid 4242)"
+        );
+    }
+
+    #[test]
+    fn test_error_display_locked_by_unknown() {
+        let err = WorkspaceError::LockedByUnknown(PathBuf::from("/project/.airsspec/.lock"));
+        assert_eq!(
+            err.to_string(),
+            "workspace lock /project/.airsspec/.lock is held by an unknown process; \
+             remove it if no airsspec process is running"
+        );
+    }
+
     #[test]
     fn test_error_display_io() {
         let err = WorkspaceError::Io("file not found".to_string());
@@ -18,21 +18,27 @@
 //! - [`storage`] - Filesystem implementations of core storage traits
 //! - [`validation`] - Workspace validation orchestration
 //! - [`server`] - MCP server setup, handler, and lifecycle
+//! - [`tools`] - MCP tool handlers (`workspace_validate`, `spec_add_dependency`,
+//!   `spec_remove_dependency`, `spec_search`, `tags_list`)
 //!
 //! ## Future Modules (Phase 5+)
 //!
-//! - `tools/` - MCP tool handlers (`spec_create`, `plan_create`, etc.)
+//! - More tools (`spec_create`, `plan_create`, etc.)
 //! - `resources/` - Resource providers (`airsspec:///` URIs)
 //! - `prompts/` - Prompt template providers
 //! - `logging/` - JSONL session logging
 
 pub mod server;
 pub mod storage;
+pub mod tools;
 pub mod validation;
 
+#[cfg(test)]
+mod test_support;
+
 // Convenience re-exports
 pub use server::{AirsSpecHandler, McpServerBuilder, ServerError};
 pub use storage::FileSystemPlanStorage;
 pub use storage::FileSystemSpecStorage;
 pub use storage::FileSystemWorkspaceProvider;
-pub use validation::validate_workspace;
+pub use validation::{check_workspace_integrity, validate_workspace};
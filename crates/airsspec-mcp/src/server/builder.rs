@@ -1,19 +1,31 @@
 //! Builder for constructing the `AirsSpec` MCP server.
 //!
-//! [`McpServerBuilder`] validates workspace configuration, creates the
-//! handler with stub providers, builds the stdio transport, and returns
-//! a ready-to-run [`McpServer`](airsprotocols_mcp::McpServer).
+//! [`McpServerBuilder`] validates workspace configuration, acquires the
+//! [`WorkspaceLock`], creates the handler with the workspace tools and stub
+//! resource/prompt providers, builds the stdio transport, and returns a
+//! ready-to-run [`McpServer`](airsprotocols_mcp::McpServer). The lock is
+//! held by the tool provider, so it is released only when the server is
+//! dropped.
+//!
+//! It also starts a background task that polls the specs directory with a
+//! [`SpecWatcher`] and forwards changes to the handler, which notifies
+//! clients subscribed to the changed specs.
 
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 
 use airsprotocols_mcp::McpServer;
 use airsprotocols_mcp::protocol::ServerInfo;
 use airsprotocols_mcp::protocol::Transport;
 use airsprotocols_mcp::transport::adapters::stdio::StdioTransportBuilder;
 
+use airsspec_core::workspace::WorkspaceError;
+
 use super::error::ServerError;
 use super::handler::AirsSpecHandler;
+use crate::storage::{SpecWatcher, WorkspaceLock};
+use crate::tools::WorkspaceToolProvider;
 
 /// Builder for constructing the `AirsSpec` MCP server.
 ///
@@ -40,15 +52,24 @@ pub struct McpServerBuilder {
     workspace_path: Option<PathBuf>,
     /// Whether debug mode is enabled.
     debug: bool,
+    /// Maximum number of tool calls executing at once.
+    max_concurrent_tool_calls: usize,
+    /// How often the specs directory is polled for changes.
+    spec_watch_interval: Duration,
 }
 
 impl McpServerBuilder {
+    /// Default interval between polls of the specs directory.
+    pub const DEFAULT_SPEC_WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
     /// Create a new builder with default settings.
     #[must_use]
     pub fn new() -> Self {
         Self {
             workspace_path: None,
             debug: false,
+            max_concurrent_tool_calls: AirsSpecHandler::DEFAULT_MAX_CONCURRENT_TOOL_CALLS,
+            spec_watch_interval: Self::DEFAULT_SPEC_WATCH_INTERVAL,
         }
     }
 
@@ -68,16 +89,39 @@ impl McpServerBuilder {
         self
     }
 
+    /// Limit how many tool calls execute at once; further calls are queued.
+    ///
+    /// Defaults to [`AirsSpecHandler::DEFAULT_MAX_CONCURRENT_TOOL_CALLS`].
+    #[must_use]
+    pub fn max_concurrent_tool_calls(mut self, limit: usize) -> Self {
+        self.max_concurrent_tool_calls = limit;
+        self
+    }
+
+    /// Set how often the specs directory is polled for changes to notify
+    /// subscribed clients about.
+    ///
+    /// Defaults to [`DEFAULT_SPEC_WATCH_INTERVAL`](Self::DEFAULT_SPEC_WATCH_INTERVAL).
+    /// A zero interval is treated as one millisecond.
+    #[must_use]
+    pub fn spec_watch_interval(mut self, interval: Duration) -> Self {
+        self.spec_watch_interval = interval.max(Duration::from_millis(1));
+        self
+    }
+
     /// Build the MCP server.
     ///
-    /// Validates the workspace path, creates the handler with stub
-    /// providers, builds the stdio transport, and returns a configured
-    /// [`McpServer`].
+    /// Validates the workspace path, locks the workspace, creates the
+    /// handler with the workspace tools, builds the stdio transport, and
+    /// returns a configured [`McpServer`]. The workspace stays locked until
+    /// the server is dropped.
     ///
     /// # Errors
     ///
     /// Returns [`ServerError::InvalidWorkspace`] if the workspace path
-    /// does not exist or is not a directory.
+    /// does not exist, is not a directory, or has no `.airsspec` directory.
+    /// Returns [`ServerError::Workspace`] if the workspace is locked by
+    /// another process or the lock file cannot be created.
     /// Returns [`ServerError::Transport`] if transport creation fails.
     pub async fn build(self) -> Result<McpServer<impl Transport>, ServerError> {
         // 1. Determine workspace path
@@ -102,16 +146,39 @@ impl McpServerBuilder {
             )));
         }
 
-        // 3. Create server info
+        // 3. Lock the workspace for the server's lifetime
+        let lock = WorkspaceLock::acquire(&workspace_path).map_err(|err| match err {
+            WorkspaceError::NotFound(_) => ServerError::InvalidWorkspace(format!(
+                "no .airsspec directory in: {}",
+                workspace_path.display()
+            )),
+            err => ServerError::Workspace(err),
+        })?;
+
+        // 4. Create server info
         let server_info = ServerInfo {
             name: String::from("airsspec"),
             version: String::from(env!("CARGO_PKG_VERSION")),
         };
 
-        // 4. Create handler (writes directly to stdout, no transport ref needed)
-        let handler = Arc::new(AirsSpecHandler::new(server_info));
+        // 5. Create handler (writes to a shared stdout writer, no transport ref needed)
+        let specs_dir = workspace_path.join(".airsspec").join("specs");
+        let tool_provider =
+            Arc::new(WorkspaceToolProvider::new(workspace_path).with_workspace_lock(lock));
+        let handler = Arc::new(
+            AirsSpecHandler::new(server_info)
+                .with_tool_provider(tool_provider)
+                .with_max_concurrent_tool_calls(self.max_concurrent_tool_calls),
+        );
+
+        // 6. Watch the specs directory for subscribed resources
+        spawn_spec_watcher(
+            SpecWatcher::new(specs_dir),
+            Arc::downgrade(&handler),
+            self.spec_watch_interval,
+        );
 
-        // 5. Build stdio transport with handler
+        // 7. Build stdio transport with handler
         let session_id = uuid::Uuid::new_v4().to_string();
         let transport = StdioTransportBuilder::new()
             .with_message_handler(handler)
@@ -120,11 +187,52 @@ impl McpServerBuilder {
             .await
             .map_err(ServerError::Transport)?;
 
-        // 6. Wrap in McpServer lifecycle manager
+        // 8. Wrap in McpServer lifecycle manager
         Ok(McpServer::new(transport))
     }
 }
 
+/// Polls `watcher` every `interval` and passes changed specs to the handler.
+///
+/// Polling reads the specs directory, so it runs on the blocking thread
+/// pool, with the watcher moved there and back each time. Notifications go
+/// through the handler's shared output writer, so they never interleave with
+/// responses. The task ends once the handler has been dropped, or if a poll
+/// panics.
+fn spawn_spec_watcher(
+    mut watcher: SpecWatcher,
+    handler: Weak<AirsSpecHandler>,
+    interval: Duration,
+) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            let Some(handler) = handler.upgrade() else {
+                break;
+            };
+            let polled = tokio::task::spawn_blocking(move || {
+                let changed = watcher.poll();
+                (watcher, changed)
+            })
+            .await;
+            let changed = match polled {
+                Ok((returned, changed)) => {
+                    watcher = returned;
+                    changed
+                }
+                Err(err) => {
+                    tracing::error!("Spec watcher stopped: {err}");
+                    break;
+                }
+            };
+            if !changed.is_empty() {
+                handler.notify_spec_changes(&changed).await;
+            }
+        }
+    });
+}
+
 impl Default for McpServerBuilder {
     fn default() -> Self {
         Self::new()
@@ -170,12 +278,51 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_builder_requires_airsspec_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        let result = McpServerBuilder::new()
+            .workspace_path(temp.path().to_path_buf())
+            .build()
+            .await;
+
+        let Err(err) = result else {
+            panic!("expected error for directory without .airsspec");
+        };
+        assert!(
+            matches!(err, ServerError::InvalidWorkspace(ref msg) if msg.contains(".airsspec")),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_builder_rejects_locked_workspace() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir(temp.path().join(".airsspec")).unwrap();
+        let _held = WorkspaceLock::acquire(temp.path()).unwrap();
+
+        let result = McpServerBuilder::new()
+            .workspace_path(temp.path().to_path_buf())
+            .build()
+            .await;
+
+        let Err(err) = result else {
+            panic!("expected error for locked workspace");
+        };
+        assert!(
+            matches!(err, ServerError::Workspace(WorkspaceError::Locked(_))),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_builder_default_values() {
         let builder = McpServerBuilder::new();
         let debug_output = format!("{builder:?}");
         assert!(debug_output.contains("workspace_path: None"));
         assert!(debug_output.contains("debug: false"));
+        assert!(debug_output.contains("max_concurrent_tool_calls: 64"));
+        assert!(debug_output.contains("spec_watch_interval: 1s"));
     }
 
     #[test]
@@ -1,11 +1,14 @@
 //! Server error types for the `AirsSpec` MCP server.
 //!
-//! Provides a unified error type covering transport, builder, serialization,
-//! and provider failure modes for the MCP server module.
+//! Provides a unified error type covering transport, builder, workspace
+//! locking, serialization, and provider failure modes for the MCP server
+//! module.
 
 use airsprotocols_mcp::McpError;
 use airsprotocols_mcp::protocol::TransportError;
 
+use airsspec_core::workspace::WorkspaceError;
+
 /// Errors that can occur in the MCP server.
 #[derive(Debug, thiserror::Error)]
 pub enum ServerError {
@@ -17,6 +20,10 @@ pub enum ServerError {
     #[error("invalid workspace: {0}")]
     InvalidWorkspace(String),
 
+    /// The workspace could not be locked, e.g. another server holds it.
+    #[error("workspace error: {0}")]
+    Workspace(#[from] WorkspaceError),
+
     /// JSON serialization/deserialization error.
     #[error("serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
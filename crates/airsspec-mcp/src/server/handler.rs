@@ -11,17 +11,44 @@
 //!
 //! 1. **Routing + Response Construction (testable):** [`AirsSpecHandler::route_request`]
 //!    takes a [`JsonRpcRequest`] and returns a [`JsonRpcResponse`] -- no I/O.
-//! 2. **I/O Layer (thin):** The private `send_response` method writes a
-//!    [`JsonRpcResponse`] to stdout. The [`MessageHandler::handle_message`]
-//!    implementation glues the two layers together.
+//! 2. **I/O Layer (thin):** The private `send_message` method writes a
+//!    response or notification to stdout. The
+//!    [`MessageHandler::handle_message`] implementation glues the two layers
+//!    together.
+//!
+//! Every message goes through one shared writer behind a mutex and is written
+//! as a single newline-terminated buffer, so responses and watcher
+//! notifications sent from different tasks never interleave on the stream.
 //!
 //! Tests call `route_request()` directly and assert on the returned response.
+//!
+//! # Concurrency
+//!
+//! At most [`max_concurrent_tool_calls`](AirsSpecHandler::with_max_concurrent_tool_calls)
+//! `tools/call` requests execute at once; further calls wait for a free slot.
+//! Other methods, including `initialize` and `ping`, are never queued.
+//!
+//! # Resource Subscriptions
+//!
+//! `resources/subscribe` and `resources/unsubscribe` record which resource
+//! URIs this connection wants updates for.
+//! [`notify_spec_changes`](AirsSpecHandler::notify_spec_changes) turns the
+//! spec IDs reported by a [`SpecWatcher`](crate::storage::SpecWatcher) into
+//! `notifications/resources/updated` messages for the subscribed URIs.
+//!
+//! # Listing Order
+//!
+//! Every `*/list` response is sorted before it is returned (see
+//! [`ordering`](super::ordering)), so providers may store their items in
+//! any collection without making listings nondeterministic.
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
 use serde_json::Value;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::{Mutex, Semaphore};
 
 use airsprotocols_mcp::McpResult;
 use airsprotocols_mcp::protocol::constants::methods;
@@ -35,6 +62,13 @@ use airsprotocols_mcp::protocol::{
 };
 use airsprotocols_mcp::providers::{PromptProvider, ResourceProvider, ToolProvider};
 
+use airsspec_core::spec::SpecId;
+
+use super::ordering::sort_by_str_key;
+use super::subscriptions::{
+    RESOURCES_SUBSCRIBE, RESOURCES_UNSUBSCRIBE, RESOURCES_UPDATED, ResourceSubscriptions,
+};
+
 // ---------------------------------------------------------------------------
 // Stub providers (replaced by real implementations in Tasks 5.3-5.6)
 // ---------------------------------------------------------------------------
@@ -95,6 +129,9 @@ impl PromptProvider for StubPromptProvider {
 // AirsSpecHandler
 // ---------------------------------------------------------------------------
 
+/// Output stream shared by every message the handler sends.
+type SharedWriter = Arc<Mutex<Box<dyn AsyncWrite + Send + Unpin>>>;
+
 /// Central message handler for the `AirsSpec` MCP server.
 ///
 /// Routes incoming JSON-RPC messages to the appropriate provider
@@ -121,17 +158,29 @@ pub struct AirsSpecHandler {
     resource_provider: Arc<dyn ResourceProvider>,
     /// Prompt provider for `prompts/list` and `prompts/get`.
     prompt_provider: Arc<dyn PromptProvider>,
+    /// Maximum number of `tools/call` requests executing at once.
+    max_concurrent_tool_calls: usize,
+    /// Permits bounding concurrent `tools/call` execution.
+    tool_call_permits: Arc<Semaphore>,
+    /// Resource URIs this connection has subscribed to.
+    subscriptions: ResourceSubscriptions,
+    /// Stream all responses and notifications are written to.
+    output: SharedWriter,
 }
 
 impl std::fmt::Debug for AirsSpecHandler {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AirsSpecHandler")
             .field("server_info", &self.server_info)
+            .field("max_concurrent_tool_calls", &self.max_concurrent_tool_calls)
             .finish_non_exhaustive()
     }
 }
 
 impl AirsSpecHandler {
+    /// Default limit on concurrently executing tool calls.
+    pub const DEFAULT_MAX_CONCURRENT_TOOL_CALLS: usize = 64;
+
     /// Create a new handler with stub providers.
     ///
     /// The stub providers return empty lists and errors for all operations.
@@ -144,6 +193,10 @@ impl AirsSpecHandler {
             tool_provider: Arc::new(StubToolProvider),
             resource_provider: Arc::new(StubResourceProvider),
             prompt_provider: Arc::new(StubPromptProvider),
+            max_concurrent_tool_calls: Self::DEFAULT_MAX_CONCURRENT_TOOL_CALLS,
+            tool_call_permits: Arc::new(Semaphore::new(Self::DEFAULT_MAX_CONCURRENT_TOOL_CALLS)),
+            subscriptions: ResourceSubscriptions::default(),
+            output: Arc::new(Mutex::new(Box::new(tokio::io::stdout()))),
         }
     }
 
@@ -164,16 +217,49 @@ impl AirsSpecHandler {
             tool_provider,
             resource_provider,
             prompt_provider,
+            max_concurrent_tool_calls: Self::DEFAULT_MAX_CONCURRENT_TOOL_CALLS,
+            tool_call_permits: Arc::new(Semaphore::new(Self::DEFAULT_MAX_CONCURRENT_TOOL_CALLS)),
+            subscriptions: ResourceSubscriptions::default(),
+            output: Arc::new(Mutex::new(Box::new(tokio::io::stdout()))),
         }
     }
 
+    /// Replace the tool provider, keeping the other providers.
+    #[must_use]
+    pub fn with_tool_provider(mut self, tool_provider: Arc<dyn ToolProvider>) -> Self {
+        self.tool_provider = tool_provider;
+        self
+    }
+
+    /// Write responses and notifications to `writer` instead of stdout.
+    #[must_use]
+    pub fn with_output(mut self, writer: impl AsyncWrite + Send + Unpin + 'static) -> Self {
+        self.output = Arc::new(Mutex::new(Box::new(writer)));
+        self
+    }
+
+    /// Limit how many `tools/call` requests execute at once.
+    ///
+    /// Calls beyond the limit wait until a running call finishes. A limit of
+    /// zero is treated as one.
+    #[must_use]
+    pub fn with_max_concurrent_tool_calls(mut self, limit: usize) -> Self {
+        let limit = limit.max(1);
+        self.max_concurrent_tool_calls = limit;
+        self.tool_call_permits = Arc::new(Semaphore::new(limit));
+        self
+    }
+
     /// Build the default set of server capabilities.
     fn default_capabilities() -> ServerCapabilities {
         ServerCapabilities {
             experimental: Some(serde_json::json!({})),
             logging: Some(LoggingCapabilities {}),
             prompts: Some(PromptCapabilities::default()),
-            resources: Some(ResourceCapabilities::default()),
+            resources: Some(ResourceCapabilities {
+                subscribe: Some(true),
+                ..ResourceCapabilities::default()
+            }),
             tools: Some(ToolCapabilities::default()),
         }
     }
@@ -218,6 +304,8 @@ impl AirsSpecHandler {
             methods::RESOURCES_TEMPLATES_LIST => {
                 self.handle_resources_templates_list(request).await
             }
+            RESOURCES_SUBSCRIBE => self.handle_resources_subscribe(request),
+            RESOURCES_UNSUBSCRIBE => self.handle_resources_unsubscribe(request),
             methods::PROMPTS_LIST => self.handle_prompts_list(request).await,
             methods::PROMPTS_GET => self.handle_prompts_get(request).await,
             _ => JsonRpcResponse::method_not_found(&request.method, Some(request.id.clone())),
@@ -265,7 +353,8 @@ impl AirsSpecHandler {
 
     async fn handle_tools_list(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
         match self.tool_provider.list_tools().await {
-            Ok(tools) => {
+            Ok(mut tools) => {
+                sort_by_str_key(&mut tools, |tool| tool.name.as_str());
                 let result = ListToolsResult::new(tools);
                 Self::serialize_result(&result, request.id.clone(), "tools list")
             }
@@ -290,6 +379,15 @@ impl AirsSpecHandler {
             );
         };
 
+        // The semaphore is never closed, so acquiring only fails if that changes.
+        let Ok(_permit) = self.tool_call_permits.acquire().await else {
+            return JsonRpcResponse::internal_error(
+                "tool call limiter closed",
+                None,
+                Some(request.id.clone()),
+            );
+        };
+
         match self
             .tool_provider
             .call_tool(&call_request.name, call_request.arguments)
@@ -311,7 +409,8 @@ impl AirsSpecHandler {
 
     async fn handle_resources_list(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
         match self.resource_provider.list_resources().await {
-            Ok(resources) => {
+            Ok(mut resources) => {
+                sort_by_str_key(&mut resources, |resource| resource.uri.as_str());
                 let result = ListResourcesResult::new(resources);
                 Self::serialize_result(&result, request.id.clone(), "resources list")
             }
@@ -355,7 +454,8 @@ impl AirsSpecHandler {
 
     async fn handle_resources_templates_list(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
         match self.resource_provider.list_resource_templates().await {
-            Ok(templates) => {
+            Ok(mut templates) => {
+                sort_by_str_key(&mut templates, |template| template.name.as_str());
                 let result = ListResourceTemplatesResult::new(templates);
                 Self::serialize_result(&result, request.id.clone(), "resource templates list")
             }
@@ -367,11 +467,65 @@ impl AirsSpecHandler {
         }
     }
 
+    fn handle_resources_subscribe(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
+        let Some(uri) = Self::uri_param(request) else {
+            return JsonRpcResponse::invalid_params(
+                "invalid resources/subscribe params: expected {uri}",
+                None,
+                Some(request.id.clone()),
+            );
+        };
+
+        self.subscriptions.subscribe(uri);
+        JsonRpcResponse::success(serde_json::json!({}), request.id.clone())
+    }
+
+    fn handle_resources_unsubscribe(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
+        let Some(uri) = Self::uri_param(request) else {
+            return JsonRpcResponse::invalid_params(
+                "invalid resources/unsubscribe params: expected {uri}",
+                None,
+                Some(request.id.clone()),
+            );
+        };
+
+        self.subscriptions.unsubscribe(uri);
+        JsonRpcResponse::success(serde_json::json!({}), request.id.clone())
+    }
+
+    /// Extract the `uri` string parameter of a subscription request.
+    fn uri_param(request: &JsonRpcRequest) -> Option<&str> {
+        request
+            .params
+            .as_ref()
+            .and_then(|params| params.get("uri"))
+            .and_then(Value::as_str)
+    }
+
+    /// Build a `notifications/resources/updated` message for each changed
+    /// spec this connection has subscribed to.
+    ///
+    /// Returns the notifications without sending them -- does **not**
+    /// perform I/O.
+    pub(crate) fn resource_updated_notifications(
+        &self,
+        changed: &[SpecId],
+    ) -> Vec<JsonRpcNotification> {
+        self.subscriptions
+            .updated_uris(changed)
+            .into_iter()
+            .map(|uri| {
+                JsonRpcNotification::new(RESOURCES_UPDATED, Some(serde_json::json!({ "uri": uri })))
+            })
+            .collect()
+    }
+
     // -- Prompts ------------------------------------------------------------
 
     async fn handle_prompts_list(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
         match self.prompt_provider.list_prompts().await {
-            Ok(prompts) => {
+            Ok(mut prompts) => {
+                sort_by_str_key(&mut prompts, |prompt| prompt.name.as_str());
                 let result = ListPromptsResult::new(prompts);
                 Self::serialize_result(&result, request.id.clone(), "prompts list")
             }
@@ -417,32 +571,41 @@ impl AirsSpecHandler {
     // Layer 2: I/O -- writes to stdout
     // -----------------------------------------------------------------------
 
-    /// Write a JSON-RPC response directly to stdout.
+    /// Send `notifications/resources/updated` for each changed spec this
+    /// connection has subscribed to.
+    ///
+    /// Called with the IDs returned by
+    /// [`SpecWatcher::poll`](crate::storage::SpecWatcher::poll).
+    pub async fn notify_spec_changes(&self, changed: &[SpecId]) {
+        for notification in self.resource_updated_notifications(changed) {
+            self.send_message(JsonRpcMessage::Notification(notification))
+                .await;
+        }
+    }
+
+    /// Write a JSON-RPC message to the shared output stream.
     ///
     /// This is the only method that performs I/O. All other handler methods
-    /// are pure functions that return [`JsonRpcResponse`].
-    async fn send_response(&self, response: JsonRpcResponse) {
-        use tokio::io::AsyncWriteExt;
-
-        let message = JsonRpcMessage::Response(response);
-        match serde_json::to_string(&message) {
-            Ok(json) => {
-                let mut out = tokio::io::stdout();
-                if let Err(e) = out.write_all(json.as_bytes()).await {
-                    tracing::error!("Failed to write response to stdout: {e}");
-                    return;
-                }
-                if let Err(e) = out.write_all(b"\n").await {
-                    tracing::error!("Failed to write newline to stdout: {e}");
-                    return;
-                }
-                if let Err(e) = out.flush().await {
-                    tracing::error!("Failed to flush stdout: {e}");
-                }
-            }
+    /// are pure functions that return a [`JsonRpcResponse`] or
+    /// [`JsonRpcNotification`]. The message and its trailing newline are
+    /// written as one buffer while holding the output lock.
+    async fn send_message(&self, message: JsonRpcMessage) {
+        let mut line = match serde_json::to_vec(&message) {
+            Ok(line) => line,
             Err(e) => {
-                tracing::error!("Failed to serialize response: {e}");
+                tracing::error!("Failed to serialize message: {e}");
+                return;
             }
+        };
+        line.push(b'\n');
+
+        let mut out = self.output.lock().await;
+        if let Err(e) = out.write_all(&line).await {
+            tracing::error!("Failed to write message to stdout: {e}");
+            return;
+        }
+        if let Err(e) = out.flush().await {
+            tracing::error!("Failed to flush stdout: {e}");
         }
     }
 
@@ -475,7 +638,7 @@ impl MessageHandler<()> for AirsSpecHandler {
         match message {
             JsonRpcMessage::Request(request) => {
                 let response = self.route_request(&request).await;
-                self.send_response(response).await;
+                self.send_message(JsonRpcMessage::Response(response)).await;
             }
             JsonRpcMessage::Notification(ref notification) => {
                 Self::handle_notification(notification);
@@ -502,10 +665,35 @@ impl MessageHandler<()> for AirsSpecHandler {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
     use super::*;
     use airsprotocols_mcp::protocol::RequestId;
     use airsprotocols_mcp::protocol::constants::error_codes;
 
+    /// Tool provider that records the peak number of in-flight calls.
+    #[derive(Default)]
+    struct CountingToolProvider {
+        in_flight: AtomicUsize,
+        peak: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl ToolProvider for CountingToolProvider {
+        async fn list_tools(&self) -> McpResult<Vec<airsprotocols_mcp::protocol::Tool>> {
+            Ok(vec![])
+        }
+
+        async fn call_tool(&self, _name: &str, _arguments: Value) -> McpResult<Vec<Content>> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(vec![Content::text("done")])
+        }
+    }
+
     /// Create a handler for testing with default stub providers.
     fn test_handler() -> AirsSpecHandler {
         AirsSpecHandler::new(ServerInfo {
@@ -626,6 +814,45 @@ mod tests {
         assert!(!content.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_tool_calls_respect_concurrency_limit() {
+        let provider = Arc::new(CountingToolProvider::default());
+        let handler = Arc::new(
+            test_handler()
+                .with_tool_provider(Arc::clone(&provider) as Arc<dyn ToolProvider>)
+                .with_max_concurrent_tool_calls(2),
+        );
+
+        let mut calls = tokio::task::JoinSet::new();
+        for id in 0..8 {
+            let handler = Arc::clone(&handler);
+            calls.spawn(async move {
+                let params = serde_json::json!({ "name": "spec_list", "arguments": {} });
+                handler
+                    .route_request(&make_request("tools/call", id, Some(params)))
+                    .await
+            });
+        }
+        let responses = calls.join_all().await;
+
+        assert_eq!(responses.len(), 8);
+        for response in responses {
+            let result = response.result.expect("expected result");
+            assert_ne!(result["is_error"], true);
+        }
+        assert_eq!(provider.peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_ping_is_not_limited_by_busy_tool_calls() {
+        let handler = test_handler().with_max_concurrent_tool_calls(1);
+        let _held = handler.tool_call_permits.acquire().await.unwrap();
+
+        let response = handler.route_request(&make_request("ping", 13, None)).await;
+
+        assert!(response.error.is_none());
+    }
+
     #[tokio::test]
     async fn test_handle_ping_preserves_request_id() {
         let handler = test_handler();
@@ -685,4 +912,120 @@ mod tests {
         let error = response.error.expect("expected error");
         assert_eq!(error["code"], error_codes::INTERNAL_ERROR);
     }
+
+    #[tokio::test]
+    async fn test_initialize_advertises_resource_subscriptions() {
+        let handler = test_handler();
+        let request = make_request("initialize", 13, None);
+
+        let response = handler.route_request(&request).await;
+
+        let result = response.result.expect("expected result");
+        assert_eq!(result["capabilities"]["resources"]["subscribe"], true);
+    }
+
+    #[tokio::test]
+    async fn test_spec_change_notifies_subscribed_uri() {
+        let temp = tempfile::tempdir().unwrap();
+        let spec_file = temp.path().join("1737734400-auth.yaml");
+        std::fs::write(&spec_file, "before").unwrap();
+        std::fs::write(temp.path().join("1737734500-other.yaml"), "before").unwrap();
+        let mut watcher = crate::storage::SpecWatcher::new(temp.path());
+
+        let handler = test_handler();
+        let params = serde_json::json!({ "uri": "airsspec:///specs/1737734400-auth" });
+        let response = handler
+            .route_request(&make_request("resources/subscribe", 14, Some(params)))
+            .await;
+        assert_eq!(response.result, Some(serde_json::json!({})));
+
+        std::fs::write(&spec_file, "after the edit").unwrap();
+        std::fs::write(temp.path().join("1737734500-other.yaml"), "after the edit").unwrap();
+        let notifications = handler.resource_updated_notifications(&watcher.poll());
+
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].method, "notifications/resources/updated");
+        assert_eq!(
+            notifications[0].params,
+            Some(serde_json::json!({ "uri": "airsspec:///specs/1737734400-auth" }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_stops_notifications() {
+        let handler = test_handler();
+        let params = serde_json::json!({ "uri": "airsspec:///specs/1737734400-auth" });
+        handler
+            .route_request(&make_request(
+                "resources/subscribe",
+                15,
+                Some(params.clone()),
+            ))
+            .await;
+        let response = handler
+            .route_request(&make_request("resources/unsubscribe", 16, Some(params)))
+            .await;
+        assert!(response.error.is_none());
+
+        let changed = [SpecId::new(1_737_734_400, "auth")];
+        assert!(handler.resource_updated_notifications(&changed).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_without_uri_is_invalid_params() {
+        let handler = test_handler();
+        let request = make_request("resources/subscribe", 17, Some(serde_json::json!({})));
+
+        let response = handler.route_request(&request).await;
+
+        let error = response.error.expect("expected error");
+        assert_eq!(error["code"], error_codes::INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_messages_are_written_as_whole_lines() {
+        use tokio::io::AsyncReadExt;
+
+        // A tiny pipe forces every write to be split across many polls.
+        let (writer, mut reader) = tokio::io::duplex(8);
+        let handler = Arc::new(test_handler().with_output(writer));
+        let params = serde_json::json!({ "uri": "airsspec:///specs/1737734400-auth" });
+        handler
+            .route_request(&make_request("resources/subscribe", 18, Some(params)))
+            .await;
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for id in 0..16 {
+            let handler = Arc::clone(&handler);
+            tasks.spawn(async move {
+                let response = handler.route_request(&make_request("ping", id, None)).await;
+                handler
+                    .send_message(JsonRpcMessage::Response(response))
+                    .await;
+            });
+            let handler = Arc::clone(&handler);
+            tasks.spawn(async move {
+                handler
+                    .notify_spec_changes(&[SpecId::new(1_737_734_400, "auth")])
+                    .await;
+            });
+        }
+        let read = tokio::spawn(async move {
+            let mut output = String::new();
+            reader.read_to_string(&mut output).await.unwrap();
+            output
+        });
+        while let Some(joined) = tasks.join_next().await {
+            joined.unwrap();
+        }
+        drop(handler);
+
+        let output = read.await.unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 32);
+        for line in lines {
+            serde_json::from_str::<Value>(line)
+                .unwrap_or_else(|e| panic!("corrupted message {line:?}: {e}"));
+        }
+    }
 }
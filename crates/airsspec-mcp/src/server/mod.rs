@@ -8,10 +8,19 @@
 //! - [`McpServerBuilder`] -- Builder for constructing a fully configured
 //!   MCP server with stdio transport.
 //! - [`ServerError`] -- Error types for server operations.
+//!
+//! Resource subscriptions are tracked per connection by the private
+//! `subscriptions` module; the builder polls the specs directory and
+//! forwards changes to the handler.
+//!
+//! Provider listings are returned in a stable sorted order; see the
+//! private `ordering` module.
 
 mod builder;
 mod error;
 mod handler;
+pub(crate) mod ordering;
+mod subscriptions;
 
 pub use builder::McpServerBuilder;
 pub use error::ServerError;
@@ -0,0 +1,73 @@
+//! Deterministic ordering for provider listings.
+//!
+//! Providers are free to keep their tools, resources, and prompts in a
+//! `HashMap`, whose iteration order changes between runs. Clients cache
+//! listings and tests compare them, so the handler sorts every `*/list`
+//! result with [`sort_by_str_key`] before responding: tools, prompts, and
+//! resource templates by name, resources by URI. Providers with a fixed
+//! listing, such as the workspace tools, sort it the same way themselves so
+//! they list identically when called directly.
+
+/// Sorts `items` in place by the string returned from `key`.
+///
+/// The sort is stable, so items with equal keys keep the order the
+/// provider returned them in.
+pub(crate) fn sort_by_str_key<T, F>(items: &mut [T], key: F)
+where
+    F: Fn(&T) -> &str,
+{
+    items.sort_by(|a, b| key(a).cmp(key(b)));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Item {
+        name: String,
+        tag: u8,
+    }
+
+    fn item(name: &str, tag: u8) -> Item {
+        Item {
+            name: name.to_string(),
+            tag,
+        }
+    }
+
+    #[test]
+    fn test_sorts_hash_map_values_by_name() {
+        let map: HashMap<String, Item> = ["spec_update", "plan_create", "spec_create", "build_run"]
+            .into_iter()
+            .map(|name| (name.to_string(), item(name, 0)))
+            .collect();
+
+        let mut items: Vec<Item> = map.into_values().collect();
+        sort_by_str_key(&mut items, |i| i.name.as_str());
+
+        let names: Vec<&str> = items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["build_run", "plan_create", "spec_create", "spec_update"]
+        );
+    }
+
+    #[test]
+    fn test_sort_is_stable_for_equal_keys() {
+        let mut items = vec![item("b", 1), item("a", 2), item("b", 3)];
+        sort_by_str_key(&mut items, |i| i.name.as_str());
+
+        let tags: Vec<u8> = items.iter().map(|i| i.tag).collect();
+        assert_eq!(tags, vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn test_empty_slice_is_unchanged() {
+        let mut items: Vec<Item> = Vec::new();
+        sort_by_str_key(&mut items, |i| i.name.as_str());
+        assert!(items.is_empty());
+    }
+}
@@ -0,0 +1,92 @@
+//! Resource subscriptions for one client connection.
+//!
+//! Clients call `resources/subscribe` with a resource URI to be told when
+//! it changes. [`ResourceSubscriptions`] remembers those URIs so the
+//! handler can turn the spec IDs reported by
+//! [`SpecWatcher`](crate::storage::SpecWatcher) into
+//! `notifications/resources/updated` messages for subscribed URIs only.
+
+// Layer 1: Standard library
+use std::collections::BTreeSet;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::spec::SpecId;
+
+/// Method name for subscribing to a resource.
+pub(crate) const RESOURCES_SUBSCRIBE: &str = "resources/subscribe";
+
+/// Method name for cancelling a resource subscription.
+pub(crate) const RESOURCES_UNSUBSCRIBE: &str = "resources/unsubscribe";
+
+/// Notification sent when a subscribed resource changes.
+pub(crate) const RESOURCES_UPDATED: &str = "notifications/resources/updated";
+
+/// Returns the resource URI of the spec `id`, e.g.
+/// `airsspec:///specs/1737734400-user-auth`.
+pub(crate) fn spec_resource_uri(id: &SpecId) -> String {
+    format!("airsspec:///specs/{id}")
+}
+
+/// The resource URIs a client has subscribed to.
+#[derive(Debug, Default)]
+pub(crate) struct ResourceSubscriptions {
+    uris: Mutex<BTreeSet<String>>,
+}
+
+impl ResourceSubscriptions {
+    /// Subscribes to `uri`. Subscribing twice is the same as once.
+    pub(crate) fn subscribe(&self, uri: &str) {
+        self.lock().insert(uri.to_string());
+    }
+
+    /// Cancels the subscription to `uri`, returning whether there was one.
+    pub(crate) fn unsubscribe(&self, uri: &str) -> bool {
+        self.lock().remove(uri)
+    }
+
+    /// Returns the URIs of the `changed` specs that are subscribed to.
+    pub(crate) fn updated_uris(&self, changed: &[SpecId]) -> Vec<String> {
+        let uris = self.lock();
+        changed
+            .iter()
+            .map(spec_resource_uri)
+            .filter(|uri| uris.contains(uri))
+            .collect()
+    }
+
+    /// Locks the URI set. Poisoning is ignored: a panic while holding the
+    /// lock cannot leave the set inconsistent.
+    fn lock(&self) -> MutexGuard<'_, BTreeSet<String>> {
+        self.uris.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_subscribed_specs_are_reported() {
+        let subscriptions = ResourceSubscriptions::default();
+        let auth = SpecId::new(1_737_734_400, "auth");
+        let search = SpecId::new(1_737_734_500, "search");
+        subscriptions.subscribe(&spec_resource_uri(&auth));
+
+        let updated = subscriptions.updated_uris(&[auth, search]);
+
+        assert_eq!(updated, ["airsspec:///specs/1737734400-auth"]);
+    }
+
+    #[test]
+    fn test_unsubscribe_stops_reports() {
+        let subscriptions = ResourceSubscriptions::default();
+        let auth = SpecId::new(1_737_734_400, "auth");
+        let uri = spec_resource_uri(&auth);
+        subscriptions.subscribe(&uri);
+
+        assert!(subscriptions.unsubscribe(&uri));
+        assert!(!subscriptions.unsubscribe(&uri));
+        assert!(subscriptions.updated_uris(&[auth]).is_empty());
+    }
+}
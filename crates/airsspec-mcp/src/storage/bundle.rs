@@ -0,0 +1,146 @@
+//! # Export Bundle
+//!
+//! Packs every spec and plan of a workspace into a single zip archive for
+//! sharing a project snapshot. The archive mirrors the workspace layout:
+//!
+//! ```text
+//! specs/{spec-id}.yaml
+//! plans/{spec-id}.plan.yaml
+//! ```
+//!
+//! The archive is written with the [`zip`] crate. Entries are deflated and
+//! carry a fixed timestamp, so exporting the same workspace twice yields
+//! identical bytes; ZIP64 records are added when a bundle outgrows the
+//! classic format's limits.
+
+// Layer 1: Standard library
+use std::fs;
+use std::io::{Cursor, Write as _};
+use std::path::Path;
+
+// Layer 2: External crates
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, DateTime, ZipWriter};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::plan::{PlanError, PlanStorage};
+use airsspec_core::spec::{SpecError, SpecStorage};
+
+/// Writes all specs and plans into a zip archive at `out`.
+///
+/// Specs are stored under `specs/` and plans under `plans/`, each as the
+/// same YAML the filesystem storage writes. The file at `out` is replaced
+/// if it exists. Returns the number of files in the archive.
+///
+/// # Errors
+///
+/// - Any error from listing or loading specs
+/// - [`SpecError::Io`] if a plan cannot be listed or loaded, or the archive
+///   cannot be written
+/// - [`SpecError::InvalidFormat`] if a spec or plan cannot be serialized
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use airsspec_mcp::storage::{FileSystemPlanStorage, FileSystemSpecStorage, export_bundle};
+///
+/// # async fn example() -> Result<(), airsspec_core::spec::SpecError> {
+/// let specs = FileSystemSpecStorage::new("/path/to/.airsspec/specs");
+/// let plans = FileSystemPlanStorage::new("/path/to/.airsspec/specs");
+/// let count = export_bundle(&specs, &plans, Path::new("snapshot.zip")).await?;
+/// println!("bundled {count} files");
+/// # Ok(())
+/// # }
+/// ```
+pub async fn export_bundle(
+    storage: &impl SpecStorage,
+    plan_storage: &impl PlanStorage,
+    out: &Path,
+) -> Result<usize, SpecError> {
+    let mut archive = Bundle::new();
+
+    for id in storage.list_specs().await? {
+        let spec = storage.load_spec(&id).await?;
+        let yaml = serde_yaml::to_string(&spec).map_err(|err| {
+            SpecError::InvalidFormat(format!("failed to serialize spec '{id}' to YAML: {err}"))
+        })?;
+        archive.add(&format!("specs/{id}.yaml"), yaml.as_bytes())?;
+    }
+
+    for id in plan_storage
+        .list_plans()
+        .await
+        .map_err(|err| plan_error(&err))?
+    {
+        let plan = plan_storage
+            .load_plan(&id)
+            .await
+            .map_err(|err| plan_error(&err))?;
+        let yaml = serde_yaml::to_string(&plan).map_err(|err| {
+            SpecError::InvalidFormat(format!("failed to serialize plan '{id}' to YAML: {err}"))
+        })?;
+        archive.add(&format!("plans/{id}.plan.yaml"), yaml.as_bytes())?;
+    }
+
+    let count = archive.entries;
+    let bytes = archive.finish()?;
+    fs::write(out, bytes).map_err(|err| {
+        SpecError::Io(format!("failed to write bundle '{}': {err}", out.display()))
+    })?;
+    Ok(count)
+}
+
+/// Reports a failure to read plans as a spec I/O error.
+fn plan_error(err: &PlanError) -> SpecError {
+    SpecError::Io(format!("failed to export plan: {err}"))
+}
+
+/// In-memory zip archive the bundle is assembled in before it is written.
+struct Bundle {
+    /// Archive being written.
+    writer: ZipWriter<Cursor<Vec<u8>>>,
+    /// Number of entries added.
+    entries: usize,
+}
+
+impl Bundle {
+    fn new() -> Self {
+        Self {
+            writer: ZipWriter::new(Cursor::new(Vec::new())),
+            entries: 0,
+        }
+    }
+
+    /// Appends the file `name` with `data`.
+    fn add(&mut self, name: &str, data: &[u8]) -> Result<(), SpecError> {
+        // Entries of 4 GiB or more need ZIP64 headers, which must be
+        // requested before the data is written.
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .last_modified_time(DateTime::default())
+            .large_file(u32::try_from(data.len()).is_err());
+        self.writer
+            .start_file(name, options)
+            .map_err(|err| bundle_error(name, &err))?;
+        self.writer
+            .write_all(data)
+            .map_err(|err| bundle_error(name, &err))?;
+        self.entries += 1;
+        Ok(())
+    }
+
+    /// Returns the complete archive bytes.
+    fn finish(self) -> Result<Vec<u8>, SpecError> {
+        self.writer
+            .finish()
+            .map(Cursor::into_inner)
+            .map_err(|err| bundle_error("archive", &err))
+    }
+}
+
+/// Reports a failure to add `what` to the archive as a spec I/O error.
+fn bundle_error(what: &str, err: &impl std::fmt::Display) -> SpecError {
+    SpecError::Io(format!("failed to write bundle {what}: {err}"))
+}
@@ -0,0 +1,120 @@
+//! # Object-Safe Spec Storage
+//!
+//! [`SpecStorage`] returns `impl Future`, which keeps `airsspec-core` free of
+//! boxing and async runtimes but makes the trait unusable as `dyn
+//! SpecStorage`. [`DynSpecStorage`] mirrors it with boxed futures (via
+//! `async_trait`) and is implemented for every `SpecStorage`, so I/O-layer
+//! code that needs type erasure -- such as MCP providers -- can hold an
+//! `Arc<dyn DynSpecStorage>`.
+//!
+//! Core code keeps using `SpecStorage` generically; this adapter exists only
+//! at the I/O boundary.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use airsspec_mcp::FileSystemSpecStorage;
+//! use airsspec_mcp::storage::DynSpecStorage;
+//!
+//! let storage: Arc<dyn DynSpecStorage> =
+//!     Arc::new(FileSystemSpecStorage::new("/path/to/.airsspec/specs"));
+//! ```
+
+// Layer 2: External crates
+use async_trait::async_trait;
+
+// Layer 3: Internal crates/modules
+use airsspec_core::spec::{Spec, SpecError, SpecId, SpecStorage};
+
+/// Object-safe counterpart of [`SpecStorage`].
+///
+/// Implemented for every [`SpecStorage`]; see the module documentation.
+#[async_trait]
+pub trait DynSpecStorage: Send + Sync {
+    /// Loads a specification by its ID. See [`SpecStorage::load_spec`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `SpecError::NotFound` if the spec does not exist.
+    async fn load_spec(&self, id: &SpecId) -> Result<Spec, SpecError>;
+
+    /// Saves a specification. See [`SpecStorage::save_spec`].
+    ///
+    /// # Errors
+    ///
+    /// May return `SpecError` for I/O failures.
+    async fn save_spec(&self, spec: &Spec) -> Result<(), SpecError>;
+
+    /// Lists all specification IDs. See [`SpecStorage::list_specs`].
+    ///
+    /// # Errors
+    ///
+    /// May return `SpecError` for I/O failures.
+    async fn list_specs(&self) -> Result<Vec<SpecId>, SpecError>;
+
+    /// Deletes a specification. See [`SpecStorage::delete_spec`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `SpecError::NotFound` if the spec does not exist.
+    async fn delete_spec(&self, id: &SpecId) -> Result<(), SpecError>;
+}
+
+#[async_trait]
+impl<T: SpecStorage> DynSpecStorage for T {
+    async fn load_spec(&self, id: &SpecId) -> Result<Spec, SpecError> {
+        SpecStorage::load_spec(self, id).await
+    }
+
+    async fn save_spec(&self, spec: &Spec) -> Result<(), SpecError> {
+        SpecStorage::save_spec(self, spec).await
+    }
+
+    async fn list_specs(&self) -> Result<Vec<SpecId>, SpecError> {
+        SpecStorage::list_specs(self).await
+    }
+
+    async fn delete_spec(&self, id: &SpecId) -> Result<(), SpecError> {
+        SpecStorage::delete_spec(self, id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use airsspec_core::spec::SpecMetadata;
+    use tempfile::TempDir;
+
+    use crate::storage::FileSystemSpecStorage;
+    use crate::test_support::block_on;
+
+    #[test]
+    fn test_filesystem_storage_behind_trait_object() {
+        let temp = TempDir::new().unwrap();
+        let storage: Arc<dyn DynSpecStorage> = Arc::new(FileSystemSpecStorage::new(temp.path()));
+        let spec = Spec::new(
+            SpecId::new(1_737_734_400, "dyn-storage"),
+            SpecMetadata::new("Dyn Storage", "Saved through a trait object"),
+            "# Dyn Storage",
+        );
+
+        block_on(storage.save_spec(&spec)).unwrap();
+        let loaded = block_on(storage.load_spec(spec.id())).unwrap();
+
+        assert_eq!(loaded, spec);
+        assert_eq!(
+            block_on(storage.list_specs()).unwrap(),
+            vec![spec.id().clone()]
+        );
+        block_on(storage.delete_spec(spec.id())).unwrap();
+        assert!(matches!(
+            block_on(storage.load_spec(spec.id())),
+            Err(SpecError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+//! # Spec Content Includes
+//!
+//! Resolves `{{include: path}}` directives in spec content by inlining the
+//! referenced files, so specs can share common snippets.
+//!
+//! ## Resolution
+//!
+//! - Paths in the spec's content are relative to the `base_dir` given to
+//!   [`SpecIncludeExt::resolve_includes`]
+//! - Paths in an included file are relative to the directory of the file it
+//!   resolves to, so a symlinked snippet resolves its own includes next to
+//!   its target rather than next to the link
+//! - Every included file must resolve, after following `..` and symlinks,
+//!   to a file inside `base_dir`; absolute paths and escapes are rejected
+//! - Included files may include others; a file that (transitively) includes
+//!   itself is rejected
+//! - An opening `{{include:` with no closing `}}` is left as literal text
+
+// Layer 1: Standard library
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::spec::{Spec, SpecError};
+
+/// Opening marker of an include directive.
+const INCLUDE_OPEN: &str = "{{include:";
+
+/// Closing marker of an include directive.
+const INCLUDE_CLOSE: &str = "}}";
+
+/// Extension trait resolving include directives in a spec's content.
+///
+/// Lives here rather than on [`Spec`] itself because resolving includes
+/// reads files, and `airsspec-core` does no I/O.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+/// use airsspec_mcp::storage::SpecIncludeExt as _;
+///
+/// let spec = Spec::new(
+///     SpecId::new(1_737_734_400, "auth"),
+///     SpecMetadata::new("Auth", "Login flow"),
+///     "# Auth\n\n{{include: shared/security.md}}",
+/// );
+/// let content = spec.resolve_includes(Path::new("/my/project/.airsspec/specs"))?;
+/// # Ok::<(), airsspec_core::spec::SpecError>(())
+/// ```
+pub trait SpecIncludeExt {
+    /// Returns the content with every include directive replaced by the
+    /// (recursively resolved) contents of the file it names.
+    ///
+    /// # Errors
+    ///
+    /// - [`SpecError::IncludeNotFound`] with the path of a missing file
+    /// - [`SpecError::CircularInclude`] naming the cycle if includes loop
+    /// - [`SpecError::IncludeOutsideBase`] if a path resolves outside
+    ///   `base_dir`
+    /// - [`SpecError::Io`] if an included file exists but cannot be read
+    fn resolve_includes(&self, base_dir: &Path) -> Result<String, SpecError>;
+}
+
+impl SpecIncludeExt for Spec {
+    fn resolve_includes(&self, base_dir: &Path) -> Result<String, SpecError> {
+        let root = fs::canonicalize(base_dir).unwrap_or_else(|_| base_dir.to_path_buf());
+        expand(self.content(), base_dir, &root, &mut Vec::new())
+    }
+}
+
+/// Expands the directives in `content`, resolving paths against `dir`.
+///
+/// `root` is the canonical base directory every include must stay inside;
+/// `stack` holds the canonical paths of the files currently being expanded.
+fn expand(
+    content: &str,
+    dir: &Path,
+    root: &Path,
+    stack: &mut Vec<PathBuf>,
+) -> Result<String, SpecError> {
+    let mut expanded = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find(INCLUDE_OPEN) {
+        let after_open = &rest[start + INCLUDE_OPEN.len()..];
+        let Some(end) = after_open.find(INCLUDE_CLOSE) else {
+            break;
+        };
+        expanded.push_str(&rest[..start]);
+        expanded.push_str(&include(after_open[..end].trim(), dir, root, stack)?);
+        rest = &after_open[end + INCLUDE_CLOSE.len()..];
+    }
+
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+/// Reads and expands the file `target` names relative to `dir`.
+fn include(
+    target: &str,
+    dir: &Path,
+    root: &Path,
+    stack: &mut Vec<PathBuf>,
+) -> Result<String, SpecError> {
+    let path = dir.join(target);
+    let canonical = match fs::canonicalize(&path) {
+        Ok(canonical) => canonical,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(SpecError::IncludeNotFound(path.display().to_string()));
+        }
+        Err(err) => return Err(SpecError::Io(format!("{}: {err}", path.display()))),
+    };
+    if !canonical.starts_with(root) {
+        return Err(SpecError::IncludeOutsideBase(target.to_string()));
+    }
+
+    if let Some(start) = stack.iter().position(|open| *open == canonical) {
+        let cycle: Vec<String> = stack[start..]
+            .iter()
+            .chain([&canonical])
+            .map(|path| path.display().to_string())
+            .collect();
+        return Err(SpecError::CircularInclude(cycle.join(" -> ")));
+    }
+
+    let content = fs::read_to_string(&canonical)
+        .map_err(|err| SpecError::Io(format!("{}: {err}", path.display())))?;
+    let file_dir = canonical.parent().unwrap_or(root).to_path_buf();
+    stack.push(canonical);
+    let expanded = expand(&content, &file_dir, root, stack)?;
+    stack.pop();
+    Ok(expanded)
+}
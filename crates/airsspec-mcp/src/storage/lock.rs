@@ -0,0 +1,420 @@
+//! # Workspace Lock
+//!
+//! An advisory lock file at `.airsspec/.lock` that keeps two `airsspec`
+//! processes from mutating the same workspace at once. The file holds the
+//! owning process ID and is removed when the [`WorkspaceLock`] is dropped.
+//!
+//! The lock file is written in full under a temporary name and then
+//! hard-linked into place, so it never exists without its owner's ID. A
+//! lock file that cannot be read or holds no process ID is treated as held.
+//!
+//! A lock whose owner is no longer running (e.g. after a crash) is stale and
+//! is taken over on the next acquisition. Takeovers are serialized by a
+//! second lock file, `.airsspec/.lock.takeover`, so two processes can never
+//! both replace the same stale lock. A takeover file left behind by a
+//! process that died mid-takeover is stale in the same way and is cleared
+//! before the takeover proceeds.
+
+// Layer 1: Standard library
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::workspace::WorkspaceError;
+
+/// Name of the lock file within the workspace directory.
+const LOCK_FILE: &str = ".lock";
+
+/// Name of the file held while taking over a stale lock.
+const TAKEOVER_FILE: &str = ".lock.takeover";
+
+/// Distinguishes the temporary files of concurrent acquisitions within one
+/// process.
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Held lock on a workspace; released when dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use airsspec_mcp::storage::WorkspaceLock;
+///
+/// let _lock = WorkspaceLock::acquire(Path::new("/my/project"))?;
+/// // ... mutate the workspace ...
+/// # Ok::<(), airsspec_core::workspace::WorkspaceError>(())
+/// ```
+#[derive(Debug)]
+pub struct WorkspaceLock {
+    path: PathBuf,
+}
+
+impl WorkspaceLock {
+    /// Acquires the lock for the workspace rooted at `root`.
+    ///
+    /// If the lock file names a process that is no longer running, the
+    /// stale lock is taken over.
+    ///
+    /// # Errors
+    ///
+    /// - [`WorkspaceError::Locked`] if another live process holds the lock,
+    ///   or is taking over a stale one
+    /// - [`WorkspaceError::LockedByUnknown`] if the lock file exists but
+    ///   does not hold a process ID
+    /// - [`WorkspaceError::NotFound`] if `root` has no `.airsspec` directory
+    /// - [`WorkspaceError::Io`] if the lock file cannot be created
+    pub fn acquire(root: &Path) -> Result<Self, WorkspaceError> {
+        let workspace_dir = root.join(".airsspec");
+        if !workspace_dir.is_dir() {
+            return Err(WorkspaceError::NotFound(root.to_path_buf()));
+        }
+        let path = workspace_dir.join(LOCK_FILE);
+
+        match create_lock_file(&path) {
+            Ok(()) => return Ok(Self { path }),
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        let stale_owner = stale_owner_of(&path)?;
+        let takeover = workspace_dir.join(TAKEOVER_FILE);
+        acquire_takeover(&takeover)?;
+        let result = take_over(path, stale_owner);
+        if let Err(err) = fs::remove_file(&takeover) {
+            tracing::warn!("Failed to remove {}: {err}", takeover.display());
+        }
+        result
+    }
+
+    /// Returns the path of the lock file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for WorkspaceLock {
+    fn drop(&mut self) {
+        if let Err(err) = fs::remove_file(&self.path) {
+            tracing::warn!(
+                "Failed to release workspace lock {}: {err}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+/// Returns the owner of the existing lock at `path` if it is stale, or the
+/// error to report if the lock is held.
+fn stale_owner_of(path: &Path) -> Result<u32, WorkspaceError> {
+    match read_owner(path) {
+        Some(pid) if is_process_alive(pid) => Err(WorkspaceError::Locked(pid)),
+        Some(pid) => Ok(pid),
+        None => Err(WorkspaceError::LockedByUnknown(path.to_path_buf())),
+    }
+}
+
+/// Creates the takeover file at `takeover`, first clearing one whose owner
+/// is no longer running.
+fn acquire_takeover(takeover: &Path) -> Result<(), WorkspaceError> {
+    match create_lock_file(takeover) {
+        Ok(()) => return Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    let stale_owner = stale_owner_of(takeover)?;
+    remove_stale(takeover, stale_owner)?;
+    match create_lock_file(takeover) {
+        Ok(()) => Ok(()),
+        // Another process cleared the stale file too and got there first.
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Err(held_error(takeover)),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Removes the file at `path`, last seen owned by the dead process
+/// `stale_owner`.
+///
+/// The file is first renamed aside, so that only one process can claim it,
+/// and its owner is checked again. If it was replaced by a live owner in the
+/// meantime it is put back and reported as held.
+fn remove_stale(path: &Path, stale_owner: u32) -> Result<(), WorkspaceError> {
+    let aside = unique_sibling(path, "stale");
+    match fs::rename(path, &aside) {
+        Ok(()) => {}
+        // Already cleared by another process.
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err.into()),
+    }
+
+    let owner = read_owner(&aside);
+    if owner != Some(stale_owner) {
+        let restored = fs::hard_link(&aside, path);
+        if let Err(err) = fs::remove_file(&aside) {
+            tracing::warn!("Failed to remove {}: {err}", aside.display());
+        }
+        if let Err(err) = restored {
+            tracing::warn!("Failed to restore {}: {err}", path.display());
+        }
+        return Err(owner.map_or_else(
+            || WorkspaceError::LockedByUnknown(path.to_path_buf()),
+            WorkspaceError::Locked,
+        ));
+    }
+
+    tracing::warn!(
+        "Removing stale {} (owner {stale_owner} is not running)",
+        path.display()
+    );
+    fs::remove_file(&aside)?;
+    Ok(())
+}
+
+/// Replaces the lock at `path`, last seen owned by the dead process
+/// `stale_owner`. Must only be called while holding the takeover file.
+fn take_over(path: PathBuf, stale_owner: u32) -> Result<WorkspaceLock, WorkspaceError> {
+    // The lock may have been released and re-acquired since it was read.
+    match read_owner(&path) {
+        Some(pid) if pid == stale_owner => {}
+        Some(pid) => return Err(WorkspaceError::Locked(pid)),
+        None if path.exists() => return Err(WorkspaceError::LockedByUnknown(path)),
+        None => {}
+    }
+
+    tracing::warn!(
+        "Taking over stale workspace lock {} (owner {stale_owner} is not running)",
+        path.display()
+    );
+    match fs::remove_file(&path) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+    match create_lock_file(&path) {
+        Ok(()) => Ok(WorkspaceLock { path }),
+        // A plain acquisition won the race for the freed lock.
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Err(held_error(&path)),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Returns the error reporting the existing lock file at `path` as held.
+fn held_error(path: &Path) -> WorkspaceError {
+    read_owner(path).map_or_else(
+        || WorkspaceError::LockedByUnknown(path.to_path_buf()),
+        WorkspaceError::Locked,
+    )
+}
+
+/// Atomically creates the lock file at `path` holding this process's ID.
+///
+/// The ID is written to a temporary file that is then hard-linked to
+/// `path`, which fails with [`io::ErrorKind::AlreadyExists`] if `path`
+/// exists. Readers therefore never see a partly written lock.
+fn create_lock_file(path: &Path) -> io::Result<()> {
+    let pid = std::process::id();
+    let temp = unique_sibling(path, "tmp");
+    fs::write(&temp, format!("{pid}\n"))?;
+    let linked = fs::hard_link(&temp, path);
+    if let Err(err) = fs::remove_file(&temp) {
+        tracing::warn!("Failed to remove {}: {err}", temp.display());
+    }
+    linked
+}
+
+/// Returns a path next to `path` that no other acquisition uses, ending in
+/// `.{extension}`.
+fn unique_sibling(path: &Path, extension: &str) -> PathBuf {
+    path.with_file_name(format!(
+        "{}.{}.{}.{extension}",
+        path.file_name().unwrap_or_default().to_string_lossy(),
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+/// Reads the owning process ID from a lock file, if it holds one.
+fn read_owner(path: &Path) -> Option<u32> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Returns `true` if a process with `pid` is running.
+#[cfg(target_os = "linux")]
+fn is_process_alive(pid: u32) -> bool {
+    Path::new("/proc").join(pid.to_string()).exists()
+}
+
+/// Returns `true` if a process with `pid` is running.
+#[cfg(all(unix, not(target_os = "linux")))]
+fn is_process_alive(pid: u32) -> bool {
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Returns `true` if a process with `pid` is running.
+///
+/// Liveness cannot be checked portably here, so the lock is assumed held.
+#[cfg(not(unix))]
+fn is_process_alive(_pid: u32) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    fn workspace() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join(".airsspec")).unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_acquire_writes_pid_and_releases_on_drop() {
+        let temp = workspace();
+
+        let lock = WorkspaceLock::acquire(temp.path()).unwrap();
+        let path = lock.path().to_path_buf();
+
+        assert_eq!(path, temp.path().join(".airsspec/.lock"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap().trim(),
+            std::process::id().to_string()
+        );
+        drop(lock);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_second_acquire_is_blocked() {
+        let temp = workspace();
+        let _lock = WorkspaceLock::acquire(temp.path()).unwrap();
+
+        let result = WorkspaceLock::acquire(temp.path());
+
+        assert_eq!(
+            result.unwrap_err(),
+            WorkspaceError::Locked(std::process::id())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stale_lock_is_taken_over() {
+        let temp = workspace();
+        let path = temp.path().join(".airsspec/.lock");
+        // No process can have this ID: Linux caps PIDs at 2^22.
+        fs::write(&path, format!("{}\n", u32::MAX)).unwrap();
+
+        let lock = WorkspaceLock::acquire(temp.path()).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(lock.path()).unwrap().trim(),
+            std::process::id().to_string()
+        );
+    }
+
+    #[test]
+    fn test_empty_lock_is_held() {
+        let temp = workspace();
+        let path = temp.path().join(".airsspec/.lock");
+        fs::write(&path, "").unwrap();
+
+        let result = WorkspaceLock::acquire(temp.path());
+
+        assert_eq!(
+            result.unwrap_err(),
+            WorkspaceError::LockedByUnknown(path.clone())
+        );
+        assert!(path.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_stale_lock_not_taken_over_during_another_takeover() {
+        let temp = workspace();
+        let path = temp.path().join(".airsspec/.lock");
+        fs::write(&path, format!("{}\n", u32::MAX)).unwrap();
+        let pid = std::process::id();
+        fs::write(
+            temp.path().join(".airsspec/.lock.takeover"),
+            format!("{pid}\n"),
+        )
+        .unwrap();
+
+        let result = WorkspaceLock::acquire(temp.path());
+
+        assert_eq!(result.unwrap_err(), WorkspaceError::Locked(pid));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap().trim(),
+            u32::MAX.to_string()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_takeover_left_by_dead_process_is_cleared() {
+        let temp = workspace();
+        let path = temp.path().join(".airsspec/.lock");
+        fs::write(&path, format!("{}\n", u32::MAX)).unwrap();
+        fs::write(
+            temp.path().join(".airsspec/.lock.takeover"),
+            format!("{}\n", u32::MAX - 1),
+        )
+        .unwrap();
+
+        let lock = WorkspaceLock::acquire(temp.path()).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(lock.path()).unwrap().trim(),
+            std::process::id().to_string()
+        );
+        let leftovers: Vec<_> = fs::read_dir(temp.path().join(".airsspec"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(leftovers, [".lock"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_concurrent_acquires_have_one_winner() {
+        let temp = workspace();
+        let path = temp.path().join(".airsspec/.lock");
+        fs::write(&path, format!("{}\n", u32::MAX)).unwrap();
+
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| scope.spawn(|| WorkspaceLock::acquire(temp.path())))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+
+        assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+        let leftovers: Vec<_> = fs::read_dir(temp.path().join(".airsspec"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(leftovers, [".lock"]);
+    }
+
+    #[test]
+    fn test_acquire_requires_workspace() {
+        let temp = TempDir::new().unwrap();
+
+        let result = WorkspaceLock::acquire(temp.path());
+
+        assert!(matches!(result, Err(WorkspaceError::NotFound(_))));
+    }
+}
@@ -11,11 +11,32 @@
 //! - [`FileSystemWorkspaceProvider`] - Discovers and initializes workspaces on the filesystem
 //! - [`FileSystemSpecStorage`] - Reads and writes spec YAML files
 //! - [`FileSystemPlanStorage`] - Reads and writes plan YAML files
+//! - [`export_bundle`] - Packs all specs and plans into a zip archive
+//! - [`rename_spec`] - Renames a spec's slug and updates its dependents and plan
+//! - [`DynSpecStorage`] - Object-safe adapter for any `SpecStorage`
+//! - [`WorkspaceLock`] - Lock file guarding a workspace against concurrent mutation
+//! - [`TemplateRegistry`] - Loads per-category spec templates from the workspace
+//! - [`SpecIncludeExt`] - Inlines `{{include: path}}` directives in spec content
+//! - [`SpecWatcher`] - Polls the specs directory for changed spec files
 
+mod bundle;
+mod dyn_spec;
+mod include;
+mod lock;
 mod plan;
+mod rename;
 mod spec;
+mod template;
+mod watch;
 mod workspace;
 
+pub use bundle::export_bundle;
+pub use dyn_spec::DynSpecStorage;
+pub use include::SpecIncludeExt;
+pub use lock::WorkspaceLock;
 pub use plan::FileSystemPlanStorage;
+pub use rename::rename_spec;
 pub use spec::FileSystemSpecStorage;
+pub use template::TemplateRegistry;
+pub use watch::SpecWatcher;
 pub use workspace::FileSystemWorkspaceProvider;
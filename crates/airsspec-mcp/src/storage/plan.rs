@@ -81,7 +81,7 @@ impl FileSystemPlanStorage {
     }
 
     /// Builds the file path for a plan with the given spec ID.
-    fn plan_path(&self, spec_id: &SpecId) -> PathBuf {
+    pub(crate) fn plan_path(&self, spec_id: &SpecId) -> PathBuf {
         self.specs_dir
             .join(format!("{}.plan.yaml", spec_id.as_str()))
     }
@@ -190,29 +190,10 @@ impl PlanStorage for FileSystemPlanStorage {
 mod tests {
     use super::*;
 
-    use std::pin::pin;
-    use std::sync::Arc;
-    use std::task::{Context, Poll, Wake, Waker};
-
     use airsspec_core::plan::PlanStep;
     use tempfile::TempDir;
 
-    /// Simple single-threaded executor for testing immediately-ready futures.
-    fn block_on<F: Future>(f: F) -> F::Output {
-        struct NoopWaker;
-        impl Wake for NoopWaker {
-            fn wake(self: Arc<Self>) {}
-        }
-
-        let waker = Waker::from(Arc::new(NoopWaker));
-        let mut cx = Context::from_waker(&waker);
-        let mut f = pin!(f);
-
-        match f.as_mut().poll(&mut cx) {
-            Poll::Ready(result) => result,
-            Poll::Pending => panic!("block_on: unexpected Pending from immediately-ready future"),
-        }
-    }
+    use crate::test_support::block_on;
 
     /// Creates a test plan with the given spec ID components.
     fn test_plan(timestamp: i64, slug: &str) -> Plan {
@@ -249,6 +230,22 @@ mod tests {
         assert_eq!(loaded.steps()[1].title(), plan.steps()[1].title());
     }
 
+    #[test]
+    fn test_step_tags_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemPlanStorage::new(temp.path());
+        let mut plan = test_plan(1_737_734_400, "tagged");
+        plan.step_mut(0).unwrap().add_tag("db");
+        plan.step_mut(0).unwrap().add_tag("test");
+
+        block_on(storage.save_plan(&plan)).unwrap();
+        let loaded = block_on(storage.load_plan(plan.spec_id())).unwrap();
+
+        assert_eq!(loaded.steps()[0].tags(), &["db", "test"]);
+        assert!(loaded.steps()[1].tags().is_empty());
+        assert_eq!(loaded.steps_with_tag("db").len(), 1);
+    }
+
     #[test]
     fn test_load_not_found() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,211 @@
+//! # Spec Rename
+//!
+//! Changes the slug of a stored spec while keeping its timestamp, and
+//! carries everything that refers to it along: the spec file itself, the
+//! `spec_id` of every dependency pointing at it, and its plan file.
+
+// Layer 3: Internal crates/modules
+use airsspec_core::plan::{PlanError, PlanStorage as _};
+use airsspec_core::spec::{IdGranularity, Spec, SpecError, SpecId, SpecStorage as _};
+
+use super::{FileSystemPlanStorage, FileSystemSpecStorage};
+
+/// Renames the spec `old` to `{timestamp}-{new_slug}` and returns the new ID.
+///
+/// The timestamp is copied from `old` at its own granularity, so a
+/// millisecond ID keeps its milliseconds.
+///
+/// The spec is rewritten under its new ID with its metadata and content
+/// unchanged, every other spec depending on `old` is updated to point at
+/// the new ID, and the plan file (if any) is moved alongside. The old spec
+/// file is removed last, so a failure part-way leaves the original in place.
+/// Renaming to the current slug is a no-op.
+///
+/// # Errors
+///
+/// - [`SpecError::InvalidId`] if `new_slug` is not a valid slug
+/// - [`SpecError::AlreadyExists`] if a spec with the new ID already exists
+/// - [`SpecError::NotFound`] if `old` does not exist
+/// - [`SpecError::Io`] or [`SpecError::InvalidFormat`] if a spec or the plan
+///   cannot be read or written
+///
+/// # Examples
+///
+/// ```no_run
+/// use airsspec_core::spec::SpecId;
+/// use airsspec_mcp::storage::{FileSystemSpecStorage, rename_spec};
+///
+/// # async fn example() -> Result<(), airsspec_core::spec::SpecError> {
+/// let storage = FileSystemSpecStorage::new("/path/to/.airsspec/specs");
+/// let old = SpecId::new(1_737_734_400, "user-auth");
+/// let new = rename_spec(&storage, &old, "oauth-login").await?;
+/// assert_eq!(new.as_str(), "1737734400-oauth-login");
+/// # Ok(())
+/// # }
+/// ```
+pub async fn rename_spec(
+    storage: &FileSystemSpecStorage,
+    old: &SpecId,
+    new_slug: &str,
+) -> Result<SpecId, SpecError> {
+    let timestamp = match old.granularity() {
+        IdGranularity::Seconds => old.timestamp(),
+        IdGranularity::Millis => old.timestamp_millis(),
+    };
+    let new = SpecId::try_new(timestamp, new_slug)?;
+    if &new == old {
+        return Ok(new);
+    }
+    if storage.spec_path(&new).exists() {
+        return Err(SpecError::AlreadyExists(new.as_str().to_string()));
+    }
+
+    let spec = storage.load_spec(old).await?;
+    let mut renamed = Spec::new(new.clone(), spec.metadata().clone(), spec.content());
+    retarget_dependencies(&mut renamed, old, &new);
+    storage.save_spec(&renamed).await?;
+
+    for id in storage.list_specs().await? {
+        if &id == old || id == new {
+            continue;
+        }
+        let mut dependent = storage.load_spec(&id).await?;
+        if retarget_dependencies(&mut dependent, old, &new) {
+            storage.save_spec(&dependent).await?;
+        }
+    }
+
+    let plans = FileSystemPlanStorage::new(storage.specs_dir());
+    match plans.load_plan(old).await {
+        Ok(mut plan) => {
+            plan.set_spec_id(new.clone());
+            plans
+                .save_plan(&plan)
+                .await
+                .map_err(|err| plan_error(&err))?;
+            plans
+                .delete_plan(old)
+                .await
+                .map_err(|err| plan_error(&err))?;
+        }
+        Err(PlanError::NotFound(_)) => {}
+        Err(err) => return Err(plan_error(&err)),
+    }
+
+    storage.delete_spec(old).await?;
+    Ok(new)
+}
+
+/// Points every dependency of `spec` on `old` at `new` instead.
+///
+/// Returns `true` if any dependency changed.
+fn retarget_dependencies(spec: &mut Spec, old: &SpecId, new: &SpecId) -> bool {
+    if !spec.dependencies().iter().any(|dep| &dep.spec_id == old) {
+        return false;
+    }
+    let dependencies = spec
+        .dependencies()
+        .iter()
+        .cloned()
+        .map(|mut dep| {
+            if &dep.spec_id == old {
+                dep.spec_id = new.clone();
+            }
+            dep
+        })
+        .collect();
+    spec.metadata_mut().set_dependencies(dependencies);
+    true
+}
+
+/// Reports a failure to move the plan as a spec I/O error.
+fn plan_error(err: &PlanError) -> SpecError {
+    SpecError::Io(format!("failed to move plan: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use airsspec_core::plan::{Plan, PlanStep};
+    use airsspec_core::spec::{Dependency, SpecMetadata};
+
+    use crate::test_support::block_on;
+
+    use super::*;
+
+    fn save(storage: &FileSystemSpecStorage, id: &SpecId, dependencies: Vec<Dependency>) {
+        let mut metadata = SpecMetadata::new(id.slug(), "Rename test");
+        metadata.set_dependencies(dependencies);
+        block_on(storage.save_spec(&Spec::new(id.clone(), metadata, "content"))).unwrap();
+    }
+
+    #[test]
+    fn test_rename_updates_dependents_and_plan() {
+        let temp = tempfile::tempdir().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let old = SpecId::new(1_000_000, "user-auth");
+        let api = SpecId::new(2_000_000, "api");
+        let ui = SpecId::new(3_000_000, "ui");
+        save(&storage, &old, vec![]);
+        save(&storage, &api, vec![Dependency::blocked_by(old.clone())]);
+        save(&storage, &ui, vec![Dependency::related_to(old.clone())]);
+        let plans = FileSystemPlanStorage::new(temp.path());
+        let plan = Plan::new(old.clone(), "Approach", vec![PlanStep::new(0, "Step", "")]);
+        block_on(plans.save_plan(&plan)).unwrap();
+
+        let new = block_on(rename_spec(&storage, &old, "oauth-login")).unwrap();
+
+        assert_eq!(new.as_str(), "1000000-oauth-login");
+        let renamed = block_on(storage.load_spec(&new)).unwrap();
+        assert_eq!(renamed.title(), "user-auth");
+        assert!(matches!(
+            block_on(storage.load_spec(&old)),
+            Err(SpecError::NotFound(_))
+        ));
+        for id in [&api, &ui] {
+            let dependent = block_on(storage.load_spec(id)).unwrap();
+            assert_eq!(dependent.dependencies()[0].spec_id, new);
+        }
+        assert_eq!(block_on(plans.load_plan(&new)).unwrap().spec_id(), &new);
+        assert!(block_on(plans.load_plan(&old)).is_err());
+    }
+
+    #[test]
+    fn test_rename_keeps_millisecond_timestamp() {
+        let temp = tempfile::tempdir().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let old = SpecId::parse("1737734400123-foo").unwrap();
+        save(&storage, &old, vec![]);
+
+        let new = block_on(rename_spec(&storage, &old, "bar")).unwrap();
+
+        assert_eq!(new.as_str(), "1737734400123-bar");
+        assert_eq!(new.granularity(), IdGranularity::Millis);
+        assert!(block_on(storage.load_spec(&new)).is_ok());
+    }
+
+    #[test]
+    fn test_rename_refuses_collision() {
+        let temp = tempfile::tempdir().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let old = SpecId::new(1_000_000, "user-auth");
+        save(&storage, &old, vec![]);
+        save(&storage, &SpecId::new(1_000_000, "taken"), vec![]);
+
+        let result = block_on(rename_spec(&storage, &old, "taken"));
+
+        assert!(matches!(result, Err(SpecError::AlreadyExists(_))));
+        assert!(block_on(storage.load_spec(&old)).is_ok());
+    }
+
+    #[test]
+    fn test_rename_rejects_invalid_slug() {
+        let temp = tempfile::tempdir().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let old = SpecId::new(1_000_000, "user-auth");
+        save(&storage, &old, vec![]);
+
+        let result = block_on(rename_spec(&storage, &old, ""));
+
+        assert!(matches!(result, Err(SpecError::InvalidId(_))));
+    }
+}
@@ -11,6 +11,10 @@
 //! Plan files (`{spec-id}.plan.yaml`) in the same directory are excluded
 //! from spec listing operations.
 //!
+//! Keys are written in a fixed order -- `id`, then the metadata (`title`,
+//! `description`, `category`, `dependencies`, custom fields, timestamps),
+//! and `content` last -- so saved files diff cleanly.
+//!
 //! ## Examples
 //!
 //! ```no_run
@@ -34,8 +38,32 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
+// Layer 2: External crates
+use serde::Deserialize;
+
 // Layer 3: Internal crates/modules
-use airsspec_core::spec::{Spec, SpecError, SpecId, SpecStorage};
+use airsspec_core::spec::{IdPart, Spec, SpecError, SpecId, SpecMetadata, SpecStorage};
+use airsspec_core::utils::checksum;
+
+/// Parses the ID of a listed `{stem}.yaml` file.
+///
+/// Names without a numeric timestamp are unrelated files and are skipped
+/// quietly. A timestamped name whose slug breaks the ADR-003 rules is a
+/// corrupt spec ID: it is logged, and still listed if the lenient
+/// [`SpecId::parse`] accepts it, so older specs stay visible.
+fn parse_listed_id(stem: &str) -> Option<SpecId> {
+    match SpecId::parse_strict(stem) {
+        Ok(id) => Some(id),
+        Err(SpecError::InvalidIdPart {
+            part: IdPart::Slug,
+            reason,
+        }) => {
+            tracing::warn!("spec file '{stem}.yaml' has a malformed ID slug: {reason}");
+            SpecId::parse(stem).ok()
+        }
+        Err(_) => None,
+    }
+}
 
 /// Filesystem-backed spec storage.
 ///
@@ -81,31 +109,120 @@ impl FileSystemSpecStorage {
     }
 
     /// Builds the file path for a spec with the given ID.
-    fn spec_path(&self, id: &SpecId) -> PathBuf {
+    pub(crate) fn spec_path(&self, id: &SpecId) -> PathBuf {
         self.specs_dir.join(format!("{}.yaml", id.as_str()))
     }
-}
 
-impl SpecStorage for FileSystemSpecStorage {
-    fn load_spec(&self, id: &SpecId) -> impl Future<Output = Result<Spec, SpecError>> + Send {
-        let path = self.spec_path(id);
-        let id_str = id.as_str().to_string();
+    /// Loads only the metadata of the spec `id`, for fast listing.
+    ///
+    /// The content is stored inline in the same YAML document, so the file
+    /// is still read and parsed in full; the `content` field is skipped
+    /// during deserialization rather than copied into a `String`. Prefer
+    /// [`load_spec`](SpecStorage::load_spec) when the content is needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`load_spec`](SpecStorage::load_spec).
+    pub fn load_metadata(
+        &self,
+        id: &SpecId,
+    ) -> impl Future<Output = Result<SpecMetadata, SpecError>> + Send {
+        /// The metadata portion of a spec file; other fields are ignored.
+        #[derive(Deserialize)]
+        struct MetadataOnly {
+            metadata: SpecMetadata,
+        }
 
-        let result = match fs::read_to_string(&path) {
-            Ok(content) => serde_yaml::from_str::<Spec>(&content).map_err(|err| {
-                SpecError::InvalidFormat(format!(
-                    "failed to parse spec YAML '{}': {err}",
-                    path.display()
-                ))
-            }),
+        let result = self.read_spec_file(id).and_then(|(path, content)| {
+            serde_yaml::from_str::<MetadataOnly>(&content)
+                .map(|spec| spec.metadata)
+                .map_err(|err| parse_error(&path, &err))
+        });
+
+        async move { result }
+    }
+
+    /// Returns a checksum of `spec` as [`save_spec`](SpecStorage::save_spec)
+    /// writes it: the ID, all metadata, and the content.
+    ///
+    /// Pass the hash of a spec as it was loaded to
+    /// [`save_spec_if_unchanged`](Self::save_spec_if_unchanged).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpecError::InvalidFormat`] if the spec cannot be serialized.
+    pub fn spec_hash(spec: &Spec) -> Result<String, SpecError> {
+        serde_yaml::to_string(spec)
+            .map(|yaml| checksum::fnv1a_hex(yaml.as_bytes()))
+            .map_err(|err| {
+                SpecError::InvalidFormat(format!("failed to serialize spec {}: {err}", spec.id()))
+            })
+    }
+
+    /// Saves `spec` only if the stored copy still has hash `expected_hash`
+    /// (see [`spec_hash`](Self::spec_hash)).
+    ///
+    /// Used for optimistic concurrency: a caller that read the spec earlier
+    /// passes the hash it saw, and the save is refused if the spec -- its
+    /// metadata as well as its content -- was edited on disk in the
+    /// meantime. A spec that no longer exists is treated as a conflict too.
+    ///
+    /// The stored copy is read and compared when the returned future runs,
+    /// immediately before writing. The check and the write are not atomic
+    /// with respect to other processes; callers that must exclude them hold
+    /// the [`WorkspaceLock`](super::WorkspaceLock) around the call.
+    ///
+    /// # Errors
+    ///
+    /// - [`SpecError::Conflict`] if the stored hash differs from
+    ///   `expected_hash` or the spec is gone
+    /// - Any error from [`load_spec`](SpecStorage::load_spec) or
+    ///   [`save_spec`](SpecStorage::save_spec)
+    pub fn save_spec_if_unchanged(
+        &self,
+        spec: &Spec,
+        expected_hash: &str,
+    ) -> impl Future<Output = Result<(), SpecError>> + Send {
+        async move {
+            let stored = match self.load_spec(spec.id()).await {
+                Ok(stored) => stored,
+                Err(SpecError::NotFound(id)) => {
+                    return Err(SpecError::Conflict(format!("{id} (spec no longer exists)")));
+                }
+                Err(err) => return Err(err),
+            };
+            let stored_hash = Self::spec_hash(&stored)?;
+            if stored_hash != expected_hash {
+                return Err(SpecError::Conflict(format!(
+                    "{} (expected hash {expected_hash}, found {stored_hash})",
+                    spec.id()
+                )));
+            }
+            self.save_spec(spec).await
+        }
+    }
+
+    /// Reads the raw YAML of the spec `id`, returning it with its path.
+    fn read_spec_file(&self, id: &SpecId) -> Result<(PathBuf, String), SpecError> {
+        let path = self.spec_path(id);
+        match fs::read_to_string(&path) {
+            Ok(content) => Ok((path, content)),
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                Err(SpecError::NotFound(id_str))
+                Err(SpecError::NotFound(id.as_str().to_string()))
             }
             Err(err) => Err(SpecError::Io(format!(
                 "failed to read spec file '{}': {err}",
                 path.display()
             ))),
-        };
+        }
+    }
+}
+
+impl SpecStorage for FileSystemSpecStorage {
+    fn load_spec(&self, id: &SpecId) -> impl Future<Output = Result<Spec, SpecError>> + Send {
+        let result = self.read_spec_file(id).and_then(|(path, content)| {
+            serde_yaml::from_str::<Spec>(&content).map_err(|err| parse_error(&path, &err))
+        });
 
         async move { result }
     }
@@ -158,8 +275,7 @@ impl SpecStorage for FileSystemSpecStorage {
                         // Strip .yaml extension to get the spec ID string
                         let stem = file_name.strip_suffix(".yaml")?;
 
-                        // Parse as SpecId, skip if invalid
-                        SpecId::parse(stem).ok()
+                        parse_listed_id(stem)
                     })
                     .collect();
                 Ok(ids)
@@ -193,33 +309,38 @@ impl SpecStorage for FileSystemSpecStorage {
     }
 }
 
+/// Builds the [`SpecError::InvalidFormat`] for a spec file that failed to parse.
+fn parse_error(path: &Path, err: &serde_yaml::Error) -> SpecError {
+    SpecError::InvalidFormat(format!(
+        "failed to parse spec YAML '{}'{}: {err}",
+        path.display(),
+        describe_location(err)
+    ))
+}
+
+/// Formats the position of a YAML parse error as `" at line L, column C"`.
+///
+/// Returns an empty string when `serde_yaml` has no location for the error
+/// (e.g., errors raised after parsing, during type conversion), or when its
+/// message already states the location.
+fn describe_location(err: &serde_yaml::Error) -> String {
+    if err.to_string().contains(" at line ") {
+        return String::new();
+    }
+    err.location()
+        .map(|loc| format!(" at line {}, column {}", loc.line(), loc.column()))
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use std::pin::pin;
-    use std::sync::Arc;
-    use std::task::{Context, Poll, Wake, Waker};
-
-    use airsspec_core::spec::SpecMetadata;
     use tempfile::TempDir;
 
-    /// Simple single-threaded executor for testing immediately-ready futures.
-    fn block_on<F: Future>(f: F) -> F::Output {
-        struct NoopWaker;
-        impl Wake for NoopWaker {
-            fn wake(self: Arc<Self>) {}
-        }
-
-        let waker = Waker::from(Arc::new(NoopWaker));
-        let mut cx = Context::from_waker(&waker);
-        let mut f = pin!(f);
+    use airsspec_core::spec::Approval;
 
-        match f.as_mut().poll(&mut cx) {
-            Poll::Ready(result) => result,
-            Poll::Pending => panic!("block_on: unexpected Pending from immediately-ready future"),
-        }
-    }
+    use crate::test_support::block_on;
 
     /// Creates a test spec with the given ID components.
     fn test_spec(timestamp: i64, slug: &str) -> Spec {
@@ -254,6 +375,83 @@ mod tests {
         assert_eq!(loaded.dependencies().len(), spec.dependencies().len());
     }
 
+    #[test]
+    fn test_saved_keys_are_in_stable_order() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let spec = test_spec(1_737_734_400, "user-auth");
+
+        block_on(storage.save_spec(&spec)).unwrap();
+
+        let yaml = fs::read_to_string(temp.path().join("1737734400-user-auth.yaml")).unwrap();
+        let keys: Vec<&str> = yaml
+            .lines()
+            .filter_map(|line| line.trim_start().split_once(':').map(|(key, _)| key))
+            .filter(|key| !key.starts_with('#') && !key.contains(' '))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "id",
+                "metadata",
+                "title",
+                "description",
+                "category",
+                "dependencies",
+                "created_at",
+                "updated_at",
+                "content",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_fields_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let mut spec = test_spec(1_737_734_400, "custom-fields");
+        spec.metadata_mut().set_custom("jira", "AUTH-7");
+        spec.metadata_mut().set_custom("owner", "platform-team");
+
+        block_on(storage.save_spec(&spec)).unwrap();
+        let loaded = block_on(storage.load_spec(spec.id())).unwrap();
+
+        assert_eq!(loaded.metadata().get_custom("jira"), Some("AUTH-7"));
+        assert_eq!(loaded.metadata().get_custom("owner"), Some("platform-team"));
+        assert_eq!(loaded.metadata().custom().len(), 2);
+    }
+
+    #[test]
+    fn test_owner_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let mut spec = test_spec(1_737_734_400, "owned");
+        spec.metadata_mut().set_owner("platform-team");
+
+        block_on(storage.save_spec(&spec)).unwrap();
+        let yaml = fs::read_to_string(temp.path().join("1737734400-owned.yaml")).unwrap();
+        let loaded = block_on(storage.load_spec(spec.id())).unwrap();
+
+        assert!(yaml.contains("owner: platform-team"));
+        assert_eq!(loaded.owner(), Some("platform-team"));
+    }
+
+    #[test]
+    fn test_approvals_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let mut spec = test_spec(1_737_734_400, "approved");
+        let at = spec.metadata().created_at();
+        spec.metadata_mut().add_approval(Approval::new("alice", at));
+
+        block_on(storage.save_spec(&spec)).unwrap();
+        let yaml = fs::read_to_string(temp.path().join("1737734400-approved.yaml")).unwrap();
+        let loaded = block_on(storage.load_spec(spec.id())).unwrap();
+
+        assert!(yaml.contains("approver: alice"));
+        assert_eq!(loaded.metadata().approvals(), [Approval::new("alice", at)]);
+    }
+
     #[test]
     fn test_load_not_found() {
         let temp = TempDir::new().unwrap();
@@ -358,6 +556,19 @@ mod tests {
         assert_eq!(ids[0].as_str(), "1737734400-valid-spec");
     }
 
+    #[test]
+    fn test_list_specs_keeps_malformed_slug_ids() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+
+        fs::write(temp.path().join("1737734400-Legacy_Name.yaml"), "legacy").unwrap();
+        fs::write(temp.path().join("abc-payment.yaml"), "unrelated").unwrap();
+
+        let ids = block_on(storage.list_specs()).unwrap();
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids[0].as_str(), "1737734400-Legacy_Name");
+    }
+
     #[test]
     fn test_delete_spec() {
         let temp = TempDir::new().unwrap();
@@ -415,6 +626,113 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_load_malformed_yaml_reports_line() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let id = SpecId::new(1_737_734_400, "bad-line");
+
+        // Line 3 nests a mapping value where only a scalar is allowed
+        let yaml = "\
+id: 1737734400-bad-line
+metadata:
+  title: Bad: Line
+  description: Broken
+content: ''
+";
+        fs::write(temp.path().join("1737734400-bad-line.yaml"), yaml).unwrap();
+
+        match block_on(storage.load_spec(&id)).unwrap_err() {
+            SpecError::InvalidFormat(msg) => {
+                assert_eq!(
+                    msg.matches("line 3").count(),
+                    1,
+                    "error should include the line number once, got: {msg}"
+                );
+            }
+            other => panic!("expected InvalidFormat, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_load_metadata_matches_full_load() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let mut spec = test_spec(1_737_734_400, "metadata-only");
+        spec.set_content("# Large\n\n".repeat(1_000));
+        block_on(storage.save_spec(&spec)).unwrap();
+
+        let metadata = block_on(storage.load_metadata(spec.id())).unwrap();
+        let full = block_on(storage.load_spec(spec.id())).unwrap();
+
+        assert_eq!(&metadata, full.metadata());
+    }
+
+    #[test]
+    fn test_load_metadata_not_found() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+
+        let result = block_on(storage.load_metadata(&SpecId::new(1_000_000, "missing")));
+
+        assert!(matches!(result, Err(SpecError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_save_if_unchanged_with_matching_hash() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let mut spec = test_spec(1_737_734_400, "tracked");
+        block_on(storage.save_spec(&spec)).unwrap();
+        let last_known = FileSystemSpecStorage::spec_hash(&spec).unwrap();
+
+        spec.set_content("# Updated");
+        block_on(storage.save_spec_if_unchanged(&spec, &last_known)).unwrap();
+
+        let loaded = block_on(storage.load_spec(spec.id())).unwrap();
+        assert_eq!(loaded.content(), "# Updated");
+    }
+
+    #[test]
+    fn test_save_if_unchanged_detects_external_metadata_edit() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let mut spec = test_spec(1_737_734_400, "tracked");
+        block_on(storage.save_spec(&spec)).unwrap();
+        let last_known = FileSystemSpecStorage::spec_hash(&spec).unwrap();
+
+        let mut external = spec.clone();
+        external.metadata_mut().set_title("Retitled on disk");
+        block_on(storage.save_spec(&external)).unwrap();
+
+        spec.set_content("# Server update");
+        let result = block_on(storage.save_spec_if_unchanged(&spec, &last_known));
+
+        assert!(matches!(result, Err(SpecError::Conflict(_))));
+        let loaded = block_on(storage.load_spec(spec.id())).unwrap();
+        assert_eq!(loaded.title(), "Retitled on disk");
+    }
+
+    #[test]
+    fn test_save_if_unchanged_detects_external_edit() {
+        let temp = TempDir::new().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let mut spec = test_spec(1_737_734_400, "tracked");
+        block_on(storage.save_spec(&spec)).unwrap();
+        let last_known = FileSystemSpecStorage::spec_hash(&spec).unwrap();
+
+        let mut external = spec.clone();
+        external.set_content("# Edited on disk");
+        block_on(storage.save_spec(&external)).unwrap();
+
+        spec.set_content("# Server update");
+        let result = block_on(storage.save_spec_if_unchanged(&spec, &last_known));
+
+        assert!(matches!(result, Err(SpecError::Conflict(_))));
+        let loaded = block_on(storage.load_spec(spec.id())).unwrap();
+        assert_eq!(loaded.content(), "# Edited on disk");
+    }
+
     #[test]
     fn test_send_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
@@ -0,0 +1,190 @@
+//! # Workspace Spec Templates
+//!
+//! Loads per-category spec content templates from the workspace, falling
+//! back to the built-ins from [`builtin_template`].
+//!
+//! ## Layout
+//!
+//! ```text
+//! <project-root>/
+//! └── .airsspec/
+//!     └── templates/
+//!         ├── feature.md     # Overrides the Feature template
+//!         └── bugfix.md      # Overrides the BugFix template
+//! ```
+//!
+//! File names use the category's display form (`feature`, `bugfix`, ...).
+
+// Layer 1: Standard library
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Layer 3: Internal crates/modules
+use airsspec_core::spec::{Category, builtin_template};
+
+/// Spec content templates keyed by category.
+///
+/// Categories without a workspace override use the built-in template.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use airsspec_core::spec::Category;
+/// use airsspec_mcp::storage::TemplateRegistry;
+///
+/// let registry = TemplateRegistry::from_workspace(Path::new("/my/project"));
+/// println!("{}", registry.get(Category::Feature));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRegistry {
+    overrides: HashMap<Category, String>,
+    warnings: Vec<String>,
+}
+
+impl TemplateRegistry {
+    /// Creates a registry holding only the built-in templates.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads templates from `.airsspec/templates/` under `root`.
+    ///
+    /// A template file that cannot be read, is not valid UTF-8, or is blank
+    /// is skipped with a warning and the built-in template is used instead.
+    /// A missing templates directory is not an error.
+    #[must_use]
+    pub fn from_workspace(root: &Path) -> Self {
+        let templates_dir = root.join(".airsspec").join("templates");
+        let mut registry = Self::new();
+
+        for category in Category::ALL {
+            let path = templates_dir.join(format!("{category}.md"));
+            match fs::read_to_string(&path) {
+                Ok(content) if content.trim().is_empty() => {
+                    registry.warn(format!(
+                        "Template {} is empty; using built-in {category} template",
+                        path.display()
+                    ));
+                }
+                Ok(content) => {
+                    registry.overrides.insert(category, content);
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => {
+                    registry.warn(format!(
+                        "Failed to read template {}: {err}; using built-in {category} template",
+                        path.display()
+                    ));
+                }
+            }
+        }
+
+        registry
+    }
+
+    /// Returns the template for `category`.
+    #[must_use]
+    pub fn get(&self, category: Category) -> &str {
+        self.overrides
+            .get(&category)
+            .map_or_else(|| builtin_template(category), String::as_str)
+    }
+
+    /// Returns `true` if the workspace overrides the template for `category`.
+    #[must_use]
+    pub fn is_custom(&self, category: Category) -> bool {
+        self.overrides.contains_key(&category)
+    }
+
+    /// Returns the warnings raised while loading templates.
+    #[must_use]
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    fn warn(&mut self, message: String) {
+        tracing::warn!("{message}");
+        self.warnings.push(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    fn templates_dir(root: &Path) -> std::path::PathBuf {
+        let dir = root.join(".airsspec/templates");
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_custom_feature_template_overrides_builtin() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            templates_dir(temp.path()).join("feature.md"),
+            "## Why\n\n## What\n",
+        )
+        .unwrap();
+
+        let registry = TemplateRegistry::from_workspace(temp.path());
+
+        assert!(registry.is_custom(Category::Feature));
+        assert_eq!(registry.get(Category::Feature), "## Why\n\n## What\n");
+        assert!(registry.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_category_without_file_uses_builtin() {
+        let temp = TempDir::new().unwrap();
+        fs::write(templates_dir(temp.path()).join("feature.md"), "## Why\n").unwrap();
+
+        let registry = TemplateRegistry::from_workspace(temp.path());
+
+        assert!(!registry.is_custom(Category::BugFix));
+        assert_eq!(
+            registry.get(Category::BugFix),
+            builtin_template(Category::BugFix)
+        );
+    }
+
+    #[test]
+    fn test_missing_templates_dir_uses_builtins() {
+        let temp = TempDir::new().unwrap();
+
+        let registry = TemplateRegistry::from_workspace(temp.path());
+
+        for category in Category::ALL {
+            assert_eq!(registry.get(category), builtin_template(category));
+        }
+        assert!(registry.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_malformed_template_warns_and_falls_back() {
+        let temp = TempDir::new().unwrap();
+        let dir = templates_dir(temp.path());
+        fs::write(dir.join("refactor.md"), [0xff, 0xfe, 0x00]).unwrap();
+        fs::write(dir.join("documentation.md"), "  \n").unwrap();
+
+        let registry = TemplateRegistry::from_workspace(temp.path());
+
+        assert_eq!(
+            registry.get(Category::Refactor),
+            builtin_template(Category::Refactor)
+        );
+        assert_eq!(
+            registry.get(Category::Documentation),
+            builtin_template(Category::Documentation)
+        );
+        assert_eq!(registry.warnings().len(), 2);
+        assert!(registry.warnings()[0].contains("refactor.md"));
+        assert!(registry.warnings()[1].contains("documentation.md"));
+    }
+}
@@ -0,0 +1,148 @@
+//! # Spec Directory Watcher
+//!
+//! Detects spec files that changed on disk between two polls, so the server
+//! can push `notifications/resources/updated` to subscribed clients.
+//!
+//! The watcher polls rather than relying on platform file events: each
+//! [`poll`](SpecWatcher::poll) compares the modification time and size of
+//! every `{spec-id}.yaml` file with the previous poll. Plan files are
+//! ignored, as in [`FileSystemSpecStorage`](super::FileSystemSpecStorage)
+//! listings.
+
+// Layer 1: Standard library
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+// Layer 3: Internal crates/modules
+use airsspec_core::spec::SpecId;
+
+/// Modification time and size of a spec file at one poll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+/// Polling watcher reporting specs added, changed, or removed on disk.
+///
+/// # Examples
+///
+/// ```no_run
+/// use airsspec_mcp::storage::SpecWatcher;
+///
+/// let mut watcher = SpecWatcher::new("/path/to/.airsspec/specs");
+/// // ... specs are edited ...
+/// for id in watcher.poll() {
+///     println!("changed: {id}");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpecWatcher {
+    specs_dir: PathBuf,
+    stamps: HashMap<SpecId, FileStamp>,
+}
+
+impl SpecWatcher {
+    /// Creates a watcher for `specs_dir`, taking the initial snapshot.
+    ///
+    /// Specs already on disk are not reported by the first
+    /// [`poll`](Self::poll).
+    #[must_use]
+    pub fn new(specs_dir: impl Into<PathBuf>) -> Self {
+        let specs_dir = specs_dir.into();
+        let stamps = scan(&specs_dir);
+        Self { specs_dir, stamps }
+    }
+
+    /// Returns the watched directory.
+    #[must_use]
+    pub fn specs_dir(&self) -> &Path {
+        &self.specs_dir
+    }
+
+    /// Returns the specs added, modified, or removed since the last poll,
+    /// sorted by ID.
+    ///
+    /// A missing or unreadable directory counts as empty, so deleting it
+    /// reports every spec as removed.
+    pub fn poll(&mut self) -> Vec<SpecId> {
+        let current = scan(&self.specs_dir);
+
+        let mut changed: Vec<SpecId> = current
+            .iter()
+            .filter(|(id, stamp)| self.stamps.get(*id) != Some(*stamp))
+            .map(|(id, _)| id.clone())
+            .chain(
+                self.stamps
+                    .keys()
+                    .filter(|id| !current.contains_key(*id))
+                    .cloned(),
+            )
+            .collect();
+        changed.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+        self.stamps = current;
+        changed
+    }
+}
+
+/// Reads the stamp of every spec file in `specs_dir`.
+fn scan(specs_dir: &Path) -> HashMap<SpecId, FileStamp> {
+    let Ok(entries) = fs::read_dir(specs_dir) else {
+        return HashMap::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if file_name.ends_with(".plan.yaml") {
+                return None;
+            }
+            let id = SpecId::parse(file_name.strip_suffix(".yaml")?).ok()?;
+            let metadata = entry.metadata().ok()?;
+            let stamp = FileStamp {
+                modified: metadata.modified().ok(),
+                len: metadata.len(),
+            };
+            Some((id, stamp))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_existing_specs_are_not_reported_initially() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::write(temp.path().join("1737734400-auth.yaml"), "a").unwrap();
+
+        let mut watcher = SpecWatcher::new(temp.path());
+
+        assert!(watcher.poll().is_empty());
+    }
+
+    #[test]
+    fn test_poll_reports_added_modified_and_removed_specs() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::write(temp.path().join("1737734400-auth.yaml"), "a").unwrap();
+        fs::write(temp.path().join("1737734500-gone.yaml"), "a").unwrap();
+        let mut watcher = SpecWatcher::new(temp.path());
+
+        fs::write(temp.path().join("1737734400-auth.yaml"), "changed").unwrap();
+        fs::remove_file(temp.path().join("1737734500-gone.yaml")).unwrap();
+        fs::write(temp.path().join("1737734600-new.yaml"), "a").unwrap();
+        fs::write(temp.path().join("1737734400-auth.plan.yaml"), "a").unwrap();
+
+        let changed: Vec<String> = watcher.poll().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            changed,
+            ["1737734400-auth", "1737734500-gone", "1737734600-new"]
+        );
+        assert!(watcher.poll().is_empty());
+    }
+}
@@ -11,7 +11,8 @@
 //! <project-root>/
 //! └── .airsspec/
 //!     ├── config.toml    # Project configuration (TOML format)
-//!     ├── specs/         # Specification files
+//!     ├── specs/         # Specification and plan files
+//!     ├── templates/     # Custom spec templates
 //!     └── logs/          # Session logs
 //! ```
 //!
@@ -37,11 +38,13 @@
 
 // Layer 1: Standard library
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 // Layer 3: Internal crates/modules
 use airsspec_core::workspace::{ProjectConfig, WorkspaceError, WorkspaceInfo, WorkspaceProvider};
 
+use super::WorkspaceLock;
+
 /// Filesystem-based workspace provider.
 ///
 /// Implements [`WorkspaceProvider`] by creating and reading `.airsspec/`
@@ -62,6 +65,110 @@ impl FileSystemWorkspaceProvider {
     pub fn new() -> Self {
         Self
     }
+
+    /// Returns the directories [`initialize`](WorkspaceProvider::initialize)
+    /// would create under `path`, in creation order.
+    ///
+    /// Nothing is touched on disk; used to preview `airsspec init --dry-run`.
+    #[must_use]
+    pub fn planned_directories(path: &Path) -> Vec<PathBuf> {
+        let workspace_dir = path.join(WORKSPACE_DIR);
+        let mut dirs = vec![workspace_dir.clone()];
+        dirs.extend(SUBDIRS.iter().map(|subdir| workspace_dir.join(subdir)));
+        dirs
+    }
+
+    /// Returns the path of the configuration file for a workspace at `path`.
+    #[must_use]
+    pub fn config_path(path: &Path) -> PathBuf {
+        path.join(WORKSPACE_DIR).join(CONFIG_FILE)
+    }
+
+    /// Renders `config` exactly as [`initialize`](WorkspaceProvider::initialize)
+    /// would write it to `config.toml`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WorkspaceError::InvalidConfig`] if the config cannot be
+    /// serialized to TOML.
+    pub fn render_config(config: &ProjectConfig) -> Result<String, WorkspaceError> {
+        toml::to_string_pretty(config).map_err(|e| WorkspaceError::InvalidConfig(e.to_string()))
+    }
+
+    /// Creates a workspace at `path` like
+    /// [`initialize`](WorkspaceProvider::initialize), and returns it with
+    /// its [`WorkspaceLock`] still held.
+    ///
+    /// The lock is taken as soon as the `.airsspec` directory exists, so no
+    /// other process can use the workspace before its `config.toml` is
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// - [`WorkspaceError::Locked`] or [`WorkspaceError::LockedByUnknown`]
+    ///   if `.airsspec` already exists and another process holds its lock
+    /// - [`WorkspaceError::AlreadyExists`] if `.airsspec` already exists
+    /// - [`WorkspaceError::Io`] if a directory or the config file cannot be
+    ///   created
+    /// - [`WorkspaceError::InvalidConfig`] if the config cannot be serialized
+    pub fn initialize_locked(
+        &self,
+        path: &Path,
+        config: &ProjectConfig,
+    ) -> Result<(WorkspaceInfo, WorkspaceLock), WorkspaceError> {
+        let workspace_dir = path.join(WORKSPACE_DIR);
+
+        // Creating the directory itself (not `create_dir_all`) decides which
+        // of two concurrent initializations wins.
+        match fs::create_dir(&workspace_dir) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                return Err(match WorkspaceLock::acquire(path) {
+                    Err(
+                        held @ (WorkspaceError::Locked(_) | WorkspaceError::LockedByUnknown(_)),
+                    ) => held,
+                    _ => WorkspaceError::AlreadyExists(workspace_dir),
+                });
+            }
+            Err(err) => return Err(err.into()),
+        }
+        let lock = WorkspaceLock::acquire(path)?;
+
+        for dir in Self::planned_directories(path) {
+            fs::create_dir_all(dir)?;
+        }
+        let config_content = Self::render_config(config)?;
+        fs::write(Self::config_path(path), config_content)?;
+
+        Ok((WorkspaceInfo::new(path.to_path_buf(), config.clone()), lock))
+    }
+
+    /// Creates any expected workspace subdirectories missing under `path`.
+    ///
+    /// Existing directories and files are left untouched, so this is safe to
+    /// run repeatedly and upgrades workspaces created by older versions.
+    /// Returns the directories that were created, in creation order.
+    ///
+    /// # Errors
+    ///
+    /// - [`WorkspaceError::NotFound`] if `path` has no `.airsspec` directory
+    /// - [`WorkspaceError::Io`] if a directory cannot be created
+    pub fn ensure_structure(&self, path: &Path) -> Result<Vec<PathBuf>, WorkspaceError> {
+        let workspace_dir = path.join(WORKSPACE_DIR);
+        if !workspace_dir.is_dir() {
+            return Err(WorkspaceError::NotFound(path.to_path_buf()));
+        }
+
+        let mut created = Vec::new();
+        for subdir in SUBDIRS {
+            let dir = workspace_dir.join(subdir);
+            if !dir.exists() {
+                fs::create_dir(&dir)?;
+                created.push(dir);
+            }
+        }
+        Ok(created)
+    }
 }
 
 /// Name of the workspace directory.
@@ -70,8 +177,8 @@ const WORKSPACE_DIR: &str = ".airsspec";
 /// Name of the configuration file within the workspace directory.
 const CONFIG_FILE: &str = "config.toml";
 
-/// Subdirectories created inside a new workspace.
-const SUBDIRS: &[&str] = &["specs", "logs"];
+/// Subdirectories expected inside a workspace.
+const SUBDIRS: &[&str] = &["specs", "templates", "logs"];
 
 impl WorkspaceProvider for FileSystemWorkspaceProvider {
     fn discover(&self, start: &Path) -> Result<WorkspaceInfo, WorkspaceError> {
@@ -107,27 +214,8 @@ impl WorkspaceProvider for FileSystemWorkspaceProvider {
         path: &Path,
         config: &ProjectConfig,
     ) -> Result<WorkspaceInfo, WorkspaceError> {
-        let workspace_dir = path.join(WORKSPACE_DIR);
-
-        // Check if workspace already exists
-        if workspace_dir.exists() {
-            return Err(WorkspaceError::AlreadyExists(workspace_dir));
-        }
-
-        // Create workspace directory and subdirectories
-        fs::create_dir_all(&workspace_dir)?;
-
-        for subdir in SUBDIRS {
-            fs::create_dir_all(workspace_dir.join(subdir))?;
-        }
-
-        // Write config file
-        let config_content = toml::to_string_pretty(config)
-            .map_err(|e| WorkspaceError::InvalidConfig(e.to_string()))?;
-        let config_path = workspace_dir.join(CONFIG_FILE);
-        fs::write(&config_path, config_content)?;
-
-        Ok(WorkspaceInfo::new(path.to_path_buf(), config.clone()))
+        self.initialize_locked(path, config)
+            .map(|(info, _lock)| info)
     }
 }
 
@@ -154,9 +242,110 @@ mod tests {
         assert!(temp.path().join(".airsspec").is_dir());
         assert!(temp.path().join(".airsspec/config.toml").is_file());
         assert!(temp.path().join(".airsspec/specs").is_dir());
+        assert!(temp.path().join(".airsspec/templates").is_dir());
         assert!(temp.path().join(".airsspec/logs").is_dir());
     }
 
+    #[test]
+    fn test_preview_matches_initialize() {
+        let temp = TempDir::new().unwrap();
+        let config = ProjectConfig::new("preview", "A previewed project");
+
+        let dirs = FileSystemWorkspaceProvider::planned_directories(temp.path());
+        let rendered = FileSystemWorkspaceProvider::render_config(&config).unwrap();
+        assert!(!temp.path().join(".airsspec").exists());
+
+        FileSystemWorkspaceProvider::new()
+            .initialize(temp.path(), &config)
+            .unwrap();
+
+        assert_eq!(dirs.len(), 4);
+        assert!(dirs.iter().all(|dir| dir.is_dir()));
+        let written =
+            fs::read_to_string(FileSystemWorkspaceProvider::config_path(temp.path())).unwrap();
+        assert_eq!(written, rendered);
+    }
+
+    #[test]
+    fn test_ensure_structure_fresh_workspace_creates_all() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join(".airsspec")).unwrap();
+
+        let created = FileSystemWorkspaceProvider::new()
+            .ensure_structure(temp.path())
+            .unwrap();
+
+        let expected: Vec<PathBuf> = FileSystemWorkspaceProvider::planned_directories(temp.path())
+            .into_iter()
+            .skip(1)
+            .collect();
+        assert_eq!(created, expected);
+        assert!(created.iter().all(|dir| dir.is_dir()));
+    }
+
+    #[test]
+    fn test_ensure_structure_partial_workspace_creates_missing_only() {
+        let temp = TempDir::new().unwrap();
+        let provider = FileSystemWorkspaceProvider::new();
+        let config = ProjectConfig::new("old", "An older workspace");
+        provider.initialize(temp.path(), &config).unwrap();
+        let airsspec = temp.path().join(".airsspec");
+        fs::remove_dir(airsspec.join("templates")).unwrap();
+        fs::write(airsspec.join("specs/keep.yaml"), "kept").unwrap();
+
+        let created = provider.ensure_structure(temp.path()).unwrap();
+
+        assert_eq!(created, vec![airsspec.join("templates")]);
+        assert_eq!(
+            fs::read_to_string(airsspec.join("specs/keep.yaml")).unwrap(),
+            "kept"
+        );
+        assert_eq!(
+            fs::read_to_string(airsspec.join("config.toml")).unwrap(),
+            FileSystemWorkspaceProvider::render_config(&config).unwrap()
+        );
+        assert!(provider.ensure_structure(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ensure_structure_requires_workspace() {
+        let temp = TempDir::new().unwrap();
+
+        let result = FileSystemWorkspaceProvider::new().ensure_structure(temp.path());
+
+        assert!(matches!(result, Err(WorkspaceError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_initialize_locked_holds_lock_until_dropped() {
+        let temp = TempDir::new().unwrap();
+        let provider = FileSystemWorkspaceProvider::new();
+        let config = ProjectConfig::new("test", "test");
+
+        let (_info, lock) = provider.initialize_locked(temp.path(), &config).unwrap();
+
+        assert!(FileSystemWorkspaceProvider::config_path(temp.path()).is_file());
+        assert!(matches!(
+            WorkspaceLock::acquire(temp.path()),
+            Err(WorkspaceError::Locked(_))
+        ));
+        drop(lock);
+        assert!(WorkspaceLock::acquire(temp.path()).is_ok());
+    }
+
+    #[test]
+    fn test_initialize_reports_held_lock() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join(WORKSPACE_DIR)).unwrap();
+        let _held = WorkspaceLock::acquire(temp.path()).unwrap();
+
+        let result = FileSystemWorkspaceProvider::new()
+            .initialize(temp.path(), &ProjectConfig::new("a", "b"));
+
+        assert!(matches!(result, Err(WorkspaceError::Locked(_))));
+        assert!(!FileSystemWorkspaceProvider::config_path(temp.path()).exists());
+    }
+
     #[test]
     fn test_initialize_fails_if_exists() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,44 @@
+//! Helpers shared by the unit tests in this crate.
+
+// Layer 1: Standard library
+use std::fs;
+use std::path::Path;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+/// Runs an immediately-ready future to completion on the current thread.
+///
+/// The filesystem storage does its work before returning its futures, so
+/// they complete on the first poll.
+///
+/// # Panics
+///
+/// Panics if the future is not ready on the first poll.
+pub(crate) fn block_on<F: Future>(f: F) -> F::Output {
+    struct NoopWaker;
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut f = pin!(f);
+
+    match f.as_mut().poll(&mut cx) {
+        Poll::Ready(result) => result,
+        Poll::Pending => panic!("block_on: unexpected Pending from immediately-ready future"),
+    }
+}
+
+/// Creates a minimal valid workspace in `dir`: `.airsspec/` with `specs/`,
+/// `logs/`, and a `config.toml`.
+pub(crate) fn create_workspace(dir: &Path) {
+    fs::create_dir_all(dir.join(".airsspec/specs")).unwrap();
+    fs::create_dir_all(dir.join(".airsspec/logs")).unwrap();
+    fs::write(
+        dir.join(".airsspec/config.toml"),
+        "[project]\nname = \"test\"\ndescription = \"test project\"\n",
+    )
+    .unwrap();
+}
@@ -0,0 +1,51 @@
+//! Helpers for building tool result content.
+//!
+//! Tools that return structured data send it as a single text content block
+//! holding pretty-printed JSON. Clients that understand JSON parse it
+//! directly; text-only clients still get a readable, indented document.
+
+// Layer 2: External crates
+use serde_json::Value;
+
+use airsprotocols_mcp::protocol::Content;
+
+/// Wraps `value` in a text content block as pretty-printed JSON.
+#[must_use]
+pub fn json_content(value: &Value) -> Content {
+    Content::text(pretty_json(value))
+}
+
+/// Pretty-prints `value` as indented JSON.
+#[must_use]
+pub fn pretty_json(value: &Value) -> String {
+    // Serializing a `Value` cannot fail: its map keys are always strings.
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_pretty_json_round_trips() {
+        let value = json!({
+            "valid": false,
+            "issues": [{ "severity": "error", "message": "Bad \"title\"" }],
+        });
+
+        let text = pretty_json(&value);
+        let parsed: Value = serde_json::from_str(&text).expect("should be valid JSON");
+
+        assert_eq!(parsed, value);
+    }
+
+    #[test]
+    fn test_pretty_json_is_indented() {
+        let text = pretty_json(&json!({ "items": [1, 2] }));
+
+        assert!(text.contains('\n'), "should span multiple lines: {text}");
+        assert!(text.contains("  \"items\""), "should be indented: {text}");
+    }
+}
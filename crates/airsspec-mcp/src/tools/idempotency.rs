@@ -0,0 +1,412 @@
+//! Idempotency keys for mutating tools.
+//!
+//! A client retrying a `tools/call` after a dropped response may pass the
+//! same `idempotency_key` argument again. [`IdempotencyCache`] remembers the
+//! result of each keyed call for a limited time and replays it for a repeat,
+//! so the mutation runs at most once.
+//!
+//! A key is reserved before its call runs: a retry that arrives while the
+//! original is still running waits for it and then replays its result. A
+//! key also remembers a hash of the arguments it was first used with, and
+//! reusing it with different arguments is an error. A call that fails
+//! releases its key, so a corrected retry may reuse it with other arguments.
+
+// Layer 1: Standard library
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Layer 2: External crates
+use serde_json::Value;
+
+/// Argument name clients use to pass an idempotency key.
+pub const IDEMPOTENCY_KEY: &str = "idempotency_key";
+
+/// Result slot of one keyed call, locked while the call runs.
+type Slot = Arc<tokio::sync::Mutex<Option<Value>>>;
+
+/// A reserved key.
+#[derive(Debug)]
+struct Entry {
+    /// When the call finished, or when the key was reserved if it has not.
+    recorded: Instant,
+    /// Hash of the arguments the key was reserved with.
+    arguments: u64,
+    /// The call's result once it has succeeded.
+    slot: Slot,
+}
+
+/// In-memory store of recent results for keyed tool calls.
+///
+/// Keys are scoped per tool, so the same key sent to two different tools
+/// does not collide. Entries expire after the configured TTL; only
+/// successful results are remembered, so a failed call can be retried with
+/// the same key.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use airsspec_mcp::tools::IdempotencyCache;
+/// use serde_json::json;
+///
+/// # async fn example() {
+/// let cache = IdempotencyCache::new(Duration::from_secs(60));
+/// let arguments = json!({ "idempotency_key": "req-1" });
+/// let first = cache
+///     .call("spec_add_dependency", &arguments, || async {
+///         Ok(json!({ "added": true }))
+///     })
+///     .await;
+/// let retry = cache
+///     .call("spec_add_dependency", &arguments, || async {
+///         Ok(json!({ "added": false }))
+///     })
+///     .await;
+/// assert_eq!(first, retry);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct IdempotencyCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<(String, String), Entry>>,
+}
+
+impl IdempotencyCache {
+    /// How long results are remembered by default.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(10 * 60);
+
+    /// Creates an empty cache whose entries expire after `ttl`.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns how long results are remembered.
+    #[must_use]
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Runs the tool call `run` unless `arguments` carries an
+    /// `idempotency_key` already seen for `tool` within the TTL, in which
+    /// case the original result is returned instead.
+    ///
+    /// A call with a key that is still running waits for it to finish.
+    /// Calls without a key always run, and errors are never remembered: a
+    /// failed call releases its key together with the arguments it was
+    /// reserved with.
+    ///
+    /// # Errors
+    ///
+    /// - The error of `run`, if it ran and failed
+    /// - A message saying so if the key was already used for `tool` with
+    ///   different arguments; `run` is not called
+    pub async fn call<F, Fut>(&self, tool: &str, arguments: &Value, run: F) -> Result<Value, String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Value, String>>,
+    {
+        let Some(key) = arguments.get(IDEMPOTENCY_KEY).and_then(Value::as_str) else {
+            return run().await;
+        };
+
+        let slot = self.reserve_at(tool, key, hash_arguments(arguments), Instant::now())?;
+        let mut result = slot.lock().await;
+        if let Some(result) = result.as_ref() {
+            return Ok(result.clone());
+        }
+        let value = match run().await {
+            Ok(value) => value,
+            Err(err) => {
+                self.release(tool, key, &slot);
+                return Err(err);
+            }
+        };
+        *result = Some(value.clone());
+        self.record_at(tool, key, Instant::now());
+        Ok(value)
+    }
+
+    /// Returns the slot for `key`, reserving it if it is new or expired,
+    /// and drops other expired entries.
+    ///
+    /// Entries whose slot is in use are kept even if expired, so a running
+    /// call cannot be duplicated.
+    fn reserve_at(
+        &self,
+        tool: &str,
+        key: &str,
+        arguments: u64,
+        now: Instant,
+    ) -> Result<Slot, String> {
+        let mut entries = self.lock();
+        entries.retain(|_, entry| {
+            now.duration_since(entry.recorded) < self.ttl || Arc::strong_count(&entry.slot) > 1
+        });
+        let entry = entries
+            .entry((tool.to_string(), key.to_string()))
+            .or_insert_with(|| Entry {
+                recorded: now,
+                arguments,
+                slot: Slot::default(),
+            });
+        if entry.arguments != arguments {
+            return Err(format!(
+                "{IDEMPOTENCY_KEY} '{key}' was already used for {tool} with different arguments"
+            ));
+        }
+        Ok(Arc::clone(&entry.slot))
+    }
+
+    /// Drops the reservation of `key` after its call failed, unless other
+    /// calls with the same arguments are waiting on `slot` to retry it.
+    fn release(&self, tool: &str, key: &str, slot: &Slot) {
+        let mut entries = self.lock();
+        let id = (tool.to_string(), key.to_string());
+        // One reference is the map's and one the failed call's.
+        if entries
+            .get(&id)
+            .is_some_and(|entry| Arc::ptr_eq(&entry.slot, slot) && Arc::strong_count(slot) == 2)
+        {
+            entries.remove(&id);
+        }
+    }
+
+    /// Starts the TTL of `key` from `now`, when its call succeeded.
+    fn record_at(&self, tool: &str, key: &str, now: Instant) {
+        if let Some(entry) = self.lock().get_mut(&(tool.to_string(), key.to_string())) {
+            entry.recorded = now;
+        }
+    }
+
+    /// Locks the entry map. Poisoning is ignored: a panic while holding the
+    /// lock cannot leave the map inconsistent.
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<(String, String), Entry>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Default for IdempotencyCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_TTL)
+    }
+}
+
+/// Hashes `arguments` as JSON, visiting object keys in sorted order so the
+/// hash does not depend on the order the client sent them in.
+fn hash_arguments(arguments: &Value) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hash_value(arguments, &mut hasher);
+    hasher.finish()
+}
+
+/// Feeds `value` into `hasher`, with object keys sorted.
+fn hash_value(value: &Value, hasher: &mut DefaultHasher) {
+    match value {
+        Value::Object(map) => {
+            let mut fields: Vec<(&String, &Value)> = map.iter().collect();
+            fields.sort_unstable_by_key(|&(name, _)| name);
+            hasher.write_usize(fields.len());
+            for (name, field) in fields {
+                name.hash(hasher);
+                hash_value(field, hasher);
+            }
+        }
+        Value::Array(items) => {
+            hasher.write_usize(items.len());
+            for item in items {
+                hash_value(item, hasher);
+            }
+        }
+        scalar => scalar.to_string().hash(hasher),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use serde_json::json;
+
+    use crate::test_support::block_on;
+
+    use super::*;
+
+    /// Simulates a create tool: each run creates a spec with a fresh ID.
+    fn create(cache: &IdempotencyCache, created: &Cell<u32>, key: &str) -> Value {
+        let arguments = json!({ "title": "Auth", IDEMPOTENCY_KEY: key });
+        block_on(cache.call("spec_create", &arguments, || async {
+            created.set(created.get() + 1);
+            Ok(json!({ "id": format!("{}-auth", created.get()) }))
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_repeated_key_returns_original_result() {
+        let cache = IdempotencyCache::default();
+        let created = Cell::new(0);
+
+        let first = create(&cache, &created, "req-1");
+        let retry = create(&cache, &created, "req-1");
+
+        assert_eq!(first, json!({ "id": "1-auth" }));
+        assert_eq!(retry, first);
+        assert_eq!(created.get(), 1);
+    }
+
+    #[test]
+    fn test_different_key_runs_again() {
+        let cache = IdempotencyCache::default();
+        let created = Cell::new(0);
+
+        let first = create(&cache, &created, "req-1");
+        let second = create(&cache, &created, "req-2");
+
+        assert_ne!(first, second);
+        assert_eq!(created.get(), 2);
+    }
+
+    #[test]
+    fn test_keys_are_scoped_per_tool() {
+        let cache = IdempotencyCache::default();
+        let arguments = json!({ IDEMPOTENCY_KEY: "req-1" });
+
+        block_on(cache.call("spec_add_dependency", &arguments, || async {
+            Ok(json!({ "added": true }))
+        }))
+        .unwrap();
+        let other = block_on(cache.call("spec_remove_dependency", &arguments, || async {
+            Ok(json!({ "removed": true }))
+        }));
+
+        assert_eq!(other, Ok(json!({ "removed": true })));
+    }
+
+    #[test]
+    fn test_errors_are_not_remembered() {
+        let cache = IdempotencyCache::default();
+        let arguments = json!({ IDEMPOTENCY_KEY: "req-1" });
+
+        let failed = block_on(cache.call("spec_create", &arguments, || async {
+            Err("disk full".to_string())
+        }));
+        let retry = block_on(cache.call("spec_create", &arguments, || async {
+            Ok(json!({ "id": "1-auth" }))
+        }));
+
+        assert_eq!(failed, Err("disk full".to_string()));
+        assert_eq!(retry, Ok(json!({ "id": "1-auth" })));
+    }
+
+    #[test]
+    fn test_failed_key_can_be_retried_with_corrected_arguments() {
+        let cache = IdempotencyCache::default();
+        let invalid = json!({ "spec_id": "bad id", IDEMPOTENCY_KEY: "req-1" });
+        let corrected = json!({ "spec_id": "1-auth", IDEMPOTENCY_KEY: "req-1" });
+
+        let failed = block_on(cache.call("spec_add_dependency", &invalid, || async {
+            Err("invalid spec ID".to_string())
+        }));
+        let retry = block_on(cache.call("spec_add_dependency", &corrected, || async {
+            Ok(json!({ "added": true }))
+        }));
+        let replay = block_on(cache.call("spec_add_dependency", &corrected, || async {
+            Ok(json!({ "added": false }))
+        }));
+
+        assert_eq!(failed, Err("invalid spec ID".to_string()));
+        assert_eq!(retry, Ok(json!({ "added": true })));
+        assert_eq!(replay, retry);
+    }
+
+    #[test]
+    fn test_key_reused_with_different_arguments_is_rejected() {
+        let cache = IdempotencyCache::default();
+        let created = Cell::new(0);
+        create(&cache, &created, "req-1");
+
+        let arguments = json!({ "title": "Billing", IDEMPOTENCY_KEY: "req-1" });
+        let result = block_on(cache.call("spec_create", &arguments, || async {
+            created.set(created.get() + 1);
+            Ok(json!({ "id": "2-billing" }))
+        }));
+
+        assert!(result.unwrap_err().contains("different arguments"));
+        assert_eq!(created.get(), 1);
+    }
+
+    #[test]
+    fn test_argument_order_does_not_matter() {
+        let cache = IdempotencyCache::default();
+        let first: Value =
+            serde_json::from_str(r#"{ "a": 1, "b": 2, "idempotency_key": "req-1" }"#).unwrap();
+        let reordered: Value =
+            serde_json::from_str(r#"{ "b": 2, "idempotency_key": "req-1", "a": 1 }"#).unwrap();
+
+        block_on(cache.call("spec_create", &first, || async { Ok(json!(1)) })).unwrap();
+        let retry = block_on(cache.call("spec_create", &reordered, || async { Ok(json!(2)) }));
+
+        assert_eq!(retry, Ok(json!(1)));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_retries_run_once() {
+        let cache = Arc::new(IdempotencyCache::default());
+        let runs = Arc::new(AtomicU32::new(0));
+        let arguments = json!({ "spec_id": "1-a", IDEMPOTENCY_KEY: "req-1" });
+
+        let calls: Vec<_> = (0..64)
+            .map(|_| {
+                let cache = Arc::clone(&cache);
+                let runs = Arc::clone(&runs);
+                let arguments = arguments.clone();
+                tokio::spawn(async move {
+                    cache
+                        .call("spec_add_dependency", &arguments, || async {
+                            let run = runs.fetch_add(1, Ordering::SeqCst) + 1;
+                            tokio::task::yield_now().await;
+                            Ok(json!({ "run": run }))
+                        })
+                        .await
+                })
+            })
+            .collect();
+        for call in calls {
+            assert_eq!(call.await.unwrap(), Ok(json!({ "run": 1 })));
+        }
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_entries_expire_after_ttl() {
+        let cache = IdempotencyCache::new(Duration::from_secs(60));
+        let start = Instant::now();
+        let slot = cache.reserve_at("spec_create", "req-1", 0, start).unwrap();
+        *block_on(slot.lock()) = Some(json!({ "id": "1-auth" }));
+        drop(slot);
+
+        let fresh = cache
+            .reserve_at("spec_create", "req-1", 0, start + Duration::from_secs(59))
+            .unwrap();
+        let fresh_result = block_on(fresh.lock()).clone();
+        drop(fresh);
+        let expired = cache
+            .reserve_at("spec_create", "req-1", 0, start + Duration::from_secs(60))
+            .unwrap();
+
+        assert_eq!(fresh_result, Some(json!({ "id": "1-auth" })));
+        assert_eq!(*block_on(expired.lock()), None);
+    }
+}
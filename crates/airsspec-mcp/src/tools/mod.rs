@@ -0,0 +1,35 @@
+//! MCP tool implementations.
+//!
+//! - [`WorkspaceToolProvider`] -- [`ToolProvider`](airsprotocols_mcp::providers::ToolProvider)
+//!   exposing the workspace tools to MCP clients.
+//! - [`workspace_validate`] -- Runs workspace validation and renders the
+//!   report as structured JSON (the `workspace_validate` tool).
+//! - [`add_dependency`] / [`remove_dependency`] -- Edit one spec dependency
+//!   (the `spec_add_dependency` and `spec_remove_dependency` tools).
+//! - [`spec_search`] -- Case-insensitive full-text search over specs (the
+//!   `spec_search` tool).
+//! - [`tags_list`] -- Workspace tags with the specs carrying each (the
+//!   `tags_list` tool).
+//! - [`IdempotencyCache`] -- Replays results of mutating tool calls retried
+//!   with the same `idempotency_key`.
+//! - [`json_content`] -- Wraps structured tool output as pretty-printed JSON
+//!   content.
+
+mod content;
+mod idempotency;
+mod provider;
+mod spec_dependency;
+mod spec_search;
+mod tags_list;
+mod workspace_validate;
+
+pub use content::{json_content, pretty_json};
+pub use idempotency::{IDEMPOTENCY_KEY, IdempotencyCache};
+pub use provider::WorkspaceToolProvider;
+pub use spec_dependency::{
+    SPEC_ADD_DEPENDENCY, SPEC_REMOVE_DEPENDENCY, SpecDependencyError, add_dependency,
+    call_dependency_tool, dependency_input_schema, remove_dependency,
+};
+pub use spec_search::{SPEC_SEARCH, SpecSearchError, search_input_schema, spec_search};
+pub use tags_list::{TAGS_LIST, tags_list};
+pub use workspace_validate::{WORKSPACE_VALIDATE, report_to_json, workspace_validate};
@@ -0,0 +1,246 @@
+//! Tool provider for workspace-level tools.
+//!
+//! [`WorkspaceToolProvider`] implements the `airsprotocols-mcp`
+//! [`ToolProvider`] trait and dispatches `tools/call` requests to the tool
+//! functions in this module. Mutating tools honour an optional
+//! `idempotency_key` argument via [`IdempotencyCache`] and only run while
+//! the [`WorkspaceLock`] is held.
+
+// Layer 1: Standard library
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Layer 2: External crates
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+use airsprotocols_mcp::protocol::{Content, Tool};
+use airsprotocols_mcp::providers::ToolProvider;
+use airsprotocols_mcp::{McpError, McpResult};
+
+// Layer 3: Internal crates/modules
+use super::content::json_content;
+use super::idempotency::IdempotencyCache;
+use super::spec_dependency::{
+    SPEC_ADD_DEPENDENCY, SPEC_REMOVE_DEPENDENCY, call_dependency_tool, dependency_input_schema,
+};
+use super::spec_search::{SPEC_SEARCH, SpecSearchError, search_input_schema, spec_search};
+use super::tags_list::{TAGS_LIST, tags_list};
+use super::workspace_validate::{WORKSPACE_VALIDATE, workspace_validate};
+use crate::server::ordering::sort_by_str_key;
+use crate::storage::WorkspaceLock;
+
+/// Tool provider for tools that operate on the whole workspace.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use airsspec_mcp::tools::WorkspaceToolProvider;
+///
+/// let provider = WorkspaceToolProvider::new(PathBuf::from("/my/project"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WorkspaceToolProvider {
+    /// Workspace root directory.
+    workspace_path: PathBuf,
+
+    /// Recent results of keyed mutating calls, shared between clones.
+    idempotency: Arc<IdempotencyCache>,
+
+    /// Workspace lock held for the provider's lifetime, shared between
+    /// clones. Without one, each mutating call takes the lock itself.
+    lock: Option<Arc<WorkspaceLock>>,
+}
+
+impl WorkspaceToolProvider {
+    /// Creates a provider for the workspace rooted at `workspace_path`.
+    ///
+    /// Idempotency keys are remembered for
+    /// [`IdempotencyCache::DEFAULT_TTL`].
+    #[must_use]
+    pub fn new(workspace_path: PathBuf) -> Self {
+        Self {
+            workspace_path,
+            idempotency: Arc::new(IdempotencyCache::default()),
+            lock: None,
+        }
+    }
+
+    /// Holds `lock` until the provider and all its clones are dropped, so
+    /// mutating tools need not take the lock per call.
+    ///
+    /// The MCP server passes the lock it acquires at startup here.
+    #[must_use]
+    pub fn with_workspace_lock(mut self, lock: WorkspaceLock) -> Self {
+        self.lock = Some(Arc::new(lock));
+        self
+    }
+
+    /// Sets how long idempotency keys of mutating tools are remembered.
+    #[must_use]
+    pub fn with_idempotency_ttl(mut self, ttl: Duration) -> Self {
+        self.idempotency = Arc::new(IdempotencyCache::new(ttl));
+        self
+    }
+}
+
+#[async_trait]
+impl ToolProvider for WorkspaceToolProvider {
+    async fn list_tools(&self) -> McpResult<Vec<Tool>> {
+        let mut tools = vec![
+            Tool {
+                name: String::from(WORKSPACE_VALIDATE),
+                description: Some(String::from(
+                    "Validate the workspace and return the report as JSON",
+                )),
+                input_schema: json!({ "type": "object", "properties": {} }),
+            },
+            Tool {
+                name: String::from(SPEC_ADD_DEPENDENCY),
+                description: Some(String::from(
+                    "Add a dependency to a spec, rejecting self-references, cycles, \
+                     and edits that fail validation",
+                )),
+                input_schema: dependency_input_schema(),
+            },
+            Tool {
+                name: String::from(SPEC_REMOVE_DEPENDENCY),
+                description: Some(String::from("Remove a dependency from a spec")),
+                input_schema: dependency_input_schema(),
+            },
+            Tool {
+                name: String::from(SPEC_SEARCH),
+                description: Some(String::from(
+                    "Search spec titles, descriptions, and content for a query",
+                )),
+                input_schema: search_input_schema(),
+            },
+            Tool {
+                name: String::from(TAGS_LIST),
+                description: Some(String::from(
+                    "List spec tags with the number of specs carrying each",
+                )),
+                input_schema: json!({ "type": "object", "properties": {} }),
+            },
+        ];
+        sort_by_str_key(&mut tools, |tool| tool.name.as_str());
+        Ok(tools)
+    }
+
+    async fn call_tool(&self, name: &str, arguments: Value) -> McpResult<Vec<Content>> {
+        if name == WORKSPACE_VALIDATE {
+            let result = workspace_validate(&self.workspace_path).await;
+            return Ok(vec![json_content(&result)]);
+        }
+
+        let specs_dir = self.workspace_path.join(".airsspec").join("specs");
+        if name == SPEC_SEARCH {
+            let query = arguments
+                .get("query")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            let result = spec_search(&specs_dir, query)
+                .await
+                .map_err(|err| match err {
+                    SpecSearchError::EmptyQuery => McpError::invalid_request(err.to_string()),
+                    SpecSearchError::Spec(_) => McpError::internal_error(format!("{name}: {err}")),
+                })?;
+            return Ok(vec![json_content(&result)]);
+        }
+        if name == TAGS_LIST {
+            let result = tags_list(&specs_dir)
+                .await
+                .map_err(|err| McpError::internal_error(format!("{name}: {err}")))?;
+            return Ok(vec![json_content(&result)]);
+        }
+
+        if name != SPEC_ADD_DEPENDENCY && name != SPEC_REMOVE_DEPENDENCY {
+            return Err(McpError::tool_not_found(name));
+        }
+        let _call_lock = if self.lock.is_some() {
+            None
+        } else {
+            Some(
+                WorkspaceLock::acquire(&self.workspace_path)
+                    .map_err(|err| McpError::internal_error(format!("{name}: {err}")))?,
+            )
+        };
+        // Rejected edits are tool errors, reported to the client with
+        // `isError` set rather than as successful content.
+        let result = self
+            .idempotency
+            .call(name, &arguments, || async {
+                call_dependency_tool(&specs_dir, name, arguments.clone())
+                    .await
+                    .unwrap_or_else(|| Err(format!("unknown tool: {name}")))
+            })
+            .await
+            .map_err(McpError::invalid_request)?;
+        Ok(vec![json_content(&result)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lists_workspace_tools() {
+        let provider = WorkspaceToolProvider::new(PathBuf::from("/nonexistent"));
+        let tools = provider.list_tools().await.unwrap();
+
+        let names: Vec<&str> = tools.iter().map(|tool| tool.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                SPEC_ADD_DEPENDENCY,
+                SPEC_REMOVE_DEPENDENCY,
+                SPEC_SEARCH,
+                TAGS_LIST,
+                WORKSPACE_VALIDATE
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mutating_tool_requires_workspace_lock() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(temp.path().join(".airsspec/specs")).unwrap();
+        let _held = WorkspaceLock::acquire(temp.path()).unwrap();
+        let provider = WorkspaceToolProvider::new(temp.path().to_path_buf());
+
+        let result = provider
+            .call_tool(
+                SPEC_REMOVE_DEPENDENCY,
+                json!({ "spec_id": "1000000-a", "target": "1000001-b" }),
+            )
+            .await;
+
+        let Err(err) = result else {
+            panic!("expected the held lock to reject the call");
+        };
+        assert!(err.to_string().contains("locked"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_blank_search_query_is_tool_error() {
+        let temp = tempfile::tempdir().unwrap();
+        let provider = WorkspaceToolProvider::new(temp.path().to_path_buf());
+
+        let result = provider
+            .call_tool(SPEC_SEARCH, json!({ "query": " " }))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_tool_is_not_found() {
+        let provider = WorkspaceToolProvider::new(PathBuf::from("/nonexistent"));
+        let result = provider.call_tool("no_such_tool", json!({})).await;
+
+        assert!(result.is_err());
+    }
+}
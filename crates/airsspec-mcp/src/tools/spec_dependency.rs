@@ -0,0 +1,509 @@
+//! The `spec_add_dependency` and `spec_remove_dependency` tools.
+//!
+//! Edits a single dependency of a stored spec without rewriting the rest of
+//! it. Adding a dependency is checked against the whole workspace first:
+//! self-references and cycles of `blocked_by` dependencies are rejected,
+//! while a target that does not exist (yet) is allowed and reported as a
+//! warning.
+//!
+//! Both tools take `{"spec_id", "target", "kind"}` arguments, where `kind`
+//! is a [`DependencyKind`] in snake case and defaults to `related_to`.
+
+// Layer 1: Standard library
+use std::collections::HashMap;
+use std::path::Path;
+
+// Layer 2: External crates
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::spec::{
+    Dependency, DependencyKind, Spec, SpecError, SpecId, SpecStorage as _, validate_spec,
+};
+use airsspec_core::validation::{BlockingGraph, ValidationIssue, ValidationReport};
+
+use super::workspace_validate::report_to_json;
+use crate::storage::FileSystemSpecStorage;
+
+/// Tool name for adding a dependency, advertised in `tools/list`.
+pub const SPEC_ADD_DEPENDENCY: &str = "spec_add_dependency";
+
+/// Tool name for removing a dependency, advertised in `tools/list`.
+pub const SPEC_REMOVE_DEPENDENCY: &str = "spec_remove_dependency";
+
+/// Arguments accepted by both dependency tools.
+#[derive(Debug, Deserialize)]
+struct DependencyArgs {
+    spec_id: String,
+    target: String,
+    #[serde(default)]
+    kind: DependencyKind,
+}
+
+/// JSON schema for [`DependencyArgs`], advertised in `tools/list`.
+#[must_use]
+pub fn dependency_input_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "spec_id": { "type": "string", "description": "Spec to edit" },
+            "target": { "type": "string", "description": "Dependency target spec ID" },
+            "kind": {
+                "type": "string",
+                "enum": ["blocked_by", "related_to", "child_of", "parent_of"],
+                "default": "related_to"
+            },
+            "idempotency_key": {
+                "type": "string",
+                "description": "Repeat a retried call's original result instead of applying it again"
+            }
+        },
+        "required": ["spec_id", "target"]
+    })
+}
+
+/// Runs the dependency tool `name` with JSON `arguments`.
+///
+/// Returns `None` if `name` is not a dependency tool. Otherwise returns the
+/// JSON result, `{"added": true, "report": ...}` or `{"removed": bool}`, or
+/// an error message when the arguments are invalid or the edit is rejected.
+pub async fn call_dependency_tool(
+    specs_dir: &Path,
+    name: &str,
+    arguments: Value,
+) -> Option<Result<Value, String>> {
+    if name != SPEC_ADD_DEPENDENCY && name != SPEC_REMOVE_DEPENDENCY {
+        return None;
+    }
+
+    let args: DependencyArgs = match serde_json::from_value(arguments) {
+        Ok(args) => args,
+        Err(err) => return Some(Err(format!("invalid arguments: {err}"))),
+    };
+    let (spec_id, target) = match (SpecId::parse(&args.spec_id), SpecId::parse(&args.target)) {
+        (Ok(spec_id), Ok(target)) => (spec_id, target),
+        (Err(err), _) | (_, Err(err)) => return Some(Err(err.to_string())),
+    };
+
+    let result = if name == SPEC_ADD_DEPENDENCY {
+        add_dependency(specs_dir, &spec_id, &target, args.kind)
+            .await
+            .map(|report| json!({ "added": true, "report": report_to_json(&report) }))
+    } else {
+        remove_dependency(specs_dir, &spec_id, &target, args.kind)
+            .await
+            .map(|removed| json!({ "removed": removed }))
+    };
+    Some(result.map_err(|err| err.to_string()))
+}
+
+/// Errors that reject a dependency edit.
+#[derive(Debug, thiserror::Error)]
+pub enum SpecDependencyError {
+    /// The spec would depend on itself.
+    #[error("spec '{0}' cannot depend on itself")]
+    SelfReference(String),
+
+    /// The new dependency would close a cycle in the dependency graph.
+    #[error("adding '{target}' to '{spec}' would create a circular dependency")]
+    Cycle {
+        /// The spec being edited.
+        spec: String,
+        /// The dependency target that leads back to `spec`.
+        target: String,
+    },
+
+    /// The edited spec would fail validation, so it was not saved.
+    #[error("adding '{target}' to '{spec}' would make it invalid: {errors}")]
+    Invalid {
+        /// The spec being edited.
+        spec: String,
+        /// The dependency target.
+        target: String,
+        /// The validation errors, separated by `; `.
+        errors: String,
+    },
+
+    /// Loading or saving the spec failed.
+    #[error(transparent)]
+    Spec(#[from] SpecError),
+}
+
+/// Adds a `kind` dependency on `target` to the spec `spec_id`, validates
+/// the result, then saves it.
+///
+/// Adding a dependency that is already present is a no-op. Returns the
+/// spec's validation report after the edit, including a warning if
+/// `target` does not exist in the workspace.
+///
+/// # Errors
+///
+/// - [`SpecDependencyError::SelfReference`] if `target` is `spec_id`
+/// - [`SpecDependencyError::Cycle`] if `kind` is `BlockedBy` and `target` is
+///   already blocked, directly or transitively, by `spec_id`
+/// - [`SpecDependencyError::Invalid`] if the edited spec has validation
+///   errors, e.g. too many dependencies; nothing is saved
+/// - [`SpecDependencyError::Spec`] if the spec cannot be loaded or saved,
+///   including [`SpecError::Conflict`] if it was edited on disk meanwhile
+pub async fn add_dependency(
+    specs_dir: &Path,
+    spec_id: &SpecId,
+    target: &SpecId,
+    kind: DependencyKind,
+) -> Result<ValidationReport, SpecDependencyError> {
+    if spec_id == target {
+        return Err(SpecDependencyError::SelfReference(spec_id.to_string()));
+    }
+
+    let storage = FileSystemSpecStorage::new(specs_dir);
+    let mut spec = storage.load_spec(spec_id).await?;
+    let loaded_hash = FileSystemSpecStorage::spec_hash(&spec)?;
+    let workspace = load_workspace_specs(&storage).await;
+
+    if kind == DependencyKind::BlockedBy
+        && BlockingGraph::new(workspace.values()).reaches(target.as_str(), spec_id.as_str())
+    {
+        return Err(SpecDependencyError::Cycle {
+            spec: spec_id.to_string(),
+            target: target.to_string(),
+        });
+    }
+
+    let dependency = Dependency::new(target.clone(), kind);
+    let changed = !spec.dependencies().contains(&dependency);
+    if changed {
+        spec.metadata_mut().add_dependency(dependency);
+    }
+
+    let mut report = validate_spec(&spec);
+    if !report.is_valid() {
+        let errors: Vec<&str> = report.errors().iter().map(|e| e.message()).collect();
+        return Err(SpecDependencyError::Invalid {
+            spec: spec_id.to_string(),
+            target: target.to_string(),
+            errors: errors.join("; "),
+        });
+    }
+    if changed {
+        storage.save_spec_if_unchanged(&spec, &loaded_hash).await?;
+    }
+
+    if !workspace.contains_key(target.as_str()) {
+        report.add_issue(
+            ValidationIssue::warning(format!(
+                "Dependency target '{target}' does not exist in the workspace"
+            ))
+            .with_field("metadata.dependencies"),
+        );
+    }
+    Ok(report)
+}
+
+/// Removes the `kind` dependency on `target` from the spec `spec_id`.
+///
+/// Returns `true` if a dependency was removed and the spec saved, or
+/// `false` if the spec had no such dependency.
+///
+/// # Errors
+///
+/// Returns [`SpecDependencyError::Spec`] if the spec cannot be loaded or
+/// saved, including [`SpecError::Conflict`] if it was edited on disk
+/// meanwhile.
+pub async fn remove_dependency(
+    specs_dir: &Path,
+    spec_id: &SpecId,
+    target: &SpecId,
+    kind: DependencyKind,
+) -> Result<bool, SpecDependencyError> {
+    let storage = FileSystemSpecStorage::new(specs_dir);
+    let mut spec = storage.load_spec(spec_id).await?;
+    let loaded_hash = FileSystemSpecStorage::spec_hash(&spec)?;
+
+    let dependencies = spec.dependencies();
+    let remaining: Vec<Dependency> = dependencies
+        .iter()
+        .filter(|dep| !(dep.spec_id == *target && dep.kind == kind))
+        .cloned()
+        .collect();
+    if remaining.len() == dependencies.len() {
+        return Ok(false);
+    }
+
+    spec.metadata_mut().set_dependencies(remaining);
+    storage.save_spec_if_unchanged(&spec, &loaded_hash).await?;
+    Ok(true)
+}
+
+/// Loads every readable spec, keyed by ID. Unreadable specs are skipped:
+/// they cannot contribute edges to the graph.
+async fn load_workspace_specs(storage: &FileSystemSpecStorage) -> HashMap<String, Spec> {
+    let mut specs = HashMap::new();
+    for id in storage.list_specs().await.unwrap_or_default() {
+        if let Ok(spec) = storage.load_spec(&id).await {
+            specs.insert(id.to_string(), spec);
+        }
+    }
+    specs
+}
+
+#[cfg(test)]
+mod tests {
+    use airsspec_core::spec::{SpecMetadata, SpecStorage};
+
+    use crate::test_support::block_on;
+
+    use super::*;
+
+    fn save_spec(dir: &Path, timestamp: i64, slug: &str, deps: Vec<Dependency>) -> SpecId {
+        let id = SpecId::new(timestamp, slug);
+        let mut metadata = SpecMetadata::new(slug, "Dependency tool test");
+        metadata.set_dependencies(deps);
+        let spec = Spec::new(id.clone(), metadata, "Content");
+        block_on(FileSystemSpecStorage::new(dir).save_spec(&spec)).unwrap();
+        id
+    }
+
+    fn load(dir: &Path, id: &SpecId) -> Spec {
+        block_on(FileSystemSpecStorage::new(dir).load_spec(id)).unwrap()
+    }
+
+    #[test]
+    fn test_add_dependency_persists() {
+        let temp = tempfile::tempdir().unwrap();
+        let a = save_spec(temp.path(), 1_000_000, "a", vec![]);
+        let b = save_spec(temp.path(), 1_000_001, "b", vec![]);
+
+        let report = block_on(add_dependency(
+            temp.path(),
+            &a,
+            &b,
+            DependencyKind::BlockedBy,
+        ))
+        .unwrap();
+
+        assert!(report.is_empty(), "got: {:?}", report.issues());
+        assert_eq!(
+            load(temp.path(), &a).dependencies(),
+            &[Dependency::blocked_by(b)]
+        );
+    }
+
+    #[test]
+    fn test_add_dangling_dependency_warns() {
+        let temp = tempfile::tempdir().unwrap();
+        let a = save_spec(temp.path(), 1_000_000, "a", vec![]);
+        let missing = SpecId::new(9_999_999, "missing");
+
+        let report = block_on(add_dependency(
+            temp.path(),
+            &a,
+            &missing,
+            DependencyKind::RelatedTo,
+        ))
+        .unwrap();
+
+        assert!(report.is_valid());
+        assert!(report.warnings()[0].message().contains("does not exist"));
+        assert_eq!(load(temp.path(), &a).dependencies().len(), 1);
+    }
+
+    #[test]
+    fn test_add_self_reference_rejected() {
+        let temp = tempfile::tempdir().unwrap();
+        let a = save_spec(temp.path(), 1_000_000, "a", vec![]);
+
+        let result = block_on(add_dependency(
+            temp.path(),
+            &a,
+            &a,
+            DependencyKind::BlockedBy,
+        ));
+
+        assert!(matches!(result, Err(SpecDependencyError::SelfReference(_))));
+        assert!(load(temp.path(), &a).dependencies().is_empty());
+    }
+
+    #[test]
+    fn test_add_cycle_rejected() {
+        let temp = tempfile::tempdir().unwrap();
+        let a = save_spec(temp.path(), 1_000_000, "a", vec![]);
+        let b = save_spec(
+            temp.path(),
+            1_000_001,
+            "b",
+            vec![Dependency::blocked_by(SpecId::new(1_000_002, "c"))],
+        );
+        save_spec(
+            temp.path(),
+            1_000_002,
+            "c",
+            vec![Dependency::blocked_by(a.clone())],
+        );
+
+        let result = block_on(add_dependency(
+            temp.path(),
+            &a,
+            &b,
+            DependencyKind::BlockedBy,
+        ));
+
+        assert!(matches!(result, Err(SpecDependencyError::Cycle { .. })));
+        assert!(load(temp.path(), &a).dependencies().is_empty());
+    }
+
+    #[test]
+    fn test_add_related_back_edge_allowed() {
+        let temp = tempfile::tempdir().unwrap();
+        let a = save_spec(temp.path(), 1_000_000, "a", vec![]);
+        let b = save_spec(
+            temp.path(),
+            1_000_001,
+            "b",
+            vec![Dependency::blocked_by(a.clone())],
+        );
+
+        let report = block_on(add_dependency(
+            temp.path(),
+            &a,
+            &b,
+            DependencyKind::RelatedTo,
+        ))
+        .unwrap();
+
+        assert!(report.is_valid());
+        assert_eq!(
+            load(temp.path(), &a).dependencies(),
+            &[Dependency::related_to(b)]
+        );
+    }
+
+    #[test]
+    fn test_add_invalidating_dependency_not_saved() {
+        let temp = tempfile::tempdir().unwrap();
+        let existing: Vec<Dependency> = (0..50)
+            .map(|i| Dependency::related_to(SpecId::new(2_000_000 + i, "dep")))
+            .collect();
+        let a = save_spec(temp.path(), 1_000_000, "a", existing);
+        let b = save_spec(temp.path(), 1_000_001, "b", vec![]);
+
+        let result = block_on(add_dependency(
+            temp.path(),
+            &a,
+            &b,
+            DependencyKind::RelatedTo,
+        ));
+
+        assert!(
+            matches!(result, Err(SpecDependencyError::Invalid { ref errors, .. }) if errors.contains("Too many dependencies")),
+            "got: {result:?}"
+        );
+        assert_eq!(load(temp.path(), &a).dependencies().len(), 50);
+    }
+
+    #[test]
+    fn test_remove_dependency() {
+        let temp = tempfile::tempdir().unwrap();
+        let b = SpecId::new(1_000_001, "b");
+        let a = save_spec(
+            temp.path(),
+            1_000_000,
+            "a",
+            vec![
+                Dependency::blocked_by(b.clone()),
+                Dependency::related_to(b.clone()),
+            ],
+        );
+
+        let removed = block_on(remove_dependency(
+            temp.path(),
+            &a,
+            &b,
+            DependencyKind::BlockedBy,
+        ))
+        .unwrap();
+        let removed_again = block_on(remove_dependency(
+            temp.path(),
+            &a,
+            &b,
+            DependencyKind::BlockedBy,
+        ))
+        .unwrap();
+
+        assert!(removed);
+        assert!(!removed_again);
+        assert_eq!(
+            load(temp.path(), &a).dependencies(),
+            &[Dependency::related_to(b)]
+        );
+    }
+
+    #[test]
+    fn test_call_dependency_tool_round_trip() {
+        let temp = tempfile::tempdir().unwrap();
+        let a = save_spec(temp.path(), 1_000_000, "a", vec![]);
+        save_spec(temp.path(), 1_000_001, "b", vec![]);
+        let args = json!({ "spec_id": "1000000-a", "target": "1000001-b", "kind": "blocked_by" });
+
+        let added = block_on(call_dependency_tool(
+            temp.path(),
+            SPEC_ADD_DEPENDENCY,
+            args.clone(),
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!(added["added"], json!(true));
+        assert_eq!(added["report"]["valid"], json!(true));
+
+        let removed = block_on(call_dependency_tool(
+            temp.path(),
+            SPEC_REMOVE_DEPENDENCY,
+            args,
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!(removed, json!({ "removed": true }));
+        assert!(load(temp.path(), &a).dependencies().is_empty());
+    }
+
+    #[test]
+    fn test_call_dependency_tool_reports_errors() {
+        let temp = tempfile::tempdir().unwrap();
+        save_spec(temp.path(), 1_000_000, "a", vec![]);
+
+        let self_ref = block_on(call_dependency_tool(
+            temp.path(),
+            SPEC_ADD_DEPENDENCY,
+            json!({ "spec_id": "1000000-a", "target": "1000000-a" }),
+        ))
+        .unwrap()
+        .unwrap_err();
+        let bad_args = block_on(call_dependency_tool(
+            temp.path(),
+            SPEC_ADD_DEPENDENCY,
+            json!({ "spec_id": "1000000-a" }),
+        ))
+        .unwrap()
+        .unwrap_err();
+
+        assert!(self_ref.contains("itself"), "{self_ref}");
+        assert!(bad_args.contains("invalid arguments"), "{bad_args}");
+        assert!(block_on(call_dependency_tool(temp.path(), "other", json!({}))).is_none());
+    }
+
+    #[test]
+    fn test_missing_spec_is_error() {
+        let temp = tempfile::tempdir().unwrap();
+        let a = SpecId::new(1_000_000, "a");
+        let b = SpecId::new(1_000_001, "b");
+
+        let result = block_on(add_dependency(
+            temp.path(),
+            &a,
+            &b,
+            DependencyKind::BlockedBy,
+        ));
+
+        assert!(matches!(result, Err(SpecDependencyError::Spec(_))));
+    }
+}
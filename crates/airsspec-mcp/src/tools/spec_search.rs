@@ -0,0 +1,263 @@
+//! The `spec_search` tool.
+//!
+//! Case-insensitive full-text search over spec titles, descriptions, and
+//! content. Results are ranked by where the query first matches -- title,
+//! then description, then content -- and carry a short snippet around the
+//! match.
+
+// Layer 1: Standard library
+use std::path::Path;
+
+// Layer 2: External crates
+use serde_json::{Value, json};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::spec::{Spec, SpecError, SpecStorage as _};
+
+use crate::storage::FileSystemSpecStorage;
+
+/// Tool name advertised in `tools/list`.
+pub const SPEC_SEARCH: &str = "spec_search";
+
+/// Characters of context kept on each side of a match in a snippet.
+const SNIPPET_CONTEXT: usize = 40;
+
+/// Where a query matched within a spec, in ranking order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchField {
+    Title,
+    Description,
+    Content,
+}
+
+impl MatchField {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Description => "description",
+            Self::Content => "content",
+        }
+    }
+}
+
+/// Errors that fail a search.
+#[derive(Debug, thiserror::Error)]
+pub enum SpecSearchError {
+    /// The query is empty or whitespace only.
+    #[error("query must not be empty")]
+    EmptyQuery,
+
+    /// Listing or loading the specs failed.
+    #[error(transparent)]
+    Spec(#[from] SpecError),
+}
+
+/// JSON schema for the `spec_search` arguments, advertised in `tools/list`.
+#[must_use]
+pub fn search_input_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "query": { "type": "string", "description": "Text to search for" }
+        },
+        "required": ["query"]
+    })
+}
+
+/// Searches the specs in `specs_dir` for `query`.
+///
+/// Returns `{"query", "results": [{"id", "title", "matchedIn", "snippet"}]}`,
+/// ranked title matches first, then description, then content; ties keep
+/// spec ID order.
+///
+/// # Errors
+///
+/// Returns [`SpecSearchError::EmptyQuery`] for a blank query, and
+/// [`SpecSearchError::Spec`] if the specs cannot be listed or any spec
+/// cannot be loaded.
+pub async fn spec_search(specs_dir: &Path, query: &str) -> Result<Value, SpecSearchError> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Err(SpecSearchError::EmptyQuery);
+    }
+
+    let storage = FileSystemSpecStorage::new(specs_dir);
+    let mut ids = storage.list_specs().await?;
+    ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+    let mut hits = Vec::new();
+    for id in ids {
+        let spec = storage.load_spec(&id).await?;
+        if let Some((field, snippet)) = find_match(&spec, &needle) {
+            hits.push((field, spec, snippet));
+        }
+    }
+    hits.sort_by_key(|(field, _, _)| *field);
+
+    let results: Vec<Value> = hits
+        .iter()
+        .map(|(field, spec, snippet)| {
+            json!({
+                "id": spec.id().as_str(),
+                "title": spec.title(),
+                "matchedIn": field.as_str(),
+                "snippet": snippet,
+            })
+        })
+        .collect();
+    Ok(json!({ "query": query, "results": results }))
+}
+
+/// Returns the highest-ranked field containing `needle` (already
+/// lowercased) and a snippet around the match.
+fn find_match(spec: &Spec, needle: &str) -> Option<(MatchField, String)> {
+    [
+        (MatchField::Title, spec.title()),
+        (MatchField::Description, spec.description()),
+        (MatchField::Content, spec.content()),
+    ]
+    .into_iter()
+    .find_map(|(field, text)| make_snippet(text, needle).map(|snippet| (field, snippet)))
+}
+
+/// Returns a one-line snippet of `text` around the first case-insensitive
+/// occurrence of `needle`, or `None` if it does not occur.
+///
+/// Up to [`SNIPPET_CONTEXT`] characters are kept on each side of the match;
+/// trimmed ends are marked with `...`.
+fn make_snippet(text: &str, needle: &str) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let (match_start, match_end) = find_folded(&chars, needle)?;
+
+    let from = match_start.saturating_sub(SNIPPET_CONTEXT);
+    let to = (match_end + SNIPPET_CONTEXT).min(chars.len());
+    let body: String = chars[from..to].iter().collect();
+    let body = body.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let prefix = if from > 0 { "..." } else { "" };
+    let suffix = if to < chars.len() { "..." } else { "" };
+    Some(format!("{prefix}{body}{suffix}"))
+}
+
+/// Returns the character range of the first occurrence of `needle`
+/// (already lowercased) in `chars`, comparing each character lowercased.
+///
+/// Matching works on characters rather than byte offsets because
+/// lowercasing can change a character's length, and even split one
+/// character into several (`İ` becomes `i̇`).
+fn find_folded(chars: &[char], needle: &str) -> Option<(usize, usize)> {
+    (0..chars.len()).find_map(|start| {
+        let mut folded = chars[start..]
+            .iter()
+            .enumerate()
+            .flat_map(|(offset, c)| c.to_lowercase().map(move |lower| (offset, lower)));
+        let mut end = start;
+        for expected in needle.chars() {
+            let (offset, actual) = folded.next()?;
+            if actual != expected {
+                return None;
+            }
+            end = start + offset + 1;
+        }
+        Some((start, end))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use airsspec_core::spec::{SpecId, SpecMetadata, SpecStorage};
+
+    use crate::test_support::block_on;
+
+    use super::*;
+
+    fn save_spec(dir: &Path, timestamp: i64, title: &str, content: &str) {
+        let spec = Spec::new(
+            SpecId::new(timestamp, "spec"),
+            SpecMetadata::new(title, "Search test"),
+            content,
+        );
+        block_on(FileSystemSpecStorage::new(dir).save_spec(&spec)).unwrap();
+    }
+
+    #[test]
+    fn test_title_match_ranks_above_content_match() {
+        let temp = tempfile::tempdir().unwrap();
+        save_spec(
+            temp.path(),
+            1_000_000,
+            "Billing",
+            "Charge cards via OAuth tokens.",
+        );
+        save_spec(temp.path(), 2_000_000, "OAuth Login", "Sign in flow.");
+
+        let result = block_on(spec_search(temp.path(), "oauth")).unwrap();
+
+        let results = result["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["id"], json!("2000000-spec"));
+        assert_eq!(results[0]["matchedIn"], json!("title"));
+        assert_eq!(results[1]["id"], json!("1000000-spec"));
+        assert_eq!(results[1]["matchedIn"], json!("content"));
+        assert_eq!(
+            results[1]["snippet"],
+            json!("Charge cards via OAuth tokens.")
+        );
+    }
+
+    #[test]
+    fn test_no_match_returns_empty() {
+        let temp = tempfile::tempdir().unwrap();
+        save_spec(temp.path(), 1_000_000, "Billing", "Charge cards.");
+
+        let result = block_on(spec_search(temp.path(), "kubernetes")).unwrap();
+
+        assert_eq!(result["results"], json!([]));
+    }
+
+    #[test]
+    fn test_blank_query_is_error() {
+        let temp = tempfile::tempdir().unwrap();
+
+        let result = block_on(spec_search(temp.path(), "  "));
+
+        assert!(matches!(result, Err(SpecSearchError::EmptyQuery)));
+    }
+
+    #[test]
+    fn test_unreadable_spec_is_error() {
+        let temp = tempfile::tempdir().unwrap();
+        save_spec(temp.path(), 1_000_000, "Billing", "Charge cards.");
+        std::fs::write(temp.path().join("2000000-broken.yaml"), "id: [").unwrap();
+
+        let result = block_on(spec_search(temp.path(), "billing"));
+
+        assert!(
+            matches!(result, Err(SpecSearchError::Spec(_))),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn test_snippet_survives_case_folding_length_changes() {
+        // `İ` lowercases to two characters and `ẞ` to a shorter encoding,
+        // so byte offsets in the lowercased text do not map onto `text`.
+        let snippet = make_snippet("İẞ and more", "ß").unwrap();
+        assert_eq!(snippet, "İẞ and more");
+
+        let snippet = make_snippet("xİy", "i̇y").unwrap();
+        assert_eq!(snippet, "xİy");
+    }
+
+    #[test]
+    fn test_snippet_trims_long_text() {
+        let text = format!("{} needle {}", "a ".repeat(50), "b ".repeat(50));
+
+        let snippet = make_snippet(&text, "needle").unwrap();
+
+        assert!(snippet.starts_with("..."));
+        assert!(snippet.ends_with("..."));
+        assert!(snippet.contains("needle"));
+        assert!(snippet.chars().count() < text.chars().count());
+    }
+}
@@ -0,0 +1,104 @@
+//! The `tags_list` tool.
+//!
+//! Lists every tag used across the workspace's specs with the number of
+//! specs carrying it, built from [`build_tag_index`].
+
+// Layer 1: Standard library
+use std::path::Path;
+
+// Layer 2: External crates
+use serde_json::{Value, json};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::spec::{SpecError, SpecId, SpecStorage as _, build_tag_index};
+
+use crate::storage::FileSystemSpecStorage;
+
+/// Tool name advertised in `tools/list`.
+pub const TAGS_LIST: &str = "tags_list";
+
+/// Lists the tags of the specs in `specs_dir`.
+///
+/// Returns `{"tags": [{"tag", "count", "specs"}]}` sorted by tag. Tags are
+/// normalized as in [`build_tag_index`]; `specs` lists the IDs carrying the
+/// tag in ID order.
+///
+/// # Errors
+///
+/// Returns an error if the specs cannot be listed or any spec cannot be
+/// loaded, rather than reporting counts that silently leave it out.
+pub async fn tags_list(specs_dir: &Path) -> Result<Value, SpecError> {
+    let storage = FileSystemSpecStorage::new(specs_dir);
+    let mut ids = storage.list_specs().await?;
+    ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+    let mut specs = Vec::new();
+    for id in ids {
+        specs.push(storage.load_spec(&id).await?);
+    }
+
+    let tags: Vec<Value> = build_tag_index(&specs)
+        .into_iter()
+        .map(|(tag, ids)| {
+            let ids: Vec<&str> = ids.iter().map(SpecId::as_str).collect();
+            json!({ "tag": tag, "count": ids.len(), "specs": ids })
+        })
+        .collect();
+    Ok(json!({ "tags": tags }))
+}
+
+#[cfg(test)]
+mod tests {
+    use airsspec_core::spec::{Spec, SpecMetadata, SpecStorage};
+
+    use crate::test_support::block_on;
+
+    use super::*;
+
+    fn save_tagged_spec(dir: &Path, timestamp: i64, tags: &[&str]) {
+        let mut metadata = SpecMetadata::new("Tagged", "Tags test");
+        metadata.set_tags(tags.iter().map(ToString::to_string).collect());
+        let spec = Spec::new(SpecId::new(timestamp, "spec"), metadata, "");
+        block_on(FileSystemSpecStorage::new(dir).save_spec(&spec)).unwrap();
+    }
+
+    #[test]
+    fn test_tags_sorted_with_counts() {
+        let temp = tempfile::tempdir().unwrap();
+        save_tagged_spec(temp.path(), 2_000_000, &["search", "API"]);
+        save_tagged_spec(temp.path(), 1_000_000, &["api"]);
+        save_tagged_spec(temp.path(), 3_000_000, &[]);
+
+        let result = block_on(tags_list(temp.path())).unwrap();
+
+        assert_eq!(
+            result,
+            json!({
+                "tags": [
+                    { "tag": "api", "count": 2, "specs": ["1000000-spec", "2000000-spec"] },
+                    { "tag": "search", "count": 1, "specs": ["2000000-spec"] },
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_empty_workspace_has_no_tags() {
+        let temp = tempfile::tempdir().unwrap();
+
+        let result = block_on(tags_list(temp.path())).unwrap();
+
+        assert_eq!(result, json!({ "tags": [] }));
+    }
+
+    #[test]
+    fn test_unreadable_spec_is_error() {
+        let temp = tempfile::tempdir().unwrap();
+        save_tagged_spec(temp.path(), 1_000_000, &["api"]);
+        std::fs::write(temp.path().join("2000000-broken.yaml"), "id: [").unwrap();
+
+        let result = block_on(tags_list(temp.path()));
+
+        assert!(result.is_err(), "{result:?}");
+    }
+}
@@ -0,0 +1,151 @@
+//! The `workspace_validate` tool.
+//!
+//! Runs [`validate_workspace`] and renders the resulting
+//! [`ValidationReport`] as structured JSON, so MCP clients can trigger
+//! validation without shelling out to `airsspec validate`.
+//!
+//! # Output Format
+//!
+//! ```json
+//! {
+//!   "valid": false,
+//!   "errorCount": 1,
+//!   "warningCount": 0,
+//!   "issues": [
+//!     { "severity": "error", "message": "...", "field": "[1737734400-auth] title" }
+//!   ]
+//! }
+//! ```
+//!
+//! `field` is omitted when the issue has no field.
+
+// Layer 1: Standard library
+use std::path::Path;
+
+// Layer 2: External crates
+use serde_json::{Map, Value, json};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::validation::ValidationReport;
+
+use crate::validation::validate_workspace;
+
+/// Tool name advertised in `tools/list`.
+pub const WORKSPACE_VALIDATE: &str = "workspace_validate";
+
+/// Validates the workspace at `workspace_path` and returns the report as JSON.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use airsspec_mcp::tools::workspace_validate;
+///
+/// # async fn example() {
+/// let result = workspace_validate(Path::new("/my/project")).await;
+/// println!("valid: {}", result["valid"]);
+/// # }
+/// ```
+pub async fn workspace_validate(workspace_path: &Path) -> Value {
+    let report = validate_workspace(workspace_path).await;
+    report_to_json(&report)
+}
+
+/// Renders a [`ValidationReport`] as the `workspace_validate` JSON payload.
+#[must_use]
+pub fn report_to_json(report: &ValidationReport) -> Value {
+    let issues: Vec<Value> = report
+        .issues()
+        .iter()
+        .map(|issue| {
+            let mut entry = Map::new();
+            entry.insert("severity".into(), json!(issue.severity().to_string()));
+            entry.insert("message".into(), json!(issue.message()));
+            if let Some(field) = issue.field() {
+                entry.insert("field".into(), json!(field));
+            }
+            Value::Object(entry)
+        })
+        .collect();
+
+    json!({
+        "valid": report.is_valid(),
+        "errorCount": report.error_count(),
+        "warningCount": report.warning_count(),
+        "issues": issues,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use airsspec_core::spec::{Spec, SpecId, SpecMetadata, SpecStorage};
+    use airsspec_core::validation::ValidationIssue;
+
+    use crate::storage::FileSystemSpecStorage;
+    use crate::test_support::{block_on, create_workspace};
+
+    use super::*;
+
+    #[test]
+    fn test_clean_workspace_is_valid() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+
+        let specs_dir = temp.path().join(".airsspec/specs");
+        let metadata = SpecMetadata::new("Feature", "A clean spec");
+        let spec = Spec::new(SpecId::new(1_000_000, "feature"), metadata, "# Feature");
+        block_on(FileSystemSpecStorage::new(&specs_dir).save_spec(&spec)).unwrap();
+
+        let result = block_on(workspace_validate(temp.path()));
+
+        assert_eq!(result["valid"], json!(true));
+        assert_eq!(result["errorCount"], json!(0));
+        assert_eq!(result["issues"], json!([]));
+    }
+
+    #[test]
+    fn test_invalid_spec_reports_issues() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        fs::write(
+            temp.path().join(".airsspec/specs/1000000-bad.yaml"),
+            "this is not valid yaml: [[[",
+        )
+        .unwrap();
+
+        let result = block_on(workspace_validate(temp.path()));
+
+        assert_eq!(result["valid"], json!(false));
+        assert_eq!(result["errorCount"], json!(1));
+        let issues = result["issues"].as_array().unwrap();
+        assert!(
+            issues.iter().any(|issue| issue["severity"] == "error"
+                && issue["message"]
+                    .as_str()
+                    .is_some_and(|m| m.contains("Failed to load spec"))),
+            "expected load error, got: {issues:?}"
+        );
+    }
+
+    #[test]
+    fn test_report_to_json_omits_missing_field() {
+        let report = ValidationReport::from_issues([
+            ValidationIssue::warning("No field"),
+            ValidationIssue::error("Bad title").with_field("title"),
+        ]);
+
+        let result = report_to_json(&report);
+
+        assert_eq!(result["valid"], json!(false));
+        assert_eq!(result["warningCount"], json!(1));
+        assert_eq!(
+            result["issues"],
+            json!([
+                { "severity": "warning", "message": "No field" },
+                { "severity": "error", "message": "Bad title", "field": "title" },
+            ])
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Cooperative cancellation for long-running validation.
+//!
+//! Provides [`CancellationToken`], a cheaply cloneable flag shared between
+//! the caller and [`validate_workspace_with_limits`](super::validate_workspace_with_limits).
+//! The runner checks the flag between specs and between validator phases,
+//! so cancellation takes effect at the next checkpoint rather than instantly.
+
+// Layer 1: Standard library
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A shared flag used to request that validation stop early.
+///
+/// Clones share the same underlying flag: cancelling any clone cancels
+/// them all. Once cancelled, a token stays cancelled.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_mcp::validation::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let handle = token.clone();
+///
+/// assert!(!token.is_cancelled());
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new, uncancelled token.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if cancellation has been requested.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_token_is_not_cancelled() {
+        assert!(!CancellationToken::new().is_cancelled());
+    }
+
+    #[test]
+    fn test_cancel_is_shared_between_clones() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+
+        clone.cancel();
+
+        assert!(token.is_cancelled());
+        assert!(clone.is_cancelled());
+    }
+
+    #[test]
+    fn test_token_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CancellationToken>();
+    }
+}
@@ -0,0 +1,159 @@
+//! Single-file validation.
+//!
+//! Contains [`validate_file`], which validates one spec or plan file without
+//! scanning the rest of the workspace. The file kind is inferred from its
+//! name: `{spec-id}.plan.yaml` is a plan, `{spec-id}.yaml` is a spec.
+//!
+//! Cross-spec checks (dependencies, cycles, plan/spec consistency) need the
+//! whole workspace and are not run here.
+
+// Layer 1: Standard library
+use std::path::Path;
+
+// Layer 3: Internal crates/modules
+use airsspec_core::plan::{PlanStorage as _, validate_plan};
+use airsspec_core::spec::{SpecId, SpecStorage as _};
+use airsspec_core::validation::{
+    SpecContentValidator, ValidationContextBuilder, ValidationIssue, ValidationReport, Validator,
+};
+
+use crate::storage::{FileSystemPlanStorage, FileSystemSpecStorage};
+
+/// Validates a single spec or plan file.
+///
+/// Returns `None` if `path` is not named like a spec (`{spec-id}.yaml`) or
+/// plan (`{spec-id}.plan.yaml`) file.
+///
+/// # Permissive Validation (ADR-005)
+///
+/// Once the file kind is recognized, every problem -- including a missing
+/// or malformed file -- is reported as an issue in the returned report.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use airsspec_mcp::validation::validate_file;
+///
+/// # async fn example() {
+/// let path = Path::new(".airsspec/specs/1737734400-user-auth.yaml");
+/// match validate_file(path).await {
+///     Some(report) => println!("valid: {}", report.is_valid()),
+///     None => println!("not a spec or plan file"),
+/// }
+/// # }
+/// ```
+pub async fn validate_file(path: &Path) -> Option<ValidationReport> {
+    let file_name = path.file_name()?.to_str()?;
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+    if let Some(stem) = file_name.strip_suffix(".plan.yaml") {
+        let spec_id = SpecId::parse(stem).ok()?;
+        return Some(validate_plan_file(dir, &spec_id).await);
+    }
+
+    let stem = file_name.strip_suffix(".yaml")?;
+    let spec_id = SpecId::parse(stem).ok()?;
+    Some(validate_spec_file(dir, &spec_id).await)
+}
+
+/// Loads and validates the spec `spec_id` stored in `dir`.
+async fn validate_spec_file(dir: &Path, spec_id: &SpecId) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    match FileSystemSpecStorage::new(dir).load_spec(spec_id).await {
+        Ok(spec) => {
+            let context = ValidationContextBuilder::new()
+                .workspace_path(dir.to_path_buf())
+                .specs(vec![spec])
+                .build();
+            report.merge(SpecContentValidator.validate(&context));
+        }
+        Err(err) => report.add_error(format!("Failed to load spec: {err}")),
+    }
+    report
+}
+
+/// Loads and validates the plan for `spec_id` stored in `dir`.
+async fn validate_plan_file(dir: &Path, spec_id: &SpecId) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    match FileSystemPlanStorage::new(dir).load_plan(spec_id).await {
+        Ok(plan) => {
+            for issue in validate_plan(&plan).into_issues() {
+                let field = match issue.field() {
+                    Some(field) => format!("[{spec_id}] {field}"),
+                    None => format!("[{spec_id}]"),
+                };
+                report.add_issue(
+                    ValidationIssue::new(issue.severity(), issue.message()).with_field(field),
+                );
+            }
+        }
+        Err(err) => report.add_error(format!("Failed to load plan: {err}")),
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use airsspec_core::plan::Plan;
+    use airsspec_core::spec::{Spec, SpecMetadata, SpecStorage};
+
+    use crate::test_support::block_on;
+
+    use super::*;
+
+    #[test]
+    fn test_valid_spec_file() {
+        let temp = tempfile::tempdir().unwrap();
+        let spec = Spec::new(
+            SpecId::new(1_000_000, "feature"),
+            SpecMetadata::new("Feature", "A feature"),
+            "# Feature",
+        );
+        block_on(FileSystemSpecStorage::new(temp.path()).save_spec(&spec)).unwrap();
+
+        let report = block_on(validate_file(&temp.path().join("1000000-feature.yaml"))).unwrap();
+
+        assert!(report.is_empty(), "got: {:?}", report.issues());
+    }
+
+    #[test]
+    fn test_malformed_spec_file_reports_error() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("1000000-bad.yaml");
+        fs::write(&path, "this is not valid yaml: [[[").unwrap();
+
+        let report = block_on(validate_file(&path)).unwrap();
+
+        assert!(!report.is_valid());
+        assert!(report.errors()[0].message().contains("Failed to load spec"));
+    }
+
+    #[test]
+    fn test_plan_file_issues_are_attributed() {
+        let temp = tempfile::tempdir().unwrap();
+        let plan = Plan::new(SpecId::new(1_000_000, "feature"), "Approach", vec![]);
+        block_on(FileSystemPlanStorage::new(temp.path()).save_plan(&plan)).unwrap();
+
+        let report = block_on(validate_file(
+            &temp.path().join("1000000-feature.plan.yaml"),
+        ))
+        .unwrap();
+
+        assert!(!report.is_empty());
+        assert!(report.issues().iter().all(|issue| {
+            issue
+                .field()
+                .is_some_and(|f| f.starts_with("[1000000-feature]"))
+        }));
+    }
+
+    #[test]
+    fn test_unrecognized_file_returns_none() {
+        let temp = tempfile::tempdir().unwrap();
+        assert!(block_on(validate_file(&temp.path().join("notes.md"))).is_none());
+        assert!(block_on(validate_file(&temp.path().join("config.yaml"))).is_none());
+    }
+}
@@ -0,0 +1,340 @@
+//! Incremental workspace validation.
+//!
+//! Contains [`validate_workspace_incremental`], which deep-validates only the
+//! spec and plan files modified since a given point in time. Cross-spec
+//! checks still see every spec, because a change to one file can break a
+//! dependency or close a cycle involving files that did not change.
+//!
+//! Loading and per-spec checks are shared with
+//! [`validate_workspace`](super::validate_workspace), so when every file has
+//! changed both produce the same report.
+
+// Layer 1: Standard library
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+// Layer 3: Internal crates/modules
+use airsspec_core::validation::{
+    DependencyValidator, DirectoryStructureValidator, StateTransitionValidator,
+    ValidationContextBuilder, ValidationReport, Validator,
+};
+
+use super::runner::{Loaded, load_workspace, sort_by_dependencies, validate_spec_content};
+use crate::storage::{FileSystemPlanStorage, FileSystemSpecStorage};
+
+/// Result of an incremental validation run.
+///
+/// Pairs the [`ValidationReport`] with the set of files that were
+/// deep-validated because they changed.
+#[derive(Debug, Clone, Default)]
+pub struct IncrementalReport {
+    report: ValidationReport,
+    checked_files: BTreeSet<PathBuf>,
+}
+
+impl IncrementalReport {
+    /// Returns the validation report.
+    #[must_use]
+    pub fn report(&self) -> &ValidationReport {
+        &self.report
+    }
+
+    /// Returns the files that were deep-validated, in sorted order.
+    #[must_use]
+    pub fn checked_files(&self) -> &BTreeSet<PathBuf> {
+        &self.checked_files
+    }
+
+    /// Consumes the result and returns the validation report.
+    #[must_use]
+    pub fn into_report(self) -> ValidationReport {
+        self.report
+    }
+}
+
+/// Validates only the spec and plan files modified after `since`.
+///
+/// The run has three parts:
+///
+/// 1. Directory structure validation runs as usual.
+/// 2. Every spec and plan is loaded, and any load failure is reported.
+///    Spec content is deep-validated, in dependency order, only for spec
+///    files modified after `since`.
+/// 3. Dependency validation (missing specs, cycles) runs over the full set,
+///    since the graph cannot be checked piecewise. Plans modified after
+///    `since` are checked against their specs as in a full run.
+///
+/// A file whose modification time cannot be read is treated as changed.
+///
+/// # Permissive Validation (ADR-005)
+///
+/// Like [`validate_workspace`](super::validate_workspace), this function
+/// never returns an error. Every problem found is reported as an issue.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use std::time::{Duration, SystemTime};
+/// use airsspec_mcp::validation::validate_workspace_incremental;
+///
+/// # async fn example() {
+/// let since = SystemTime::now() - Duration::from_secs(3600);
+/// let result = validate_workspace_incremental(Path::new("/my/project"), since).await;
+/// for path in result.checked_files() {
+///     println!("checked {}", path.display());
+/// }
+/// # }
+/// ```
+#[must_use]
+pub async fn validate_workspace_incremental(root: &Path, since: SystemTime) -> IncrementalReport {
+    let mut result = IncrementalReport::default();
+    let workspace_path_buf = root.to_path_buf();
+
+    // Phase 1: Validate directory structure
+    let structure_context = ValidationContextBuilder::new()
+        .workspace_path(workspace_path_buf.clone())
+        .build();
+    result
+        .report
+        .merge(DirectoryStructureValidator.validate(&structure_context));
+
+    // Phase 2: Load everything, deep-validating changed specs
+    let specs_dir = root.join(".airsspec").join("specs");
+    let spec_storage = FileSystemSpecStorage::new(&specs_dir);
+    let plan_storage = FileSystemPlanStorage::new(&specs_dir);
+    let Loaded {
+        spec_ids,
+        mut specs,
+        plan_ids,
+        plans,
+        ..
+    } = load_workspace(&specs_dir, &mut result.report, || None).await;
+
+    let changed_specs: BTreeSet<_> = spec_ids
+        .iter()
+        .filter(|id| modified_since(&spec_storage.spec_path(id), since))
+        .collect();
+    let changed_plans: BTreeSet<_> = plan_ids
+        .iter()
+        .filter(|id| modified_since(&plan_storage.plan_path(id), since))
+        .collect();
+    result.checked_files.extend(
+        changed_specs
+            .iter()
+            .map(|id| spec_storage.spec_path(id))
+            .chain(changed_plans.iter().map(|id| plan_storage.plan_path(id))),
+    );
+
+    sort_by_dependencies(&mut specs, &mut result.report);
+    for spec in specs
+        .iter()
+        .filter(|spec| changed_specs.contains(spec.id()))
+    {
+        result.report.merge(validate_spec_content(root, spec));
+    }
+
+    // Phase 3: Cross-spec validators over the full set, plan checks over
+    // the changed plans
+    let plans: Vec<_> = plans
+        .into_iter()
+        .filter(|plan| changed_plans.contains(plan.spec_id()))
+        .collect();
+    let context = ValidationContextBuilder::new()
+        .workspace_path(workspace_path_buf)
+        .specs(specs)
+        .plans(plans)
+        .build();
+
+    result.report.merge(DependencyValidator.validate(&context));
+    result
+        .report
+        .merge(StateTransitionValidator.validate(&context));
+
+    result.report.dedup();
+    result
+}
+
+/// Returns `true` if the file at `path` was modified after `since`.
+///
+/// Errs on the side of re-validating: unreadable metadata counts as changed.
+fn modified_since(path: &Path, since: SystemTime) -> bool {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .map_or(true, |modified| modified > since)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use airsspec_core::plan::{Plan, PlanStorage};
+    use airsspec_core::spec::{Dependency, Spec, SpecId, SpecMetadata, SpecStorage};
+
+    use crate::test_support::{block_on, create_workspace};
+
+    use super::*;
+
+    /// Saves a spec with an empty description, which always yields a
+    /// content warning when deep-validated.
+    fn save_spec(specs_dir: &Path, timestamp: i64, slug: &str, deps: Vec<SpecId>) -> PathBuf {
+        let mut metadata = SpecMetadata::new(format!("Test: {slug}"), "");
+        for dep_id in deps {
+            metadata.add_dependency(Dependency::blocked_by(dep_id));
+        }
+        let spec = Spec::new(SpecId::new(timestamp, slug), metadata, "Content");
+        block_on(FileSystemSpecStorage::new(specs_dir).save_spec(&spec)).unwrap();
+        specs_dir.join(format!("{timestamp}-{slug}.yaml"))
+    }
+
+    /// Saves a plan with no steps, which always yields a warning when
+    /// checked.
+    fn save_empty_plan(specs_dir: &Path, timestamp: i64, slug: &str) -> PathBuf {
+        let plan = Plan::new(SpecId::new(timestamp, slug), "Approach", vec![]);
+        block_on(FileSystemPlanStorage::new(specs_dir).save_plan(&plan)).unwrap();
+        specs_dir.join(format!("{timestamp}-{slug}.plan.yaml"))
+    }
+
+    fn set_mtime(path: &Path, time: SystemTime) {
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_only_changed_spec_is_deep_validated() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        let specs_dir = temp.path().join(".airsspec/specs");
+
+        let old = UNIX_EPOCH + Duration::from_secs(1_000);
+        let since = UNIX_EPOCH + Duration::from_secs(2_000);
+        let new = UNIX_EPOCH + Duration::from_secs(3_000);
+
+        let stale = save_spec(&specs_dir, 1_000_000, "stale", vec![]);
+        let broken = save_spec(
+            &specs_dir,
+            1_000_001,
+            "broken",
+            vec![SpecId::new(9_999_999, "missing")],
+        );
+        let touched = save_spec(&specs_dir, 1_000_002, "touched", vec![]);
+        set_mtime(&stale, old);
+        set_mtime(&broken, old);
+        set_mtime(&touched, new);
+
+        let result = block_on(validate_workspace_incremental(temp.path(), since));
+
+        assert_eq!(
+            result.checked_files().iter().collect::<Vec<_>>(),
+            vec![&touched]
+        );
+
+        // Content warnings only for the touched spec
+        let warnings = result.report().warnings();
+        assert!(!warnings.is_empty());
+        assert!(
+            warnings
+                .iter()
+                .all(|w| w.field().is_some_and(|f| f.contains("1000002-touched"))),
+            "only the touched spec should be deep-validated, got: {warnings:?}"
+        );
+
+        // Graph checks still cover the unchanged spec
+        assert!(
+            result
+                .report()
+                .errors()
+                .iter()
+                .any(|e| e.message().contains("non-existent")),
+            "dependency check should still run, got: {:?}",
+            result.report().errors()
+        );
+    }
+
+    #[test]
+    fn test_only_changed_plan_is_checked() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        let specs_dir = temp.path().join(".airsspec/specs");
+        let old = UNIX_EPOCH + Duration::from_secs(1_000);
+        let since = UNIX_EPOCH + Duration::from_secs(2_000);
+        let new = UNIX_EPOCH + Duration::from_secs(3_000);
+
+        for (timestamp, slug, plan_mtime) in
+            [(1_000_000, "stale", old), (1_000_001, "touched", new)]
+        {
+            let spec = save_spec(&specs_dir, timestamp, slug, vec![]);
+            set_mtime(&spec, old);
+            let plan = save_empty_plan(&specs_dir, timestamp, slug);
+            set_mtime(&plan, plan_mtime);
+        }
+
+        let result = block_on(validate_workspace_incremental(temp.path(), since));
+
+        assert_eq!(
+            result.checked_files().iter().collect::<Vec<_>>(),
+            vec![&specs_dir.join("1000001-touched.plan.yaml")]
+        );
+        let warnings = result.report().warnings();
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(warnings[0].message().contains("1000001-touched"));
+    }
+
+    #[test]
+    fn test_everything_changed_matches_full_run() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        let specs_dir = temp.path().join(".airsspec/specs");
+        save_spec(&specs_dir, 1_000_000, "base", vec![]);
+        save_spec(
+            &specs_dir,
+            1_000_001,
+            "child",
+            vec![
+                SpecId::new(1_000_000, "base"),
+                SpecId::new(9_999_999, "missing"),
+            ],
+        );
+        save_empty_plan(&specs_dir, 1_000_001, "child");
+        fs::write(specs_dir.join("1000002-bad.yaml"), "not: [valid").unwrap();
+
+        let incremental = block_on(validate_workspace_incremental(temp.path(), UNIX_EPOCH));
+        let full = block_on(crate::validation::validate_workspace(temp.path()));
+
+        assert!(!full.is_empty());
+        assert_eq!(incremental.report(), &full);
+    }
+
+    #[test]
+    fn test_nothing_changed_checks_no_files() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        let specs_dir = temp.path().join(".airsspec/specs");
+        let path = save_spec(&specs_dir, 1_000_000, "stale", vec![]);
+        set_mtime(&path, UNIX_EPOCH + Duration::from_secs(1_000));
+
+        let result = block_on(validate_workspace_incremental(
+            temp.path(),
+            UNIX_EPOCH + Duration::from_secs(2_000),
+        ));
+
+        assert!(result.checked_files().is_empty());
+        assert!(result.into_report().is_empty());
+    }
+
+    #[test]
+    fn test_modified_since_missing_file_counts_as_changed() {
+        let temp = tempfile::tempdir().unwrap();
+        assert!(modified_since(
+            &temp.path().join("missing.yaml"),
+            SystemTime::now()
+        ));
+    }
+}
@@ -0,0 +1,281 @@
+//! Structural integrity checks for an `AirsSpec` workspace.
+//!
+//! Contains the [`check_workspace_integrity`] function backing the
+//! `airsspec doctor` command. Where [`validate_workspace`](super::validate_workspace)
+//! focuses on spec content, these checks look at how the pieces on disk fit
+//! together: the config parses, the required directories exist, plans point
+//! at real specs, dependencies resolve, and no two spec files claim the
+//! same ID.
+
+// Layer 1: Standard library
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+// Layer 3: Internal crates/modules
+use airsspec_core::plan::PlanStorageExt as _;
+use airsspec_core::spec::{Spec, SpecStorageExt as _};
+use airsspec_core::validation::{
+    DependencyValidator, DirectoryStructureValidator, PlanReferenceValidator, ValidationContext,
+    ValidationContextBuilder, ValidationIssue, ValidationReport, Validator,
+};
+use airsspec_core::workspace::ProjectConfig;
+
+use crate::storage::{FileSystemPlanStorage, FileSystemSpecStorage};
+
+/// Checks the structural integrity of an `AirsSpec` workspace.
+///
+/// Aggregates the following checks into a single report:
+///
+/// 1. Required directories and `config.toml` exist
+/// 2. `config.toml` parses as a [`ProjectConfig`]
+/// 3. Every spec and plan file loads
+/// 4. No two spec files declare the same spec ID
+/// 5. Every plan references an existing spec
+/// 6. No spec depends on a missing spec
+///
+/// # Permissive Validation (ADR-005)
+///
+/// Like [`validate_workspace`](super::validate_workspace), this function
+/// never returns an error. Every problem found is reported as an issue.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use airsspec_mcp::validation::check_workspace_integrity;
+///
+/// # async fn example() {
+/// let report = check_workspace_integrity(Path::new("/my/project")).await;
+/// println!("{} error(s)", report.error_count());
+/// # }
+/// ```
+#[must_use]
+pub async fn check_workspace_integrity(workspace_path: &Path) -> ValidationReport {
+    let mut report = ValidationReport::new();
+    let workspace_path_buf = workspace_path.to_path_buf();
+
+    // Phase 1: Directory structure and config
+    let structure_context = ValidationContextBuilder::new()
+        .workspace_path(workspace_path_buf.clone())
+        .build();
+    report.merge(DirectoryStructureValidator.validate(&structure_context));
+    check_config(
+        &workspace_path.join(".airsspec").join("config.toml"),
+        &mut report,
+    );
+
+    // Phase 2: Load specs and plans from filesystem
+    let specs_dir = workspace_path.join(".airsspec").join("specs");
+    if !specs_dir.is_dir() {
+        // Already reported by the structure validator
+        return report;
+    }
+    let spec_storage = FileSystemSpecStorage::new(&specs_dir);
+    let plan_storage = FileSystemPlanStorage::new(&specs_dir);
+
+    let specs = collect_loaded(&spec_storage.load_all().await, "spec", &mut report);
+    let plans = collect_loaded(&plan_storage.load_all().await, "plan", &mut report);
+
+    // Phase 3: Cross-file consistency
+    let context = ValidationContextBuilder::new()
+        .workspace_path(workspace_path_buf)
+        .specs(specs)
+        .plans(plans)
+        .build();
+
+    check_duplicate_ids(&context, &mut report);
+    report.merge(PlanReferenceValidator.validate(&context));
+    report.merge(DependencyValidator.validate(&context));
+
+    report
+}
+
+/// Reports an error if `config.toml` exists but cannot be read or parsed.
+///
+/// A missing config file is left to [`DirectoryStructureValidator`].
+fn check_config(config_path: &Path, report: &mut ValidationReport) {
+    if !config_path.is_file() {
+        return;
+    }
+
+    let result = fs::read_to_string(config_path)
+        .map_err(|err| format!("Failed to read config.toml: {err}"))
+        .and_then(|content| {
+            toml::from_str::<ProjectConfig>(&content)
+                .map_err(|err| format!("Failed to parse config.toml: {err}"))
+        });
+
+    if let Err(message) = result {
+        report.add_issue(ValidationIssue::error(message).with_field("config.toml"));
+    }
+}
+
+/// Reports an error for every spec ID declared by more than one spec file.
+///
+/// Spec files are named after their ID, so a duplicate means a file's
+/// content declares an ID that belongs to another file.
+fn check_duplicate_ids<P>(context: &ValidationContext<Spec, P>, report: &mut ValidationReport) {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for spec in context.specs() {
+        *counts.entry(spec.id().as_str()).or_default() += 1;
+    }
+
+    let mut duplicates: Vec<(&str, usize)> =
+        counts.into_iter().filter(|(_, count)| *count > 1).collect();
+    duplicates.sort_unstable();
+
+    for (id, count) in duplicates {
+        report.add_issue(
+            ValidationIssue::error(format!("Spec ID '{id}' is declared by {count} spec files"))
+                .with_field(format!("[{id}] id")),
+        );
+    }
+}
+
+/// Collects successfully loaded items from a two-level `Result`, reporting
+/// failures as validation errors.
+///
+/// The outer `Result` represents listing failures (e.g., directory not found).
+/// The inner `Result` per item represents individual load failures (e.g.,
+/// malformed YAML). Both are reported as error-level validation issues.
+fn collect_loaded<T: Clone, E1: fmt::Display, E2: fmt::Display>(
+    results: &Result<Vec<Result<T, E2>>, E1>,
+    label: &str,
+    report: &mut ValidationReport,
+) -> Vec<T> {
+    let items = match results {
+        Ok(items) => items,
+        Err(err) => {
+            report.add_error(format!("Failed to list {label}s: {err}"));
+            return Vec::new();
+        }
+    };
+
+    let mut loaded = Vec::with_capacity(items.len());
+    for result in items {
+        match result {
+            Ok(item) => loaded.push(item.clone()),
+            Err(err) => {
+                report.add_error(format!("Failed to load {label}: {err}"));
+            }
+        }
+    }
+    loaded
+}
+
+#[cfg(test)]
+mod tests {
+    use airsspec_core::plan::{Plan, PlanStep, PlanStorage};
+    use airsspec_core::spec::{Dependency, SpecId, SpecMetadata, SpecStorage};
+
+    use crate::test_support::{block_on, create_workspace};
+
+    use super::*;
+
+    fn make_spec(timestamp: i64, slug: &str, deps: Vec<SpecId>) -> Spec {
+        let mut metadata = SpecMetadata::new(format!("Test: {slug}"), "Description");
+        for dep_id in deps {
+            metadata.add_dependency(Dependency::blocked_by(dep_id));
+        }
+        Spec::new(SpecId::new(timestamp, slug), metadata, "Content")
+    }
+
+    fn save_spec(specs_dir: &Path, spec: &Spec) {
+        block_on(FileSystemSpecStorage::new(specs_dir).save_spec(spec)).unwrap();
+    }
+
+    fn save_plan(specs_dir: &Path, timestamp: i64, slug: &str) {
+        let plan = Plan::new(
+            SpecId::new(timestamp, slug),
+            "Test approach",
+            vec![PlanStep::new(0, "Step 1", "First step")],
+        );
+        block_on(FileSystemPlanStorage::new(specs_dir).save_plan(&plan)).unwrap();
+    }
+
+    #[test]
+    fn test_healthy_workspace_is_empty() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        let specs_dir = temp.path().join(".airsspec/specs");
+        save_spec(&specs_dir, &make_spec(1_000_000, "feature", vec![]));
+        save_plan(&specs_dir, 1_000_000, "feature");
+
+        let report = block_on(check_workspace_integrity(temp.path()));
+        assert!(
+            report.is_empty(),
+            "unexpected issues: {:?}",
+            report.issues()
+        );
+    }
+
+    #[test]
+    fn test_missing_workspace_reports_errors() {
+        let temp = tempfile::tempdir().unwrap();
+
+        let report = block_on(check_workspace_integrity(temp.path()));
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_unparseable_config_reports_error() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        fs::write(temp.path().join(".airsspec/config.toml"), "not = [valid").unwrap();
+
+        let report = block_on(check_workspace_integrity(temp.path()));
+        assert!(
+            report.errors().iter().any(
+                |e| e.field() == Some("config.toml") && e.message().contains("Failed to parse")
+            )
+        );
+    }
+
+    #[test]
+    fn test_orphaned_plan_reports_error() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        save_plan(&temp.path().join(".airsspec/specs"), 2_000_000, "gone");
+
+        let report = block_on(check_workspace_integrity(temp.path()));
+        assert_eq!(report.error_count(), 1);
+        assert!(report.errors()[0].message().contains("2000000-gone"));
+    }
+
+    #[test]
+    fn test_dangling_dependency_reports_error() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        save_spec(
+            &temp.path().join(".airsspec/specs"),
+            &make_spec(1_000_000, "source", vec![SpecId::new(9_999_999, "missing")]),
+        );
+
+        let report = block_on(check_workspace_integrity(temp.path()));
+        assert_eq!(report.error_count(), 1);
+        assert!(report.errors()[0].message().contains("non-existent"));
+    }
+
+    #[test]
+    fn test_duplicate_spec_ids_report_error() {
+        let temp = tempfile::tempdir().unwrap();
+        create_workspace(temp.path());
+        let specs_dir = temp.path().join(".airsspec/specs");
+        let spec = make_spec(1_000_000, "original", vec![]);
+        save_spec(&specs_dir, &spec);
+
+        // A second file whose content claims the same ID
+        let yaml = serde_yaml::to_string(&spec).unwrap();
+        fs::write(specs_dir.join("1000001-copy.yaml"), yaml).unwrap();
+
+        let report = block_on(check_workspace_integrity(temp.path()));
+        assert!(
+            report
+                .errors()
+                .iter()
+                .any(|e| e.message().contains("declared by 2 spec files"))
+        );
+    }
+}
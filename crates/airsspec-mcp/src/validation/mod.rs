@@ -2,12 +2,23 @@
 //!
 //! Provides the [`validate_workspace`] function that orchestrates end-to-end
 //! workspace validation by loading specs and plans from the filesystem and
-//! running all workspace validators.
+//! running all workspace validators, [`validate_workspace_incremental`] for
+//! re-validating only changed files, [`validate_file`] for checking a single
+//! spec or plan file, and [`check_workspace_integrity`] for the structural
+//! checks behind `airsspec doctor`.
 //!
 //! This module lives in `airsspec-mcp` (per ADR-002) because it performs
 //! filesystem I/O operations. The validators themselves live in
 //! `airsspec-core::validation::validators`.
 
+mod cancel;
+mod file;
+mod incremental;
+mod integrity;
 mod runner;
 
-pub use runner::validate_workspace;
+pub use cancel::CancellationToken;
+pub use file::validate_file;
+pub use incremental::{IncrementalReport, validate_workspace_incremental};
+pub use integrity::check_workspace_integrity;
+pub use runner::{validate_workspace, validate_workspace_with_limits};
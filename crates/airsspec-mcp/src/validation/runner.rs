@@ -7,17 +7,18 @@
 //! [`ValidationReport`](airsspec_core::validation::ValidationReport).
 
 // Layer 1: Standard library
-use std::fmt;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 // Layer 3: Internal crates/modules
-use airsspec_core::plan::PlanStorageExt as _;
-use airsspec_core::spec::SpecStorageExt as _;
+use airsspec_core::plan::{Plan, PlanStorage as _};
+use airsspec_core::spec::{Spec, SpecId, SpecStorage as _, dependency_levels};
 use airsspec_core::validation::{
     DependencyValidator, DirectoryStructureValidator, SpecContentValidator,
     StateTransitionValidator, ValidationContextBuilder, ValidationReport, Validator,
 };
 
+use super::cancel::CancellationToken;
 use crate::storage::{FileSystemPlanStorage, FileSystemSpecStorage};
 
 /// Validates an `AirsSpec` workspace at the given path.
@@ -28,7 +29,15 @@ use crate::storage::{FileSystemPlanStorage, FileSystemSpecStorage};
 /// 2. Loads all specs and plans from the filesystem
 /// 3. Reports any load failures as validation errors
 /// 4. Runs content, dependency, and state validators on loaded data
-/// 5. Returns a merged `ValidationReport` with all issues
+/// 5. Returns a merged `ValidationReport` with all issues, each reported once
+///
+/// Specs are content-validated in dependency order, so a spec's issues are
+/// reported after those of the specs it is blocked by. Ties are broken by
+/// ID. If the dependencies form a cycle, specs are validated in ID order and
+/// an info note says so.
+///
+/// Runs to completion with no time limit. Use
+/// [`validate_workspace_with_limits`] to bound the run.
 ///
 /// # Permissive Validation (ADR-005)
 ///
@@ -55,6 +64,57 @@ use crate::storage::{FileSystemPlanStorage, FileSystemSpecStorage};
 /// ```
 #[must_use]
 pub async fn validate_workspace(workspace_path: &Path) -> ValidationReport {
+    validate_workspace_with_limits(workspace_path, None, &CancellationToken::new()).await
+}
+
+/// Validates an `AirsSpec` workspace, stopping early on timeout or cancellation.
+///
+/// Runs the same checks as [`validate_workspace`], but checks `timeout` and
+/// `cancel` before each spec or plan is loaded, before each spec's content
+/// is validated, and between validator phases. When either limit is hit,
+/// validation stops and the issues collected so far are returned, plus an
+/// error saying the report is partial. An interrupted run is therefore never
+/// valid: the checks it skipped might have found errors.
+///
+/// # Arguments
+///
+/// * `workspace_path` - Root directory containing `.airsspec/`
+/// * `timeout` - Maximum wall-clock time to spend, or `None` for no limit
+/// * `cancel` - Token the caller can use to stop validation early
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use std::time::Duration;
+/// use airsspec_mcp::validation::{CancellationToken, validate_workspace_with_limits};
+///
+/// # async fn example() {
+/// let cancel = CancellationToken::new();
+/// let report = validate_workspace_with_limits(
+///     Path::new("/my/project"),
+///     Some(Duration::from_secs(30)),
+///     &cancel,
+/// )
+/// .await;
+/// # }
+/// ```
+#[must_use]
+pub async fn validate_workspace_with_limits(
+    workspace_path: &Path,
+    timeout: Option<Duration>,
+    cancel: &CancellationToken,
+) -> ValidationReport {
+    let checkpoint = Checkpoint::new(timeout, cancel);
+    run(workspace_path, || checkpoint.interrupted()).await
+}
+
+/// Runs every validation phase, calling `interrupted` at each checkpoint
+/// and stopping with a partial report once it returns a reason.
+async fn run(
+    workspace_path: &Path,
+    mut interrupted: impl FnMut() -> Option<&'static str>,
+) -> ValidationReport {
     let mut report = ValidationReport::new();
     let workspace_path_buf = workspace_path.to_path_buf();
 
@@ -64,96 +124,186 @@ pub async fn validate_workspace(workspace_path: &Path) -> ValidationReport {
         .build();
     report.merge(DirectoryStructureValidator.validate(&structure_context));
 
-    // Phase 2: Load specs and plans from filesystem
+    // Phase 2: Load specs and plans from filesystem, one at a time
     let specs_dir = workspace_path.join(".airsspec").join("specs");
-    let spec_storage = FileSystemSpecStorage::new(&specs_dir);
-    let plan_storage = FileSystemPlanStorage::new(&specs_dir);
-
-    let specs = collect_loaded(&spec_storage.load_all().await, "spec", &mut report);
-    let plans = collect_loaded(&plan_storage.load_all().await, "plan", &mut report);
+    let loaded = load_workspace(&specs_dir, &mut report, &mut interrupted).await;
+    if let Some(reason) = loaded.interrupted {
+        return partial(report, reason);
+    }
+    let Loaded {
+        mut specs, plans, ..
+    } = loaded;
+
+    // Phase 3: Validate each spec's content, dependencies first
+    sort_by_dependencies(&mut specs, &mut report);
+    for spec in &specs {
+        if let Some(reason) = interrupted() {
+            return partial(report, reason);
+        }
+        report.merge(validate_spec_content(workspace_path, spec));
+    }
 
-    // Phase 3: Build typed context and run remaining validators
+    // Phase 4: Build typed context and run cross-spec validators
     let context = ValidationContextBuilder::new()
         .workspace_path(workspace_path_buf)
         .specs(specs)
         .plans(plans)
         .build();
 
-    report.merge(SpecContentValidator.validate(&context));
+    if let Some(reason) = interrupted() {
+        return partial(report, reason);
+    }
     report.merge(DependencyValidator.validate(&context));
+
+    if let Some(reason) = interrupted() {
+        return partial(report, reason);
+    }
     report.merge(StateTransitionValidator.validate(&context));
 
+    report.dedup();
     report
 }
 
-/// Collects successfully loaded items from a two-level `Result`, reporting
-/// failures as validation errors.
+/// Specs and plans loaded from a workspace's specs directory.
+pub(super) struct Loaded {
+    /// IDs of every listed spec file, including ones that failed to load.
+    pub(super) spec_ids: Vec<SpecId>,
+    /// Specs that loaded.
+    pub(super) specs: Vec<Spec>,
+    /// Spec IDs of every listed plan file, including ones that failed to load.
+    pub(super) plan_ids: Vec<SpecId>,
+    /// Plans that loaded.
+    pub(super) plans: Vec<Plan>,
+    /// Why loading stopped early, if it did.
+    pub(super) interrupted: Option<&'static str>,
+}
+
+/// Loads every spec and plan in `specs_dir`, reporting listing and load
+/// failures to `report`.
 ///
-/// The outer `Result` represents listing failures (e.g., directory not found).
-/// The inner `Result` per item represents individual load failures (e.g.,
-/// malformed YAML). Both are reported as error-level validation issues.
-fn collect_loaded<T: Clone, E1: fmt::Display, E2: fmt::Display>(
-    results: &Result<Vec<Result<T, E2>>, E1>,
-    label: &str,
+/// Calls `interrupted` before each file and stops once it returns a reason,
+/// which is recorded in [`Loaded::interrupted`].
+pub(super) async fn load_workspace(
+    specs_dir: &Path,
     report: &mut ValidationReport,
-) -> Vec<T> {
-    let items = match results {
-        Ok(items) => items,
-        Err(err) => {
-            report.add_error(format!("Failed to list {label}s: {err}"));
-            return Vec::new();
-        }
+    mut interrupted: impl FnMut() -> Option<&'static str>,
+) -> Loaded {
+    let spec_storage = FileSystemSpecStorage::new(specs_dir);
+    let plan_storage = FileSystemPlanStorage::new(specs_dir);
+    let mut loaded = Loaded {
+        spec_ids: Vec::new(),
+        specs: Vec::new(),
+        plan_ids: Vec::new(),
+        plans: Vec::new(),
+        interrupted: None,
     };
 
-    let mut loaded = Vec::with_capacity(items.len());
-    for result in items {
-        match result {
-            Ok(item) => loaded.push(item.clone()),
-            Err(err) => {
-                report.add_error(format!("Failed to load {label}: {err}"));
-            }
+    match spec_storage.list_specs().await {
+        Ok(ids) => loaded.spec_ids = ids,
+        Err(err) => report.add_error(format!("Failed to list specs: {err}")),
+    }
+    for id in &loaded.spec_ids {
+        if let Some(reason) = interrupted() {
+            loaded.interrupted = Some(reason);
+            return loaded;
         }
+        match spec_storage.load_spec(id).await {
+            Ok(spec) => loaded.specs.push(spec),
+            Err(err) => report.add_error(format!("Failed to load spec: {err}")),
+        }
+    }
+
+    match plan_storage.list_plans().await {
+        Ok(ids) => loaded.plan_ids = ids,
+        Err(err) => report.add_error(format!("Failed to list plans: {err}")),
     }
+    for id in &loaded.plan_ids {
+        if let Some(reason) = interrupted() {
+            loaded.interrupted = Some(reason);
+            return loaded;
+        }
+        match plan_storage.load_plan(id).await {
+            Ok(plan) => loaded.plans.push(plan),
+            Err(err) => report.add_error(format!("Failed to load plan: {err}")),
+        }
+    }
+
     loaded
 }
 
-#[cfg(test)]
-mod tests {
-    use std::fs;
-    use std::pin::pin;
-    use std::sync::Arc;
-    use std::task::{Context, Poll, Wake, Waker};
+/// Validates the content of `spec` on its own.
+pub(super) fn validate_spec_content(workspace_path: &Path, spec: &Spec) -> ValidationReport {
+    let spec_context = ValidationContextBuilder::new()
+        .workspace_path(workspace_path.to_path_buf())
+        .specs(vec![spec.clone()])
+        .build();
+    SpecContentValidator.validate(&spec_context)
+}
 
-    use airsspec_core::plan::{Plan, PlanStep, PlanStorage};
-    use airsspec_core::spec::{Dependency, Spec, SpecId, SpecMetadata, SpecStorage};
+/// Orders `specs` so each comes after the specs it is blocked by, breaking
+/// ties by ID. Falls back to ID order, noting why in `report`, on a cycle.
+pub(super) fn sort_by_dependencies(specs: &mut [Spec], report: &mut ValidationReport) {
+    match dependency_levels(specs) {
+        Ok(levels) => specs.sort_by(|a, b| {
+            levels[a.id()]
+                .cmp(&levels[b.id()])
+                .then_with(|| a.id().cmp(b.id()))
+        }),
+        Err(err) => {
+            report.add_info(format!("{err}; validating specs in ID order"));
+            specs.sort_by(|a, b| a.id().cmp(b.id()));
+        }
+    }
+}
 
-    use super::*;
+/// Tracks the limits a validation run must respect.
+struct Checkpoint<'a> {
+    deadline: Option<Instant>,
+    cancel: &'a CancellationToken,
+}
 
-    /// Simple single-threaded executor for testing immediately-ready futures.
-    fn block_on<F: Future>(f: F) -> F::Output {
-        struct NoopWaker;
-        impl Wake for NoopWaker {
-            fn wake(self: Arc<Self>) {}
-        }
-        let waker = Waker::from(Arc::new(NoopWaker));
-        let mut cx = Context::from_waker(&waker);
-        let mut f = pin!(f);
-        match f.as_mut().poll(&mut cx) {
-            Poll::Ready(result) => result,
-            Poll::Pending => panic!("block_on: unexpected Pending"),
+impl<'a> Checkpoint<'a> {
+    fn new(timeout: Option<Duration>, cancel: &'a CancellationToken) -> Self {
+        Self {
+            deadline: timeout.map(|limit| Instant::now() + limit),
+            cancel,
         }
     }
 
-    /// Creates a valid workspace directory structure.
-    fn create_workspace(dir: &std::path::Path) {
-        fs::create_dir_all(dir.join(".airsspec/specs")).unwrap();
-        fs::create_dir_all(dir.join(".airsspec/logs")).unwrap();
-        fs::write(
-            dir.join(".airsspec/config.toml"),
-            "[project]\nname = \"test\"\ndescription = \"test project\"\n",
-        )
-        .unwrap();
+    /// Returns why the run must stop, or `None` if it may continue.
+    fn interrupted(&self) -> Option<&'static str> {
+        if self.cancel.is_cancelled() {
+            Some("cancelled")
+        } else if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            Some("timed out")
+        } else {
+            None
+        }
     }
+}
+
+/// Marks a report as truncated by an early stop.
+fn partial(mut report: ValidationReport, reason: &str) -> ValidationReport {
+    report.dedup();
+    report.add_error(format!(
+        "Validation {reason} before completion; report is partial"
+    ));
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use airsspec_core::plan::{Plan, PlanStep, PlanStorage};
+    use airsspec_core::spec::{Dependency, SpecId, SpecMetadata, SpecStorage};
+
+    use crate::test_support::{block_on, create_workspace};
+
+    use super::*;
 
     /// Creates and saves a test spec to the workspace.
     fn save_test_spec(specs_dir: &std::path::Path, timestamp: i64, slug: &str) {
@@ -330,6 +480,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duplicate_issues_reported_once() {
+        let temp = tempfile::tempdir().unwrap();
+        let ws = temp.path();
+        create_workspace(ws);
+
+        let specs_dir = ws.join(".airsspec/specs");
+        let missing = SpecId::new(9_999_999, "nonexistent");
+        save_test_spec_with_deps(
+            &specs_dir,
+            1_000_000,
+            "depends-twice",
+            vec![missing.clone(), missing],
+        );
+
+        let report = block_on(validate_workspace(ws));
+        let broken = report
+            .errors()
+            .iter()
+            .filter(|e| e.message().contains("non-existent"))
+            .count();
+        assert_eq!(broken, 1, "got: {:?}", report.errors());
+    }
+
     #[test]
     fn test_empty_plan_steps_reports_warning() {
         let temp = tempfile::tempdir().unwrap();
@@ -370,6 +544,113 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cancelled_run_returns_partial_report() {
+        let temp = tempfile::tempdir().unwrap();
+        let ws = temp.path();
+        create_workspace(ws);
+        let specs_dir = ws.join(".airsspec/specs");
+        save_undescribed_spec(&specs_dir, 1_000_000, "first", vec![]);
+        save_undescribed_spec(&specs_dir, 2_000_000, "second", vec![]);
+
+        // Checkpoints run before loading each spec, then before validating
+        // each one: cancel at the fourth, once the first spec is validated.
+        let cancel = CancellationToken::new();
+        let checkpoint = Checkpoint::new(None, &cancel);
+        let mut checks = 0;
+        let report = block_on(run(ws, || {
+            checks += 1;
+            if checks == 4 {
+                cancel.cancel();
+            }
+            checkpoint.interrupted()
+        }));
+
+        assert_eq!(checks, 4);
+        first_issue_of(&report, "1000000-first");
+        assert!(
+            !report
+                .issues()
+                .iter()
+                .any(|i| i.field().is_some_and(|f| f.starts_with("[2000000-second]"))),
+            "second spec should not have been validated, got: {:?}",
+            report.issues()
+        );
+        assert_eq!(report.error_count(), 1, "got: {:?}", report.errors());
+        assert!(
+            report.errors()[0]
+                .message()
+                .contains("cancelled before completion")
+        );
+    }
+
+    #[test]
+    fn test_pre_cancelled_run_stops_after_structure_phase() {
+        let temp = tempfile::tempdir().unwrap();
+        let ws = temp.path();
+
+        // Missing logs/ directory is caught by the structure phase
+        fs::create_dir_all(ws.join(".airsspec/specs")).unwrap();
+        fs::write(
+            ws.join(".airsspec/config.toml"),
+            "[project]\nname = \"test\"\ndescription = \"test\"\n",
+        )
+        .unwrap();
+        save_test_spec(&ws.join(".airsspec/specs"), 1_000_000, "my-feature");
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let report = block_on(validate_workspace_with_limits(ws, None, &cancel));
+
+        assert!(
+            report
+                .warnings()
+                .iter()
+                .any(|w| w.message().contains("logs")),
+            "structure phase should have run, got: {:?}",
+            report.issues()
+        );
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_zero_timeout_returns_partial_report() {
+        let temp = tempfile::tempdir().unwrap();
+        let ws = temp.path();
+        create_workspace(ws);
+        save_test_spec(&ws.join(".airsspec/specs"), 1_000_000, "my-feature");
+
+        let report = block_on(validate_workspace_with_limits(
+            ws,
+            Some(Duration::ZERO),
+            &CancellationToken::new(),
+        ));
+
+        assert!(report.warnings().is_empty(), "got: {:?}", report.issues());
+        assert_eq!(report.error_count(), 1);
+        assert!(report.errors()[0].message().contains("timed out"));
+    }
+
+    #[test]
+    fn test_generous_limits_match_unbounded_run() {
+        let temp = tempfile::tempdir().unwrap();
+        let ws = temp.path();
+        create_workspace(ws);
+        save_test_spec(&ws.join(".airsspec/specs"), 1_000_000, "my-feature");
+
+        let report = block_on(validate_workspace_with_limits(
+            ws,
+            Some(Duration::from_secs(3600)),
+            &CancellationToken::new(),
+        ));
+
+        assert!(
+            report.is_empty(),
+            "unexpected issues: {:?}",
+            report.issues()
+        );
+    }
+
     #[test]
     fn test_multiple_validators_all_run() {
         let temp = tempfile::tempdir().unwrap();
@@ -410,4 +691,84 @@ mod tests {
             report.warnings()
         );
     }
+
+    /// Saves a spec with an empty description, so content validation warns
+    /// about it, blocked by `deps`.
+    fn save_undescribed_spec(
+        specs_dir: &std::path::Path,
+        timestamp: i64,
+        slug: &str,
+        deps: Vec<SpecId>,
+    ) {
+        let storage = FileSystemSpecStorage::new(specs_dir);
+        let mut metadata = SpecMetadata::new(format!("Test: {slug}"), "");
+        for dep_id in deps {
+            metadata.add_dependency(Dependency::blocked_by(dep_id));
+        }
+        let spec = Spec::new(SpecId::new(timestamp, slug), metadata, "# Content");
+        block_on(storage.save_spec(&spec)).unwrap();
+    }
+
+    /// Position of the first issue attributed to `spec` in the report.
+    fn first_issue_of(report: &ValidationReport, spec: &str) -> usize {
+        let prefix = format!("[{spec}]");
+        report
+            .issues()
+            .iter()
+            .position(|issue| issue.field().is_some_and(|f| f.starts_with(&prefix)))
+            .unwrap_or_else(|| panic!("no issue for {spec}: {:?}", report.issues()))
+    }
+
+    #[test]
+    fn test_specs_validated_in_dependency_order() {
+        let temp = tempfile::tempdir().unwrap();
+        let ws = temp.path();
+        create_workspace(ws);
+
+        // IDs sort top < middle < base, the reverse of dependency order.
+        let specs_dir = ws.join(".airsspec/specs");
+        let base = SpecId::new(3_000_000, "base");
+        let middle = SpecId::new(2_000_000, "middle");
+        save_undescribed_spec(&specs_dir, 3_000_000, "base", vec![]);
+        save_undescribed_spec(&specs_dir, 2_000_000, "middle", vec![base.clone()]);
+        save_undescribed_spec(&specs_dir, 1_000_000, "top", vec![middle, base]);
+
+        let report = block_on(validate_workspace(ws));
+
+        let base_at = first_issue_of(&report, "3000000-base");
+        let middle_at = first_issue_of(&report, "2000000-middle");
+        let top_at = first_issue_of(&report, "1000000-top");
+        assert!(
+            base_at < middle_at && middle_at < top_at,
+            "expected base, middle, top order, got: {:?}",
+            report.issues()
+        );
+    }
+
+    #[test]
+    fn test_cyclic_specs_validated_in_id_order_with_note() {
+        let temp = tempfile::tempdir().unwrap();
+        let ws = temp.path();
+        create_workspace(ws);
+
+        let specs_dir = ws.join(".airsspec/specs");
+        let first = SpecId::new(1_000_000, "first");
+        let second = SpecId::new(2_000_000, "second");
+        save_undescribed_spec(&specs_dir, 1_000_000, "first", vec![second]);
+        save_undescribed_spec(&specs_dir, 2_000_000, "second", vec![first]);
+
+        let report = block_on(validate_workspace(ws));
+
+        assert!(
+            report
+                .issues()
+                .iter()
+                .any(|i| i.message().contains("validating specs in ID order")),
+            "expected cycle note, got: {:?}",
+            report.issues()
+        );
+        assert!(
+            first_issue_of(&report, "1000000-first") < first_issue_of(&report, "2000000-second")
+        );
+    }
 }
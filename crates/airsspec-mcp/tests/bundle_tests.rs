@@ -0,0 +1,112 @@
+//! # Export Bundle Integration Tests
+//!
+//! Exercises [`export_bundle`] against a small on-disk workspace:
+//!
+//! - Specs and plans land under `specs/` and `plans/`
+//! - Entry contents match the stored YAML
+//! - An empty workspace yields an empty archive
+//! - Exporting the same workspace twice yields identical bytes
+//!
+//! Archives are read back with the `zip` crate's reader.
+
+// Layer 1: Standard library
+use std::fs;
+use std::io::Read as _;
+use std::path::Path;
+
+// Layer 2: External crates
+use zip::ZipArchive;
+
+// Layer 3: Internal crates
+use airsspec_core::plan::{Plan, PlanStep, PlanStorage as _};
+use airsspec_core::spec::{Spec, SpecId, SpecMetadata, SpecStorage as _};
+use airsspec_mcp::storage::{FileSystemPlanStorage, FileSystemSpecStorage, export_bundle};
+
+/// Returns the name and contents of every entry in the zip at `path`, read
+/// back with the `zip` crate's reader.
+fn zip_entries(path: &Path) -> Vec<(String, String)> {
+    let mut archive = ZipArchive::new(fs::File::open(path).unwrap()).unwrap();
+    (0..archive.len())
+        .map(|index| {
+            let mut entry = archive.by_index(index).unwrap();
+            let mut data = String::new();
+            entry.read_to_string(&mut data).unwrap();
+            (entry.name().to_string(), data)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_bundle_contains_specs_and_plans() {
+    let workspace = tempfile::tempdir().unwrap();
+    let specs = FileSystemSpecStorage::new(workspace.path());
+    let plans = FileSystemPlanStorage::new(workspace.path());
+
+    let auth = SpecId::new(1_737_734_400, "auth");
+    let search = SpecId::new(1_737_734_500, "search");
+    for id in [&auth, &search] {
+        let spec = Spec::new(id.clone(), SpecMetadata::new("Title", "Desc"), "# Body");
+        specs.save_spec(&spec).await.unwrap();
+    }
+    let plan = Plan::new(
+        auth.clone(),
+        "Token flow",
+        vec![PlanStep::new(0, "Add login", "Wire the endpoint")],
+    );
+    plans.save_plan(&plan).await.unwrap();
+
+    let out = tempfile::tempdir().unwrap();
+    let bundle = out.path().join("snapshot.zip");
+    let count = export_bundle(&specs, &plans, &bundle).await.unwrap();
+
+    let entries = zip_entries(&bundle);
+    let mut names: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+    names.sort_unstable();
+    assert_eq!(count, 3);
+    assert_eq!(
+        names,
+        [
+            "plans/1737734400-auth.plan.yaml",
+            "specs/1737734400-auth.yaml",
+            "specs/1737734500-search.yaml",
+        ]
+    );
+
+    let (_, auth_yaml) = entries
+        .iter()
+        .find(|(name, _)| name == "specs/1737734400-auth.yaml")
+        .unwrap();
+    let stored = fs::read_to_string(workspace.path().join("1737734400-auth.yaml")).unwrap();
+    assert_eq!(auth_yaml, &stored);
+}
+
+#[tokio::test]
+async fn test_empty_workspace_yields_empty_bundle() {
+    let workspace = tempfile::tempdir().unwrap();
+    let specs = FileSystemSpecStorage::new(workspace.path());
+    let plans = FileSystemPlanStorage::new(workspace.path());
+    let bundle = workspace.path().join("empty.zip");
+
+    let count = export_bundle(&specs, &plans, &bundle).await.unwrap();
+
+    assert_eq!(count, 0);
+    assert!(zip_entries(&bundle).is_empty());
+}
+
+#[tokio::test]
+async fn test_bundle_is_deterministic() {
+    let workspace = tempfile::tempdir().unwrap();
+    let specs = FileSystemSpecStorage::new(workspace.path());
+    let plans = FileSystemPlanStorage::new(workspace.path());
+    let id = SpecId::new(1_737_734_400, "auth");
+    let spec = Spec::new(id, SpecMetadata::new("Title", "Desc"), "# Body");
+    specs.save_spec(&spec).await.unwrap();
+
+    let out = tempfile::tempdir().unwrap();
+    let first = out.path().join("first.zip");
+    let second = out.path().join("second.zip");
+    export_bundle(&specs, &plans, &first).await.unwrap();
+    export_bundle(&specs, &plans, &second).await.unwrap();
+
+    assert_eq!(fs::read(&first).unwrap(), fs::read(&second).unwrap());
+}
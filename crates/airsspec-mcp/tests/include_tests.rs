@@ -0,0 +1,134 @@
+//! # Spec Include Integration Tests
+//!
+//! Exercises [`SpecIncludeExt::resolve_includes`] against real files:
+//!
+//! - Nested includes resolved relative to the including file
+//! - Includes of a symlinked file resolved relative to its target
+//! - Missing include files reported with their path
+//! - Circular includes rejected
+//! - Includes outside the base directory rejected
+
+// Layer 1: Standard library
+use std::fs;
+
+// Layer 3: Internal crates
+use airsspec_core::spec::{Spec, SpecError, SpecId, SpecMetadata};
+use airsspec_mcp::storage::SpecIncludeExt as _;
+
+/// Builds a spec whose content is `content`.
+fn spec_with_content(content: &str) -> Spec {
+    Spec::new(
+        SpecId::new(1_737_734_400, "includes"),
+        SpecMetadata::new("Includes", "Spec with includes"),
+        content,
+    )
+}
+
+#[test]
+fn test_resolve_includes_inlines_nested_files() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("shared")).unwrap();
+    fs::write(
+        temp.path().join("shared/security.md"),
+        "Use TLS.\n{{include: footer.md}}",
+    )
+    .unwrap();
+    fs::write(temp.path().join("shared/footer.md"), "-- end --").unwrap();
+
+    let spec = spec_with_content("# Auth\n\n{{include: shared/security.md}}\n");
+    let content = spec.resolve_includes(temp.path()).unwrap();
+
+    assert_eq!(content, "# Auth\n\nUse TLS.\n-- end --\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_resolve_includes_follows_symlink_to_target_directory() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("shared")).unwrap();
+    fs::write(
+        temp.path().join("shared/security.md"),
+        "Use TLS.\n{{include: footer.md}}",
+    )
+    .unwrap();
+    fs::write(temp.path().join("shared/footer.md"), "-- end --").unwrap();
+    std::os::unix::fs::symlink(
+        temp.path().join("shared/security.md"),
+        temp.path().join("security.md"),
+    )
+    .unwrap();
+
+    let spec = spec_with_content("{{include: security.md}}");
+    let content = spec.resolve_includes(temp.path()).unwrap();
+
+    assert_eq!(content, "Use TLS.\n-- end --");
+}
+
+#[test]
+fn test_resolve_includes_missing_file_errors_with_path() {
+    let temp = tempfile::tempdir().unwrap();
+
+    let spec = spec_with_content("{{include: missing.md}}");
+    let err = spec.resolve_includes(temp.path()).unwrap_err();
+
+    match err {
+        SpecError::IncludeNotFound(path) => assert!(path.ends_with("missing.md"), "{path}"),
+        other => panic!("expected IncludeNotFound, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_resolve_includes_rejects_circular_include() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("a.md"), "A {{include: b.md}}").unwrap();
+    fs::write(temp.path().join("b.md"), "B {{include: a.md}}").unwrap();
+
+    let spec = spec_with_content("{{include: a.md}}");
+    let err = spec.resolve_includes(temp.path()).unwrap_err();
+
+    match err {
+        SpecError::CircularInclude(cycle) => {
+            assert!(cycle.contains("a.md") && cycle.contains("b.md"), "{cycle}");
+        }
+        other => panic!("expected CircularInclude, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_resolve_includes_rejects_paths_outside_base_dir() {
+    let temp = tempfile::tempdir().unwrap();
+    let base = temp.path().join("specs");
+    fs::create_dir_all(base.join("shared")).unwrap();
+    let outside = temp.path().join("secrets.md");
+    fs::write(&outside, "secret").unwrap();
+    fs::write(
+        base.join("shared/escape.md"),
+        "{{include: ../../secrets.md}}",
+    )
+    .unwrap();
+
+    for content in [
+        "{{include: ../secrets.md}}".to_string(),
+        format!("{{{{include: {}}}}}", outside.display()),
+        "{{include: shared/escape.md}}".to_string(),
+    ] {
+        let err = spec_with_content(&content)
+            .resolve_includes(&base)
+            .unwrap_err();
+
+        assert!(
+            matches!(err, SpecError::IncludeOutsideBase(ref path) if path.ends_with("secrets.md")),
+            "{content}: {err:?}"
+        );
+    }
+}
+
+#[test]
+fn test_resolve_includes_leaves_unterminated_directive() {
+    let temp = tempfile::tempdir().unwrap();
+
+    let spec = spec_with_content("Literal {{include: never closed");
+    let content = spec.resolve_includes(temp.path()).unwrap();
+
+    assert_eq!(content, "Literal {{include: never closed");
+}
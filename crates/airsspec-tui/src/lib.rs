@@ -47,5 +47,10 @@ pub mod theme;
 pub mod widgets;
 pub mod wizard;
 
-pub use reporter::render_validation_report;
+pub use reporter::{
+    ReportOptions, WorkspaceReportOptions, prefers_ascii_symbols, render_annotated,
+    render_lifecycle_badge, render_summary_line, render_validation_report,
+    render_validation_report_with_options, render_workspace_report,
+    render_workspace_report_with_states, resolve_spec_links, terminal_wrap_width,
+};
 pub use wizard::{InitWizardResult, run_init_wizard};
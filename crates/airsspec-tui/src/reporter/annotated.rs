@@ -0,0 +1,293 @@
+//! Annotated source excerpts for validation issues.
+//!
+//! Shows the lines around the YAML key an issue refers to, with a caret
+//! under the key, in the style of compiler diagnostics.
+
+// Layer 1: Standard library
+use std::io::{self, Write};
+use std::ops::Range;
+
+// Layer 2: External crates
+use crossterm::style::{Attribute, ResetColor, SetAttribute, SetForegroundColor};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::validation::{ValidationIssue, ValidationSeverity};
+
+use crate::theme::colors;
+
+/// Number of source lines shown before and after the offending line.
+const CONTEXT_LINES: usize = 2;
+
+/// Renders `issue` with an excerpt of `source` pointing at its field.
+///
+/// `source` is either a YAML document, such as a spec file written by the
+/// storage layer, or a document whose YAML frontmatter sits between `---`
+/// lines. The issue's field is looked up as a path of nested keys
+/// (`metadata.title` is the `title:` key indented under `metadata:`). Path
+/// segments missing from the document are skipped, so `metadata.title` also
+/// finds a top-level `title:` in flat frontmatter. When found, the
+/// surrounding lines are printed with line numbers and a caret under the
+/// key:
+///
+/// ```text
+/// [error] metadata.title: Title cannot be empty
+///  --> line 3
+///   |
+/// 1 | id: 1737734400-login
+/// 2 | metadata:
+/// 3 |   title: ''
+///   |   ^^^^^
+/// 4 |   description: Login flow
+/// 5 |   category: feature
+///   |
+/// ```
+///
+/// When the issue has no field, or the key is not in the document, only the
+/// message line is printed.
+///
+/// # Errors
+///
+/// Returns an error if writing to the provided writer fails.
+pub fn render_annotated(
+    issue: &ValidationIssue,
+    source: &str,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    let color = match issue.severity() {
+        ValidationSeverity::Error => colors::ERROR,
+        ValidationSeverity::Warning => colors::WARNING,
+        // Info, and any future severity, uses the primary color.
+        _ => colors::PRIMARY,
+    };
+    write!(writer, "{}", SetForegroundColor(color.into()))?;
+    write!(writer, "{}", SetAttribute(Attribute::Bold))?;
+    writeln!(writer, "{issue}")?;
+    write!(writer, "{}", SetAttribute(Attribute::Reset))?;
+    write!(writer, "{ResetColor}")?;
+
+    let lines: Vec<&str> = source.lines().collect();
+    let Some((index, column, width)) = issue.field().and_then(|field| locate_key(&lines, field))
+    else {
+        return Ok(());
+    };
+
+    let first = index.saturating_sub(CONTEXT_LINES);
+    let last = (index + CONTEXT_LINES).min(lines.len() - 1);
+    let gutter = (last + 1).to_string().len();
+
+    writeln!(writer, "{:gutter$}--> line {}", "", index + 1)?;
+    writeln!(writer, "{:gutter$} |", "")?;
+    for (offset, line) in lines[first..=last].iter().enumerate() {
+        let number = first + offset;
+        writeln!(writer, "{:>gutter$} | {line}", number + 1)?;
+        if number == index {
+            write!(writer, "{:gutter$} | {:column$}", "", "")?;
+            write!(writer, "{}", SetForegroundColor(color.into()))?;
+            write!(writer, "{}", "^".repeat(width))?;
+            writeln!(writer, "{ResetColor}")?;
+        }
+    }
+    writeln!(writer, "{:gutter$} |", "")?;
+
+    Ok(())
+}
+
+/// Finds the line defining the key at the dotted path `field`.
+///
+/// Each segment is looked up among the keys nested directly in the previous
+/// one's block; a segment that is not there is skipped and the next one is
+/// looked up in the same block instead. List indices (`dependencies[0]`)
+/// are ignored. Returns the line index, the key's column, and the key's
+/// width in characters, or `None` if the last segment is not found.
+fn locate_key(lines: &[&str], field: &str) -> Option<(usize, usize, usize)> {
+    let mut scope = yaml_lines(lines);
+    let mut found = None;
+    for segment in field.split('.') {
+        let key = segment.split('[').next().unwrap_or(segment);
+        if key.is_empty() {
+            return None;
+        }
+        found = find_child_key(lines, scope.clone(), key);
+        if let Some((index, column)) = found {
+            scope = block_of(lines, index, column, scope.end);
+        }
+    }
+    found.map(|(index, column)| (index, column, field_key_width(field)))
+}
+
+/// Returns the range of lines holding YAML: those between the opening and
+/// closing `---` if `lines` starts with frontmatter, otherwise all of them.
+fn yaml_lines(lines: &[&str]) -> Range<usize> {
+    if lines.first().map(|line| line.trim_end()) != Some("---") {
+        return 0..lines.len();
+    }
+    let end = lines
+        .iter()
+        .skip(1)
+        .position(|line| line.trim_end() == "---")
+        .map_or(lines.len(), |offset| offset + 1);
+    1..end
+}
+
+/// Finds `key` among the least-indented keys in `scope`, returning its line
+/// index and column.
+fn find_child_key(lines: &[&str], scope: Range<usize>, key: &str) -> Option<(usize, usize)> {
+    let entries: Vec<(usize, &str)> = scope
+        .filter_map(|index| {
+            let line = lines[index];
+            let trimmed = line.trim_start();
+            (!trimmed.is_empty() && !trimmed.starts_with('#')).then_some((index, line))
+        })
+        .collect();
+    let child_indent = entries.iter().map(|(_, line)| indent_of(line)).min()?;
+
+    entries.into_iter().find_map(|(index, line)| {
+        let column = indent_of(line);
+        let rest = line[column..].strip_prefix(key)?;
+        (column == child_indent && rest.starts_with(':')).then_some((index, column))
+    })
+}
+
+/// Returns the lines after the key at `index` that are indented deeper than
+/// its `column`, up to `end`.
+fn block_of(lines: &[&str], index: usize, column: usize, end: usize) -> Range<usize> {
+    let start = index + 1;
+    let block_end = (start..end)
+        .find(|&line| {
+            let text = lines[line];
+            !text.trim().is_empty() && indent_of(text) <= column
+        })
+        .unwrap_or(end);
+    start..block_end
+}
+
+/// Returns the number of leading spaces of `line`.
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Returns the width in characters of the last key in `field`.
+fn field_key_width(field: &str) -> usize {
+    let last = field.rsplit('.').next().unwrap_or(field);
+    last.split('[').next().unwrap_or(last).chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use airsspec_core::spec::{Spec, SpecId, SpecMetadata, SpecStorage as _};
+    use airsspec_mcp::FileSystemSpecStorage;
+
+    use super::*;
+
+    const SOURCE: &str = "---\nid: 1737734400-login\ntitle: \"\"\ndescription: Login flow\ncategory: feature\n---\n\n# Login\n";
+
+    fn render_plain(issue: &ValidationIssue, source: &str) -> String {
+        let mut buf = Vec::new();
+        render_annotated(issue, source, &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        strip_escapes(&output)
+    }
+
+    /// Removes ANSI escape sequences so assertions see only the text.
+    fn strip_escapes(text: &str) -> String {
+        let mut plain = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            } else {
+                plain.push(c);
+            }
+        }
+        plain
+    }
+
+    #[test]
+    fn test_frontmatter_field_shows_excerpt_with_caret() {
+        let issue = ValidationIssue::error("Title cannot be empty").with_field("metadata.title");
+
+        let output = render_plain(&issue, SOURCE);
+
+        assert_eq!(
+            output,
+            "[error] metadata.title: Title cannot be empty\n \
+             --> line 3\n  \
+             |\n\
+             1 | ---\n\
+             2 | id: 1737734400-login\n\
+             3 | title: \"\"\n  \
+             | ^^^^^\n\
+             4 | description: Login flow\n\
+             5 | category: feature\n  \
+             |\n"
+        );
+    }
+
+    #[test]
+    fn test_context_is_clipped_at_source_start() {
+        let issue = ValidationIssue::warning("Odd ID").with_field("id");
+
+        let output = render_plain(&issue, SOURCE);
+
+        assert!(output.contains(" --> line 2\n"), "{output}");
+        assert!(output.contains("1 | ---\n2 | id:"), "{output}");
+        assert!(output.contains("4 | description"), "{output}");
+        assert!(!output.contains("5 | "), "{output}");
+    }
+
+    #[test]
+    fn test_unlocatable_issue_prints_only_message() {
+        let issue = ValidationIssue::warning("Empty spec content").with_field("content");
+
+        let output = render_plain(&issue, SOURCE);
+
+        assert_eq!(output, "[warning] content: Empty spec content\n");
+    }
+
+    #[tokio::test]
+    async fn test_spec_file_nested_field_is_located() {
+        let temp = tempfile::tempdir().unwrap();
+        let storage = FileSystemSpecStorage::new(temp.path());
+        let spec = Spec::new(
+            SpecId::new(1_737_734_400, "login"),
+            SpecMetadata::new("Login", "Login flow"),
+            "# Login\n\ntitle: not a key\n",
+        );
+        storage.save_spec(&spec).await.unwrap();
+        let source = std::fs::read_to_string(temp.path().join("1737734400-login.yaml")).unwrap();
+
+        let issue = ValidationIssue::error("Title too short").with_field("metadata.title");
+        let output = render_plain(&issue, &source);
+
+        assert!(output.contains(" --> line 3\n"), "{output}");
+        assert!(
+            output.contains("3 |   title: Login\n  |   ^^^^^\n"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_key_inside_block_scalar_is_not_matched() {
+        let source = "id: 1737734400-login\ncontent: |-\n  title: decoy\n";
+        let issue = ValidationIssue::error("Bad").with_field("title");
+
+        let output = render_plain(&issue, source);
+
+        assert_eq!(output, "[error] title: Bad\n");
+    }
+
+    #[test]
+    fn test_issue_without_field_or_key_prints_only_message() {
+        let issue = ValidationIssue::error("Broken").with_field("metadata.title");
+        let output = render_plain(&issue, "# Just markdown\n");
+        assert_eq!(output, "[error] metadata.title: Broken\n");
+
+        let issue = ValidationIssue::info("Checked");
+        let output = render_plain(&issue, SOURCE);
+        assert_eq!(output, "[info] Checked\n");
+    }
+}
@@ -0,0 +1,71 @@
+//! Lifecycle state badges.
+//!
+//! Maps each [`LifecycleState`] to a short colored label so reports can
+//! show at a glance where a spec stands.
+
+// Layer 2: External crates
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+
+// Layer 3: Internal crates/modules
+use airsspec_core::shared::LifecycleState;
+
+use crate::theme::colors;
+
+/// Returns the badge for `state`, e.g. `[active]` in blue.
+///
+/// Colors follow the [theme](crate::theme) palette: draft and archived are
+/// gray, active is blue, blocked is red, done is green, and cancelled is
+/// yellow.
+///
+/// # Examples
+///
+/// ```
+/// use airsspec_core::shared::LifecycleState;
+/// use airsspec_tui::reporter::render_lifecycle_badge;
+///
+/// let badge = render_lifecycle_badge(LifecycleState::Active);
+/// assert_eq!(badge.content, "[active]");
+/// ```
+#[must_use]
+pub fn render_lifecycle_badge(state: LifecycleState) -> Span<'static> {
+    let style = Style::default()
+        .fg(lifecycle_color(state))
+        .add_modifier(Modifier::BOLD);
+    Span::styled(format!("[{state}]"), style)
+}
+
+/// Returns the theme color for `state`.
+fn lifecycle_color(state: LifecycleState) -> Color {
+    match state {
+        LifecycleState::Active => colors::PRIMARY,
+        LifecycleState::Blocked => colors::ERROR,
+        LifecycleState::Done => colors::SUCCESS,
+        LifecycleState::Cancelled => colors::WARNING,
+        // Draft, Archived, and any future state render as muted.
+        _ => colors::MUTED,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_each_state_maps_to_label_and_color() {
+        let cases = [
+            (LifecycleState::Draft, "[draft]", colors::MUTED),
+            (LifecycleState::Active, "[active]", colors::PRIMARY),
+            (LifecycleState::Blocked, "[blocked]", colors::ERROR),
+            (LifecycleState::Done, "[done]", colors::SUCCESS),
+            (LifecycleState::Cancelled, "[cancelled]", colors::WARNING),
+            (LifecycleState::Archived, "[archived]", colors::MUTED),
+        ];
+
+        for (state, label, color) in cases {
+            let badge = render_lifecycle_badge(state);
+            assert_eq!(badge.content, label);
+            assert_eq!(badge.style.fg, Some(color), "color for {state}");
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! # Spec Link Resolution
+//!
+//! Rewrites raw `airsspec:///specs/{id}` links in spec content into Markdown
+//! links labelled with the target spec's title, so rendered content reads
+//! "User Auth" rather than a timestamped ID. The URI itself is kept intact.
+
+// Layer 1: Standard library
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+// Layer 3: Internal crates/modules
+use airsspec_core::spec::SpecId;
+
+/// URI prefix of a spec resource link.
+const SPEC_URI_PREFIX: &str = "airsspec:///specs/";
+
+/// Rewrites raw spec links in `content` to `[Title](airsspec:///specs/{id})`.
+///
+/// Links whose ID is not in `titles`, and links that are already the target
+/// of a Markdown link (`[text](airsspec:///specs/{id})`), are left as-is.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use airsspec_core::spec::SpecId;
+/// use airsspec_tui::reporter::resolve_spec_links;
+///
+/// let titles = HashMap::from([(SpecId::new(1_737_734_400, "user-auth"), "User Auth".to_string())]);
+/// let resolved = resolve_spec_links("See airsspec:///specs/1737734400-user-auth.", &titles);
+/// assert_eq!(resolved, "See [User Auth](airsspec:///specs/1737734400-user-auth).");
+/// ```
+#[must_use]
+pub fn resolve_spec_links<H: BuildHasher>(
+    content: &str,
+    titles: &HashMap<SpecId, String, H>,
+) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find(SPEC_URI_PREFIX) {
+        let id_start = start + SPEC_URI_PREFIX.len();
+        let id_len = rest[id_start..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .unwrap_or(rest.len() - id_start);
+        let end = id_start + id_len;
+        let uri = &rest[start..end];
+
+        output.push_str(&rest[..start]);
+        let already_linked = output.ends_with("](");
+        let title = SpecId::parse(&rest[id_start..end])
+            .ok()
+            .and_then(|id| titles.get(&id));
+        match title {
+            Some(title) if !already_linked => {
+                output.push('[');
+                output.push_str(title);
+                output.push_str("](");
+                output.push_str(uri);
+                output.push(')');
+            }
+            _ => output.push_str(uri),
+        }
+        rest = &rest[end..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn titles() -> HashMap<SpecId, String> {
+        HashMap::from([(
+            SpecId::new(1_737_734_400, "user-auth"),
+            "User Auth".to_string(),
+        )])
+    }
+
+    #[test]
+    fn test_known_id_shows_title() {
+        let content = "Depends on airsspec:///specs/1737734400-user-auth for login.";
+
+        let resolved = resolve_spec_links(content, &titles());
+
+        assert_eq!(
+            resolved,
+            "Depends on [User Auth](airsspec:///specs/1737734400-user-auth) for login."
+        );
+    }
+
+    #[test]
+    fn test_unknown_id_left_as_is() {
+        let content = "See airsspec:///specs/1737734401-billing\nand airsspec:///specs/bogus";
+
+        let resolved = resolve_spec_links(content, &titles());
+
+        assert_eq!(resolved, content);
+    }
+
+    #[test]
+    fn test_existing_markdown_link_not_rewrapped() {
+        let content = "See [auth](airsspec:///specs/1737734400-user-auth).";
+
+        let resolved = resolve_spec_links(content, &titles());
+
+        assert_eq!(resolved, content);
+    }
+}
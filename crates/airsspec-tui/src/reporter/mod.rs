@@ -11,7 +11,37 @@
 //! Issues are grouped by severity (errors first, then warnings, then info)
 //! with a summary status line at the end. Colors use the
 //! [theme](crate::theme) palette for consistency with other TUI components.
+//!
+//! [`render_summary_line`] writes a colorless `AIRSSPEC_RESULT ...` line for
+//! CI log scrapers.
+//!
+//! [`render_workspace_report`] groups the same issues by spec instead, and
+//! can collapse specs with no issues into a single summary line. Its `✓`/`✗`
+//! markers fall back to `[OK]`/`[X]` where [`prefers_ascii_symbols`] says the
+//! terminal cannot render them.
+//! [`render_workspace_report_with_states`] also shows each spec's lifecycle
+//! badge from [`render_lifecycle_badge`].
+//!
+//! [`render_annotated`] shows a single issue with the YAML lines around its
+//! field, like a compiler diagnostic.
+//!
+//! [`resolve_spec_links`] rewrites `airsspec:///specs/{id}` links in spec
+//! content to show the target spec's title.
 
+mod annotated;
+mod lifecycle;
+mod links;
 mod validation;
+mod workspace;
 
-pub use validation::render_validation_report;
+pub use annotated::render_annotated;
+pub use lifecycle::render_lifecycle_badge;
+pub use links::resolve_spec_links;
+pub use validation::{
+    ReportOptions, render_summary_line, render_validation_report,
+    render_validation_report_with_options, terminal_wrap_width,
+};
+pub use workspace::{
+    WorkspaceReportOptions, prefers_ascii_symbols, render_workspace_report,
+    render_workspace_report_with_states,
+};
@@ -4,7 +4,7 @@
 //! using crossterm style commands written to any [`std::io::Write`] destination.
 
 // Layer 1: Standard library
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 
 // Layer 2: External crates
 use crossterm::style::{Attribute, ResetColor, SetAttribute, SetForegroundColor};
@@ -47,6 +47,56 @@ pub fn render_validation_report(
     report: &ValidationReport,
     writer: &mut impl Write,
 ) -> io::Result<()> {
+    render_validation_report_with_options(report, ReportOptions::default(), writer)
+}
+
+/// Options for [`render_validation_report_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReportOptions {
+    /// Print nothing for a valid report, and only the error lines (no
+    /// headers, warnings, info, or status line) for an invalid one.
+    pub quiet: bool,
+
+    /// Wrap issue lines at this column, indenting continuation lines to
+    /// align under the message start. `None` disables wrapping; use
+    /// [`terminal_wrap_width`] to wrap at the terminal width.
+    pub wrap_width: Option<usize>,
+}
+
+/// Returns the terminal width if stdout is a terminal, for use as
+/// [`ReportOptions::wrap_width`].
+///
+/// Returns `None` when output is redirected or the size is unknown, so
+/// piped output is never wrapped.
+#[must_use]
+pub fn terminal_wrap_width() -> Option<usize> {
+    if !io::stdout().is_terminal() {
+        return None;
+    }
+    crossterm::terminal::size()
+        .ok()
+        .map(|(columns, _)| usize::from(columns))
+}
+
+/// Renders a validation report with the given [`ReportOptions`].
+///
+/// With default options this is identical to [`render_validation_report`].
+///
+/// # Errors
+///
+/// Returns an error if writing to the provided writer fails.
+pub fn render_validation_report_with_options(
+    report: &ValidationReport,
+    options: ReportOptions,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    if options.quiet {
+        for issue in report.errors() {
+            write_issue(writer, issue, options.wrap_width)?;
+        }
+        return Ok(());
+    }
+
     if report.is_empty() {
         write_empty_report(writer)?;
         return Ok(());
@@ -57,16 +107,16 @@ pub fn render_validation_report(
     let info_issues = collect_info_issues(report);
 
     if !errors.is_empty() {
-        write_section(writer, "ERRORS", errors.len(), colors::ERROR, &errors)?;
+        write_section(writer, "ERRORS", colors::ERROR, &errors, options.wrap_width)?;
     }
 
     if !warnings.is_empty() {
         write_section(
             writer,
             "WARNINGS",
-            warnings.len(),
             colors::WARNING,
             &warnings,
+            options.wrap_width,
         )?;
     }
 
@@ -74,9 +124,9 @@ pub fn render_validation_report(
         write_section(
             writer,
             "INFO",
-            info_issues.len(),
             colors::PRIMARY,
             &info_issues,
+            options.wrap_width,
         )?;
     }
 
@@ -85,6 +135,29 @@ pub fn render_validation_report(
     Ok(())
 }
 
+/// Writes a single colorless, machine-parseable summary line for CI log
+/// scrapers, e.g. `AIRSSPEC_RESULT errors=2 warnings=3 specs=5 valid=false`.
+///
+/// The format is stable: the fields always appear in this order and the
+/// line never contains escape codes, whatever the terminal supports.
+///
+/// # Errors
+///
+/// Returns an error if writing to the provided writer fails.
+pub fn render_summary_line(
+    report: &ValidationReport,
+    spec_count: usize,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(
+        writer,
+        "AIRSSPEC_RESULT errors={} warnings={} specs={spec_count} valid={}",
+        report.error_count(),
+        report.warning_count(),
+        report.is_valid()
+    )
+}
+
 /// Writes the "no issues" message for empty reports.
 fn write_empty_report(writer: &mut impl Write) -> io::Result<()> {
     write!(writer, "{}", SetForegroundColor(colors::SUCCESS.into()))?;
@@ -100,19 +173,19 @@ fn write_empty_report(writer: &mut impl Write) -> io::Result<()> {
 fn write_section(
     writer: &mut impl Write,
     header: &str,
-    count: usize,
     color: Color,
     issues: &[&ValidationIssue],
+    wrap_width: Option<usize>,
 ) -> io::Result<()> {
     // Header line: "ERRORS (2)"
     write!(writer, "{}", SetForegroundColor(color.into()))?;
     write!(writer, "{}", SetAttribute(Attribute::Bold))?;
-    writeln!(writer, "{header} ({count})")?;
+    writeln!(writer, "{header} ({})", issues.len())?;
     write!(writer, "{}", SetAttribute(Attribute::NoBold))?;
 
     // Each issue (color already set, only bold was removed)
     for issue in issues {
-        write_issue(writer, issue)?;
+        write_issue(writer, issue, wrap_width)?;
     }
 
     // Blank line after section
@@ -122,18 +195,60 @@ fn write_section(
     Ok(())
 }
 
-/// Writes a single issue line.
-fn write_issue(writer: &mut impl Write, issue: &ValidationIssue) -> io::Result<()> {
-    if let Some(field) = issue.field() {
-        writeln!(writer, "  [{field}] {}", issue.message())?;
-    } else {
-        writeln!(writer, "  {}", issue.message())?;
+/// Narrowest message column used when wrapping, so very small widths
+/// still leave room for a few words per line.
+const MIN_WRAP_COLUMNS: usize = 20;
+
+/// Writes a single issue, wrapping the message at `wrap_width` if set.
+fn write_issue(
+    writer: &mut impl Write,
+    issue: &ValidationIssue,
+    wrap_width: Option<usize>,
+) -> io::Result<()> {
+    let prefix = match issue.field() {
+        Some(field) => format!("  [{field}] "),
+        None => String::from("  "),
+    };
+    let Some(width) = wrap_width else {
+        return writeln!(writer, "{prefix}{}", issue.message());
+    };
+
+    let indent = prefix.chars().count();
+    let columns = width.saturating_sub(indent).max(MIN_WRAP_COLUMNS);
+    for (i, line) in wrap_words(issue.message(), columns).iter().enumerate() {
+        if i == 0 {
+            writeln!(writer, "{prefix}{line}")?;
+        } else {
+            writeln!(writer, "{:indent$}{line}", "")?;
+        }
     }
     Ok(())
 }
 
+/// Splits `text` into lines of at most `columns` characters, breaking at
+/// whitespace. A single word longer than `columns` gets a line of its own.
+fn wrap_words(text: &str, columns: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let needed = current.chars().count() + 1 + word.chars().count();
+        if !current.is_empty() && needed > columns {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    lines.push(current);
+    lines
+}
+
 /// Writes the status summary line.
-fn write_status_line(writer: &mut impl Write, report: &ValidationReport) -> io::Result<()> {
+pub(super) fn write_status_line(
+    writer: &mut impl Write,
+    report: &ValidationReport,
+) -> io::Result<()> {
     let info_count = report.issue_count() - report.error_count() - report.warning_count();
 
     // Status label
@@ -185,6 +300,23 @@ mod tests {
         String::from_utf8(buf).unwrap()
     }
 
+    #[test]
+    fn test_summary_line_format() {
+        let mut report = ValidationReport::new();
+        report.add_error("Missing specs directory");
+        report.add_error("Broken dependency");
+        report.add_warning("Empty spec content");
+        report.add_info("Plan has no steps");
+
+        let mut buf = Vec::new();
+        render_summary_line(&report, 5, &mut buf).unwrap();
+
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "AIRSSPEC_RESULT errors=2 warnings=1 specs=5 valid=false\n"
+        );
+    }
+
     #[test]
     fn test_empty_report_shows_no_issues() {
         let report = ValidationReport::new();
@@ -376,6 +508,92 @@ mod tests {
         assert!(result.is_err(), "Should propagate write errors");
     }
 
+    #[test]
+    fn test_quiet_valid_report_prints_nothing() {
+        let mut report = ValidationReport::new();
+        report.add_issue(ValidationIssue::warning("Empty description"));
+        report.add_issue(ValidationIssue::info("Consider adding metadata"));
+
+        let mut buf = Vec::new();
+        render_validation_report_with_options(
+            &report,
+            ReportOptions {
+                quiet: true,
+                ..ReportOptions::default()
+            },
+            &mut buf,
+        )
+        .unwrap();
+
+        assert!(buf.is_empty(), "quiet mode should print nothing when valid");
+    }
+
+    #[test]
+    fn test_quiet_invalid_report_prints_only_errors() {
+        let mut report = ValidationReport::new();
+        report.add_issue(ValidationIssue::error("Missing value").with_field("spec.title"));
+        report.add_issue(ValidationIssue::warning("Empty description"));
+        report.add_issue(ValidationIssue::error("Broken dependency"));
+
+        let mut buf = Vec::new();
+        render_validation_report_with_options(
+            &report,
+            ReportOptions {
+                quiet: true,
+                ..ReportOptions::default()
+            },
+            &mut buf,
+        )
+        .unwrap();
+        let output = String::from_utf8(buf).unwrap();
+
+        assert_eq!(
+            output,
+            "  [spec.title] Missing value\n  Broken dependency\n"
+        );
+    }
+
+    fn render_wrapped(report: &ValidationReport, width: usize) -> String {
+        let options = ReportOptions {
+            quiet: true,
+            wrap_width: Some(width),
+        };
+        let mut buf = Vec::new();
+        render_validation_report_with_options(report, options, &mut buf).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_long_message_wraps_under_message_start() {
+        let report = ValidationReport::from_issues([ValidationIssue::error(
+            "Dependency on non-existent spec 1737734400-auth blocks this spec from progressing",
+        )
+        .with_field("deps")]);
+
+        let output = render_wrapped(&report, 40);
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "  [deps] Dependency on non-existent spec",
+                "         1737734400-auth blocks this",
+                "         spec from progressing",
+            ]
+        );
+        assert!(output.lines().all(|line| line.chars().count() <= 40));
+    }
+
+    #[test]
+    fn test_short_message_stays_on_one_line() {
+        let report =
+            ValidationReport::from_issues(
+                [ValidationIssue::error("Missing title").with_field("t")],
+            );
+
+        assert_eq!(render_wrapped(&report, 40), "  [t] Missing title\n");
+    }
+
     #[test]
     fn test_errors_and_info_skips_warnings() {
         let mut report = ValidationReport::new();
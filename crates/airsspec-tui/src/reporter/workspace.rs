@@ -0,0 +1,421 @@
+//! Per-spec workspace report rendering for terminal output.
+//!
+//! Groups the issues of a workspace [`ValidationReport`] by the spec they
+//! belong to, so users can scan results spec by spec. Issues are attributed
+//! to a spec through the `[{spec_id}] ...` field prefix that the workspace
+//! validators emit; anything else is shown under a workspace-level group.
+
+// Layer 1: Standard library
+use std::env;
+use std::io::{self, Write};
+
+// Layer 2: External crates
+use crossterm::style::{Attribute, ResetColor, SetAttribute, SetForegroundColor};
+use ratatui::style::Color;
+
+// Layer 3: Internal crates/modules
+use airsspec_core::shared::LifecycleState;
+use airsspec_core::spec::SpecId;
+use airsspec_core::validation::{ValidationIssue, ValidationReport, ValidationSeverity};
+
+use super::lifecycle::render_lifecycle_badge;
+use super::validation::write_status_line;
+use crate::theme::colors;
+
+/// Options for [`render_workspace_report`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorkspaceReportOptions {
+    /// Omit specs with no issues and summarize them as "N specs passed".
+    ///
+    /// When `false`, each clean spec is listed with a green check.
+    pub hide_clean: bool,
+
+    /// Mark spec headers with `[OK]`/`[X]` instead of `✓`/`✗`, for
+    /// terminals that cannot render those symbols. Colors still apply.
+    /// Use [`prefers_ascii_symbols`] to detect this from the environment.
+    pub ascii_symbols: bool,
+}
+
+/// Header markers for groups with and without issues.
+#[derive(Debug, Clone, Copy)]
+struct Marks {
+    clean: &'static str,
+    failed: &'static str,
+}
+
+impl Marks {
+    /// Returns the ASCII or unicode markers.
+    const fn new(ascii: bool) -> Self {
+        if ascii {
+            Self {
+                clean: "[OK]",
+                failed: "[X]",
+            }
+        } else {
+            Self {
+                clean: "✓",
+                failed: "✗",
+            }
+        }
+    }
+}
+
+/// Returns `true` if the terminal is unlikely to render unicode symbols,
+/// for use as [`WorkspaceReportOptions::ascii_symbols`].
+///
+/// That is the case when `TERM` is `dumb`, or when the locale (the first of
+/// `LC_ALL`, `LC_CTYPE`, and `LANG` that is set) is missing or not UTF-8.
+#[must_use]
+pub fn prefers_ascii_symbols() -> bool {
+    let term = env::var("TERM").ok();
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()));
+    ascii_for(term.as_deref(), locale.as_deref())
+}
+
+/// Decides [`prefers_ascii_symbols`] from the `TERM` and locale values.
+fn ascii_for(term: Option<&str>, locale: Option<&str>) -> bool {
+    if term == Some("dumb") {
+        return true;
+    }
+    locale.is_none_or(|locale| {
+        let locale = locale.to_ascii_lowercase();
+        !(locale.contains("utf-8") || locale.contains("utf8"))
+    })
+}
+
+/// Renders a workspace validation report grouped by spec.
+///
+/// `spec_ids` lists every spec in the workspace, in display order; specs
+/// with no issues are considered clean. Issues not tied to a spec are shown
+/// first under `WORKSPACE`.
+///
+/// # Output Format
+///
+/// With `hide_clean: true`:
+/// ```text
+/// ✗ 1737734400-auth
+///   error [dependencies] Dependency on non-existent spec
+///
+/// 2 specs passed
+///
+/// Status: FAILED | Errors: 1 | Warnings: 0 | Info: 0
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if writing to the provided writer fails.
+pub fn render_workspace_report(
+    report: &ValidationReport,
+    spec_ids: &[SpecId],
+    options: WorkspaceReportOptions,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    let specs: Vec<(&SpecId, Option<LifecycleState>)> =
+        spec_ids.iter().map(|spec_id| (spec_id, None)).collect();
+    render_grouped(report, &specs, options, writer)
+}
+
+/// Renders a workspace validation report grouped by spec, with each spec
+/// header followed by its lifecycle badge (e.g. `✗ 1737734400-auth [active]`).
+///
+/// Otherwise identical to [`render_workspace_report`].
+///
+/// # Errors
+///
+/// Returns an error if writing to the provided writer fails.
+pub fn render_workspace_report_with_states(
+    report: &ValidationReport,
+    specs: &[(SpecId, LifecycleState)],
+    options: WorkspaceReportOptions,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    let specs: Vec<(&SpecId, Option<LifecycleState>)> = specs
+        .iter()
+        .map(|(spec_id, state)| (spec_id, Some(*state)))
+        .collect();
+    render_grouped(report, &specs, options, writer)
+}
+
+/// Shared implementation of the workspace renderers.
+fn render_grouped(
+    report: &ValidationReport,
+    specs: &[(&SpecId, Option<LifecycleState>)],
+    options: WorkspaceReportOptions,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    let marks = Marks::new(options.ascii_symbols);
+    let workspace_issues: Vec<&ValidationIssue> = report
+        .issues()
+        .iter()
+        .filter(|issue| issue_spec(issue).is_none_or(|id| !contains(specs, id)))
+        .collect();
+
+    if !workspace_issues.is_empty() {
+        write_group_header(writer, "WORKSPACE", colors::ERROR, marks.failed, None)?;
+        for issue in &workspace_issues {
+            write_issue(writer, issue, issue.field())?;
+        }
+        writeln!(writer)?;
+    }
+
+    let mut clean = 0;
+    for &(spec_id, state) in specs {
+        let id = spec_id.as_str();
+        let issues: Vec<&ValidationIssue> = report
+            .issues()
+            .iter()
+            .filter(|issue| issue_spec(issue) == Some(id))
+            .collect();
+
+        if issues.is_empty() {
+            clean += 1;
+            if !options.hide_clean {
+                write_group_header(writer, id, colors::SUCCESS, marks.clean, state)?;
+            }
+            continue;
+        }
+
+        let has_errors = issues
+            .iter()
+            .any(|issue| issue.severity() == ValidationSeverity::Error);
+        let color = if has_errors {
+            colors::ERROR
+        } else {
+            colors::WARNING
+        };
+        write_group_header(writer, id, color, marks.failed, state)?;
+        for issue in &issues {
+            let field = issue
+                .field()
+                .map(strip_spec_prefix)
+                .filter(|f| !f.is_empty());
+            write_issue(writer, issue, field)?;
+        }
+        writeln!(writer)?;
+    }
+
+    if options.hide_clean {
+        write!(writer, "{}", SetForegroundColor(colors::SUCCESS.into()))?;
+        writeln!(writer, "{clean} specs passed")?;
+        write!(writer, "{ResetColor}")?;
+        writeln!(writer)?;
+    } else if clean > 0 {
+        writeln!(writer)?;
+    }
+
+    write_status_line(writer, report)
+}
+
+/// Writes a group header such as `✗ 1737734400-auth`, followed by the
+/// lifecycle badge when `state` is known.
+fn write_group_header(
+    writer: &mut impl Write,
+    label: &str,
+    color: Color,
+    mark: &str,
+    state: Option<LifecycleState>,
+) -> io::Result<()> {
+    write!(writer, "{}", SetForegroundColor(color.into()))?;
+    write!(writer, "{}", SetAttribute(Attribute::Bold))?;
+    write!(writer, "{mark} {label}")?;
+    if let Some(state) = state {
+        let badge = render_lifecycle_badge(state);
+        if let Some(badge_color) = badge.style.fg {
+            write!(writer, "{}", SetForegroundColor(badge_color.into()))?;
+        }
+        write!(writer, " {}", badge.content)?;
+    }
+    writeln!(writer)?;
+    write!(writer, "{}", SetAttribute(Attribute::Reset))?;
+    write!(writer, "{ResetColor}")?;
+    Ok(())
+}
+
+/// Writes a single issue line, prefixed with its severity.
+fn write_issue(
+    writer: &mut impl Write,
+    issue: &ValidationIssue,
+    field: Option<&str>,
+) -> io::Result<()> {
+    match field {
+        Some(field) => writeln!(
+            writer,
+            "  {} [{field}] {}",
+            issue.severity(),
+            issue.message()
+        ),
+        None => writeln!(writer, "  {} {}", issue.severity(), issue.message()),
+    }
+}
+
+/// Returns the spec ID from a `[{spec_id}] ...` field, if present.
+fn issue_spec(issue: &ValidationIssue) -> Option<&str> {
+    let field = issue.field()?.strip_prefix('[')?;
+    field.split_once(']').map(|(id, _)| id)
+}
+
+/// Removes the `[{spec_id}]` prefix from a field, leaving the spec-local part.
+fn strip_spec_prefix(field: &str) -> &str {
+    field
+        .split_once(']')
+        .map_or(field, |(_, rest)| rest.trim_start())
+}
+
+/// Returns `true` if `id` names one of the listed specs.
+fn contains(specs: &[(&SpecId, Option<LifecycleState>)], id: &str) -> bool {
+    specs.iter().any(|(spec_id, _)| spec_id.as_str() == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids() -> Vec<SpecId> {
+        vec![
+            SpecId::new(1_000_000, "auth"),
+            SpecId::new(1_000_001, "billing"),
+            SpecId::new(1_000_002, "search"),
+        ]
+    }
+
+    fn mixed_report() -> ValidationReport {
+        ValidationReport::from_issues([
+            ValidationIssue::error("Dependency on non-existent spec")
+                .with_field("[1000000-auth] dependencies"),
+            ValidationIssue::warning("Spec has empty description")
+                .with_field("[1000002-search] description"),
+        ])
+    }
+
+    fn render(report: &ValidationReport, hide_clean: bool) -> String {
+        let mut buf = Vec::new();
+        render_workspace_report(
+            report,
+            &ids(),
+            WorkspaceReportOptions {
+                hide_clean,
+                ..WorkspaceReportOptions::default()
+            },
+            &mut buf,
+        )
+        .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_hide_clean_omits_clean_specs_and_summarizes() {
+        let output = render(&mixed_report(), true);
+
+        assert!(output.contains("✗ 1000000-auth"));
+        assert!(output.contains("error [dependencies] Dependency on non-existent spec"));
+        assert!(output.contains("✗ 1000002-search"));
+        assert!(
+            !output.contains("1000001-billing"),
+            "clean spec should be hidden, got: {output}"
+        );
+        assert!(output.contains("1 specs passed"));
+        assert!(output.contains("Status: FAILED"));
+    }
+
+    #[test]
+    fn test_show_clean_lists_clean_specs_with_check() {
+        let output = render(&mixed_report(), false);
+
+        assert!(output.contains("✓ 1000001-billing"));
+        assert!(output.contains("✗ 1000000-auth"));
+        assert!(
+            !output.contains("specs passed"),
+            "summary line is only shown when hiding clean specs"
+        );
+    }
+
+    #[test]
+    fn test_specs_render_in_given_order() {
+        let output = render(&mixed_report(), false);
+
+        let auth = output.find("1000000-auth").unwrap();
+        let billing = output.find("1000001-billing").unwrap();
+        let search = output.find("1000002-search").unwrap();
+        assert!(auth < billing && billing < search);
+    }
+
+    #[test]
+    fn test_headers_include_lifecycle_badges() {
+        let specs = vec![
+            (SpecId::new(1_000_000, "auth"), LifecycleState::Blocked),
+            (SpecId::new(1_000_001, "billing"), LifecycleState::Done),
+        ];
+        let mut buf = Vec::new();
+        render_workspace_report_with_states(
+            &mixed_report(),
+            &specs,
+            WorkspaceReportOptions::default(),
+            &mut buf,
+        )
+        .unwrap();
+        let output = String::from_utf8(buf).unwrap();
+
+        assert!(output.contains("✗ 1000000-auth"));
+        assert!(output.contains(" [blocked]"));
+        assert!(output.contains("✓ 1000001-billing"));
+        assert!(output.contains(" [done]"));
+    }
+
+    #[test]
+    fn test_unattributed_issues_grouped_under_workspace() {
+        let report = ValidationReport::from_issues([
+            ValidationIssue::error("Missing specs directory"),
+            ValidationIssue::error("Orphaned plan").with_field("[9999999-gone] plan"),
+        ]);
+
+        let output = render(&report, true);
+
+        let workspace = output.find("✗ WORKSPACE").unwrap();
+        assert!(output.contains("error Missing specs directory"));
+        assert!(output.contains("error [[9999999-gone] plan] Orphaned plan"));
+        assert!(workspace < output.find("3 specs passed").unwrap());
+    }
+
+    fn render_marks(ascii_symbols: bool) -> String {
+        let mut buf = Vec::new();
+        let options = WorkspaceReportOptions {
+            ascii_symbols,
+            ..WorkspaceReportOptions::default()
+        };
+        render_workspace_report(&mixed_report(), &ids(), options, &mut buf).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_unicode_symbols_by_default() {
+        let output = render_marks(false);
+
+        assert!(output.contains("✗ 1000000-auth"));
+        assert!(output.contains("✓ 1000001-billing"));
+        assert!(!output.contains("[OK]") && !output.contains("[X]"));
+    }
+
+    #[test]
+    fn test_ascii_symbols_replace_unicode_marks() {
+        let output = render_marks(true);
+
+        assert!(output.contains("[X] 1000000-auth"));
+        assert!(output.contains("[OK] 1000001-billing"));
+        assert!(!output.contains('✓') && !output.contains('✗'));
+        assert!(
+            output.contains("\x1b["),
+            "colors should still apply in ASCII mode"
+        );
+    }
+
+    #[test]
+    fn test_ascii_detection_from_term_and_locale() {
+        assert!(!ascii_for(Some("xterm-256color"), Some("en_US.UTF-8")));
+        assert!(!ascii_for(None, Some("C.utf8")));
+        assert!(ascii_for(Some("dumb"), Some("en_US.UTF-8")));
+        assert!(ascii_for(Some("xterm"), Some("C")));
+        assert!(ascii_for(Some("xterm"), None));
+    }
+}
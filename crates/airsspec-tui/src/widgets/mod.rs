@@ -6,7 +6,11 @@
 //!
 //! - [`TextInput`] -- Single-line text input with cursor management,
 //!   placeholder text, max length enforcement, and horizontal scrolling.
+//! - [`SpecListPicker`] -- Filterable spec list with lifecycle badges and
+//!   arrow-key selection.
 
+mod spec_list_picker;
 mod text_input;
 
+pub use spec_list_picker::SpecListPicker;
 pub use text_input::TextInput;
@@ -0,0 +1,341 @@
+//! # Spec List Picker Widget
+//!
+//! A filterable list of specs for choosing one interactively. Each row shows
+//! the spec ID, title, and lifecycle badge; typing narrows the list by
+//! substring and the arrow keys move the selection.
+//!
+//! Like [`TextInput`](super::TextInput), the picker keeps state across
+//! renders and so exposes [`SpecListPicker::render`] taking `&self` rather
+//! than implementing `ratatui::widgets::Widget`.
+
+// Layer 1: Standard library
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+// Layer 2: External crates
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{buffer::Buffer, layout::Rect};
+
+// Layer 3: Internal crates/modules
+use airsspec_core::shared::LifecycleState;
+use airsspec_core::spec::{Spec, SpecId};
+
+use crate::reporter::render_lifecycle_badge;
+use crate::theme::styles;
+
+/// One selectable row.
+#[derive(Debug, Clone)]
+struct Entry {
+    id: SpecId,
+    title: String,
+    state: LifecycleState,
+}
+
+/// A filterable spec list that returns the selected [`SpecId`].
+///
+/// # Supported Keys
+///
+/// - `Char(c)` -- Append to the filter
+/// - `Backspace` -- Remove the last filter character
+/// - `Up` / `Down` -- Move the selection within the filtered list
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use airsspec_core::spec::{Spec, SpecId, SpecMetadata};
+/// use airsspec_tui::widgets::SpecListPicker;
+///
+/// let specs = vec![Spec::new(
+///     SpecId::new(1_737_734_400, "user-auth"),
+///     SpecMetadata::new("User Auth", ""),
+///     "",
+/// )];
+/// let picker = SpecListPicker::new(&specs, &HashMap::new());
+/// assert_eq!(picker.selected().unwrap().as_str(), "1737734400-user-auth");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpecListPicker {
+    entries: Vec<Entry>,
+    filter: String,
+    /// Indices into `entries` matching the current filter, in list order.
+    visible: Vec<usize>,
+    /// Position of the selection within `visible`.
+    selected: usize,
+}
+
+impl SpecListPicker {
+    /// Creates a picker listing `specs` in the given order.
+    ///
+    /// Specs with no entry in `states` are shown as draft.
+    #[must_use]
+    pub fn new<H: BuildHasher>(
+        specs: &[Spec],
+        states: &HashMap<SpecId, LifecycleState, H>,
+    ) -> Self {
+        let entries: Vec<Entry> = specs
+            .iter()
+            .map(|spec| Entry {
+                id: spec.id().clone(),
+                title: spec.title().to_string(),
+                state: states.get(spec.id()).copied().unwrap_or_default(),
+            })
+            .collect();
+        let visible = (0..entries.len()).collect();
+        Self {
+            entries,
+            filter: String::new(),
+            visible,
+            selected: 0,
+        }
+    }
+
+    /// Returns the current filter text.
+    #[must_use]
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Returns the IDs of the specs matching the filter, in list order.
+    #[must_use]
+    pub fn visible_ids(&self) -> Vec<&SpecId> {
+        self.visible.iter().map(|&i| &self.entries[i].id).collect()
+    }
+
+    /// Returns the selected spec, or `None` if no spec matches the filter.
+    #[must_use]
+    pub fn selected(&self) -> Option<&SpecId> {
+        self.visible
+            .get(self.selected)
+            .map(|&i| &self.entries[i].id)
+    }
+
+    /// Handles a keyboard event.
+    ///
+    /// Returns `true` if the event was consumed, `false` otherwise (e.g.
+    /// `Enter` or `Esc`, which the caller handles).
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(c) => {
+                self.filter.push(c);
+                self.refilter();
+                true
+            }
+            KeyCode::Backspace => {
+                if self.filter.pop().is_some() {
+                    self.refilter();
+                }
+                true
+            }
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                true
+            }
+            KeyCode::Down => {
+                if self.selected + 1 < self.visible.len() {
+                    self.selected += 1;
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Renders the filter line and the visible rows into `area`.
+    ///
+    /// The selected row is highlighted. Rows past the bottom of `area` are
+    /// not drawn. An empty list shows "no specs".
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let filter_line = format!("Filter: {}", self.filter);
+        buf.set_stringn(
+            area.x,
+            area.y,
+            filter_line,
+            usize::from(area.width),
+            styles::muted(),
+        );
+
+        if self.visible.is_empty() {
+            if area.height > 1 {
+                buf.set_stringn(
+                    area.x,
+                    area.y + 1,
+                    "no specs",
+                    usize::from(area.width),
+                    styles::muted(),
+                );
+            }
+            return;
+        }
+
+        let rows = usize::from(area.height - 1);
+        // Keep the selection in view by scrolling the list.
+        let offset = self.selected.saturating_sub(rows.saturating_sub(1));
+        for (row, (position, &index)) in self
+            .visible
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(rows)
+            .enumerate()
+        {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "row is bounded by area height (u16)"
+            )]
+            let y = area.y + 1 + row as u16;
+            let entry = &self.entries[index];
+            let is_selected = position == self.selected;
+            let (marker, style) = if is_selected {
+                ("> ", styles::input_active())
+            } else {
+                ("  ", styles::default())
+            };
+
+            let text = format!("{marker}{} {}", entry.id, entry.title);
+            let (x, _) = buf.set_stringn(area.x, y, &text, usize::from(area.width), style);
+
+            let badge = render_lifecycle_badge(entry.state);
+            let remaining = usize::from(area.x + area.width - x);
+            if remaining > 1 {
+                buf.set_stringn(x + 1, y, badge.content.as_ref(), remaining - 1, badge.style);
+            }
+        }
+    }
+
+    /// Recomputes the visible rows after the filter changed and resets the
+    /// selection to the first match.
+    fn refilter(&mut self) {
+        let needle = self.filter.to_lowercase();
+        self.visible = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                entry.id.as_str().to_lowercase().contains(&needle)
+                    || entry.title.to_lowercase().contains(&needle)
+            })
+            .map(|(i, _)| i)
+            .collect();
+        self.selected = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    use airsspec_core::spec::SpecMetadata;
+
+    use super::*;
+
+    /// Helper to create a key press event.
+    fn key_event(code: KeyCode) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    fn picker() -> SpecListPicker {
+        let specs = vec![
+            Spec::new(
+                SpecId::new(1_000_000, "user-auth"),
+                SpecMetadata::new("User Auth", ""),
+                "",
+            ),
+            Spec::new(
+                SpecId::new(1_000_001, "billing"),
+                SpecMetadata::new("Billing", ""),
+                "",
+            ),
+            Spec::new(
+                SpecId::new(1_000_002, "auth-audit"),
+                SpecMetadata::new("Audit Log", ""),
+                "",
+            ),
+        ];
+        let states = HashMap::from([(SpecId::new(1_000_001, "billing"), LifecycleState::Active)]);
+        SpecListPicker::new(&specs, &states)
+    }
+
+    fn draw(picker: &SpecListPicker) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(50, 5)).unwrap();
+        terminal
+            .draw(|frame| picker.render(frame.area(), frame.buffer_mut()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_filter_narrows_list() {
+        let mut picker = picker();
+
+        for c in "AUTH".chars() {
+            picker.handle_key(key_event(KeyCode::Char(c)));
+        }
+
+        let ids: Vec<&str> = picker.visible_ids().iter().map(|id| id.as_str()).collect();
+        assert_eq!(ids, vec!["1000000-user-auth", "1000002-auth-audit"]);
+        let screen = draw(&picker);
+        assert!(screen.contains("Filter: AUTH"));
+        assert!(!screen.contains("billing"));
+
+        picker.handle_key(key_event(KeyCode::Backspace));
+        picker.handle_key(key_event(KeyCode::Backspace));
+        picker.handle_key(key_event(KeyCode::Backspace));
+        picker.handle_key(key_event(KeyCode::Backspace));
+        assert_eq!(picker.visible_ids().len(), 3);
+    }
+
+    #[test]
+    fn test_arrow_selection_returns_id() {
+        let mut picker = picker();
+
+        picker.handle_key(key_event(KeyCode::Down));
+        picker.handle_key(key_event(KeyCode::Down));
+        picker.handle_key(key_event(KeyCode::Down));
+        assert_eq!(picker.selected().unwrap().as_str(), "1000002-auth-audit");
+
+        picker.handle_key(key_event(KeyCode::Up));
+        assert_eq!(picker.selected().unwrap().as_str(), "1000001-billing");
+        let screen = draw(&picker);
+        assert!(screen.contains("> 1000001-billing Billing [active]"));
+        assert!(screen.contains("  1000000-user-auth User Auth [draft]"));
+    }
+
+    #[test]
+    fn test_empty_list_shows_no_specs() {
+        let mut picker = SpecListPicker::new(&[], &HashMap::new());
+
+        assert!(picker.selected().is_none());
+        assert!(picker.handle_key(key_event(KeyCode::Down)));
+        assert!(draw(&picker).contains("no specs"));
+    }
+
+    #[test]
+    fn test_filter_without_matches_selects_nothing() {
+        let mut picker = picker();
+
+        picker.handle_key(key_event(KeyCode::Char('z')));
+
+        assert!(picker.selected().is_none());
+        assert!(draw(&picker).contains("no specs"));
+    }
+}